@@ -0,0 +1,106 @@
+//! Renders `ai.rs`, the trait that players implement.
+
+use crate::docs::func_doc;
+use crate::names::{sanitize, underscore};
+use crate::structure::Game;
+
+pub fn render(game: &Game) -> String {
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+#![allow(unused_imports)]
+
+use super::*;
+use crate::types::*;
+use crate::error::Error;
+
+/// An AI that plays ${game_name}.
+///
+/// The game's orders must be implemented; every other hook has a default that does nothing
+/// special. Errors returned from any hook end the game for this client.
+pub trait AI {
+    /// The name of the player this AI plays as, unless another is given on the command line.
+    fn get_name(&self) -> String {
+        "${game_name} Rust Player".into()
+    }
+
+    /// Called once when the game starts, before the first order.
+    fn start(&mut self, _game: &Game, _player: &Player) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called each time the server updates the game state between orders.
+    ///
+    /// `changes` lists what changed since the last call, including anything that changed while
+    /// the AI was carrying out an order.
+    fn game_updated(&mut self, _game: &Game, _player: &Player, _changes: &Changes) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called when the server rejects something this AI tried to do.
+    fn invalid(&mut self, message: &str) {
+        eprintln!("Invalid: {}", message);
+    }
+
+    /// Called once when the game is over.
+    ///
+    /// # Arguments
+    ///
+    /// - _won_ - True if this AI's player won, false otherwise.
+    ///
+    /// - _reason_ - The reason this AI's player won or lost.
+    fn end(&mut self, _won: bool, _reason: &str) {}
+"#,
+        game_name = game.name
+    );
+
+    for (func_name, func) in game.ai.functions.iter() {
+        let name = sanitize(&underscore(func_name));
+        let ret = match &func.returns {
+            Some(returns) => game.ai_return_type(&returns.ty),
+            None => "()".to_string(),
+        };
+        emit!(out, "\n\n${doc}\n", doc = func_doc(func, None, "    /// "));
+        if func.arguments.is_empty() {
+            emit!(
+                out,
+                r#"
+    fn ${name}(&mut self, game: &Game, player: &Player) -> Result<${ret}, Error>;
+"#,
+                name = name,
+                ret = ret
+            );
+        } else {
+            emit!(
+                out,
+                r#"
+    fn ${name}(
+        &mut self,
+        game: &Game,
+        player: &Player,
+"#,
+                name = name
+            );
+            for arg in &func.arguments {
+                emit!(
+                    out,
+                    r#"
+        ${arg}: ${ty},
+"#,
+                    arg = sanitize(&underscore(&arg.name)),
+                    ty = game.return_type(&arg.ty)
+                );
+            }
+            emit!(
+                out,
+                r#"
+    ) -> Result<${ret}, Error>;
+"#,
+                ret = ret
+            );
+        }
+    }
+    out.push_str("}\n");
+    out
+}
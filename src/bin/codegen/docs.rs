@@ -0,0 +1,218 @@
+//! Doc comments built from the descriptions in the game structure.
+
+use crate::names::{sanitize, underscore};
+use crate::structure::{Attribute, Function, Object};
+
+/// The longest line that doc comments are wrapped to, including their prefix.
+const MAX_LINE_LENGTH: usize = 99;
+
+pub fn obj_doc(obj: &Object, prefix: &str) -> String {
+    prefix_lines(&rustify(&obj.description), prefix)
+}
+
+pub fn attr_doc(attr: &Attribute, parent: Option<&str>, prefix: &str) -> String {
+    let text = inherited(parent, rustify(&attr.description));
+    prefix_lines(&text, prefix)
+}
+
+pub fn func_doc(func: &Function, parent: Option<&str>, prefix: &str) -> String {
+    let mut text = rustify(&func.description);
+
+    if !func.arguments.is_empty() {
+        text.push_str("\n\n# Arguments");
+        for arg in &func.arguments {
+            text.push_str(&format!(
+                "\n\n- _{}_ - {}",
+                sanitize(&underscore(&arg.name)),
+                rustify(&arg.description),
+            ));
+        }
+    }
+
+    if let Some(returns) = &func.returns {
+        text.push_str(&format!(
+            "\n\n# Returns\n\n{}",
+            rustify(&returns.description)
+        ));
+    }
+
+    prefix_lines(&inherited(parent, text), prefix)
+}
+
+fn inherited(parent: Option<&str>, text: String) -> String {
+    match parent {
+        Some(parent) => format!("_Inherited from [`{}`]_\n\n{}", parent, text),
+        None => text,
+    }
+}
+
+/// Makes a description read well in rustdoc: `null` becomes `None`, and brackets are escaped so
+/// that they aren't taken for links.
+fn rustify(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if rest
+            .get(..4)
+            .is_some_and(|word| word.eq_ignore_ascii_case("null"))
+        {
+            result.push_str("None");
+            rest = &rest[4..];
+            continue;
+        }
+        match c {
+            '[' => result.push_str("\\["),
+            ']' => result.push_str("\\]"),
+            c => result.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+fn prefix_lines(text: &str, prefix: &str) -> String {
+    let lines = lines(text, MAX_LINE_LENGTH - prefix.len());
+    let lines: Vec<_> = lines
+        .iter()
+        .map(|line| format!("{}{}", prefix, line).trim_end().to_string())
+        .collect();
+    lines.join("\n")
+}
+
+/// Wraps each paragraph of `text`, indenting the continuation lines of list items.
+fn lines(text: &str, max_length: usize) -> Vec<String> {
+    let mut result = Vec::new();
+    for paragraph in text.split('\n') {
+        if paragraph.starts_with("- ") {
+            result.extend(wrap(paragraph, max_length, "  "));
+        } else if !paragraph.is_empty() {
+            result.extend(wrap(paragraph, max_length, ""));
+        } else {
+            result.push(String::new());
+        }
+    }
+    result
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0b' | '\x0c' | '\r' | ' ')
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_letter(c: char) -> bool {
+    is_word(c) && !c.is_numeric()
+}
+
+fn is_word_punct(c: char) -> bool {
+    is_word(c) || "!\"'&.,?".contains(c)
+}
+
+/// Splits text into words and runs of whitespace. Words are also split after hyphens between
+/// letters, like "long-" "winded", and around em-dashes written as `--`.
+fn chunks(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let at = |i: usize| chars.get(i).copied();
+    let holds = |i: usize, f: fn(char) -> bool| at(i).is_some_and(f);
+    // A run of two or more dashes starting at `i` and followed by a word character.
+    let em_dash = |i: usize| {
+        let dashes = chars[i..].iter().take_while(|&&c| c == '-').count();
+        dashes >= 2 && holds(i + dashes, is_word)
+    };
+
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let end = if is_space(chars[start]) {
+            start + chars[start..].iter().take_while(|&&c| is_space(c)).count()
+        } else if start > 0 && holds(start - 1, is_word_punct) && em_dash(start) {
+            start + chars[start..].iter().take_while(|&&c| c == '-').count()
+        } else {
+            let mut end = start + 1;
+            loop {
+                let hyphenated = at(end) == Some('-')
+                    && end >= 2
+                    && ((holds(end - 2, is_letter) && holds(end - 1, is_letter))
+                        || (end >= 3
+                            && holds(end - 3, is_letter)
+                            && at(end - 2) == Some('-')
+                            && holds(end - 1, is_letter)))
+                    && holds(end + 1, is_letter)
+                    && (holds(end + 2, is_letter)
+                        || (at(end + 2) == Some('-') && holds(end + 3, is_letter)));
+                if hyphenated {
+                    break end + 1;
+                }
+                if end == chars.len() || is_space(chars[end]) {
+                    break end;
+                }
+                if holds(end - 1, is_word_punct) && em_dash(end) {
+                    break end;
+                }
+                end += 1;
+            }
+        };
+        result.push(chars[start..end].iter().collect());
+        start = end;
+    }
+    result
+}
+
+/// Greedily wraps a paragraph to `width` characters, the same way Python's `textwrap` does.
+fn wrap(text: &str, width: usize, subsequent_indent: &str) -> Vec<String> {
+    let len = |s: &str| s.chars().count();
+    let is_blank = |s: &str| s.chars().all(is_space);
+
+    let mut chunks = chunks(text);
+    chunks.reverse();
+    let mut lines: Vec<String> = Vec::new();
+    while !chunks.is_empty() {
+        let mut line: Vec<String> = Vec::new();
+        let mut line_len = 0;
+        let indent = if lines.is_empty() {
+            ""
+        } else {
+            subsequent_indent
+        };
+        let width = width - len(indent);
+
+        if !lines.is_empty() && chunks.last().is_some_and(|chunk| is_blank(chunk)) {
+            chunks.pop();
+        }
+
+        while let Some(chunk) = chunks.last() {
+            if line_len + len(chunk) > width {
+                break;
+            }
+            line_len += len(chunk);
+            line.push(chunks.pop().unwrap());
+        }
+
+        // A word that is too long for any line is broken up, after a hyphen if possible.
+        if let Some(chunk) = chunks.last_mut() {
+            if len(chunk) > width {
+                let space_left = if width < 1 { 1 } else { width - line_len };
+                let chars: Vec<char> = chunk.chars().collect();
+                let mut end = space_left;
+                if let Some(hyphen) = chars[..space_left].iter().rposition(|&c| c == '-') {
+                    if hyphen > 0 && chars[..hyphen].iter().any(|&c| c != '-') {
+                        end = hyphen + 1;
+                    }
+                }
+                line.push(chars[..end].iter().collect());
+                *chunk = chars[end..].iter().collect();
+            }
+        }
+
+        if line.last().is_some_and(|chunk| is_blank(chunk)) {
+            line.pop();
+        }
+
+        if !line.is_empty() {
+            lines.push(format!("{}{}", indent, line.concat()));
+        }
+    }
+    lines
+}
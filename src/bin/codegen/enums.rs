@@ -0,0 +1,154 @@
+//! Renders `enums.rs`, which holds the strings that can only take a fixed set of values.
+
+use crate::names::variant;
+use crate::structure::{Enum, Game};
+
+pub fn render(game: &Game) -> String {
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+"#
+    );
+    for e in game
+        .enums()
+        .expect("unreachable: enums are checked when opened")
+    {
+        render_enum(&mut out, &e);
+    }
+    out
+}
+
+fn render_enum(out: &mut String, e: &Enum) {
+    let uses: String = e
+        .uses
+        .iter()
+        .map(|place| format!("/// - {}\n", place))
+        .collect();
+    emit!(
+        out,
+        r#"
+
+/// One of a fixed set of strings, used by:
+///
+${uses}#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ${name} {
+"#,
+        uses = uses,
+        name = e.name
+    );
+    for literal in e.literals {
+        emit!(
+            out,
+            r#"
+    /// `"${literal}"`
+    ${variant},
+"#,
+            literal = literal,
+            variant = variant(literal)
+        );
+    }
+    emit!(
+        out,
+        r#"
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl ${name} {
+    pub fn as_str(&self) -> &str {
+        match self {
+"#,
+        name = e.name
+    );
+    for literal in e.literals {
+        emit!(
+            out,
+            "            ${name}::${variant} => \"${literal}\",\n",
+            name = e.name,
+            variant = variant(literal),
+            literal = literal
+        );
+    }
+    emit!(
+        out,
+        r#"
+            ${name}::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for ${name} {
+    fn from(s: &str) -> ${name} {
+        match s {
+"#,
+        name = e.name
+    );
+    for literal in e.literals {
+        emit!(
+            out,
+            "            \"${literal}\" => ${name}::${variant},\n",
+            name = e.name,
+            variant = variant(literal),
+            literal = literal
+        );
+    }
+    emit!(
+        out,
+        r#"
+            other => ${name}::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for ${name} {
+    fn default() -> ${name} {
+        ${name}::from("")
+    }
+}
+
+impl fmt::Display for ${name} {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ${name} {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ${name} {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| ${name}::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for ${name} {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+"#,
+        name = e.name
+    );
+}
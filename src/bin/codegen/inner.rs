@@ -0,0 +1,891 @@
+//! Renders `inner.rs`, which holds the state of the game and drives the AI.
+
+use std::collections::BTreeSet;
+
+use crate::names::{sanitize, underscore};
+use crate::structure::Game;
+
+pub fn render(game: &Game) -> String {
+    // Only games that count turns can say which turn they ended on.
+    let final_turn = if game.game.attributes.get("currentTurn").is_some() {
+        "Some(game.current_turn())"
+    } else {
+        "None"
+    };
+
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+#![allow(unused_imports, dead_code)]
+
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::sync::{Arc, Mutex, Weak};
+
+use serde::de::Deserialize;
+use serde::ser::Serialize;
+use serde_json::Value;
+
+use super::*;
+use crate::client::base::ObjRef;
+use crate::client::connection::Connection;
+use crate::client::delta::{self, Changes, DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::gamelog::Gamelog;
+use crate::client::outcome::GameOutcome;
+use crate::client::proto::{ClientEvent, ServerEvent};
+use crate::client::runtime::{self, unexpected_event, GameOver, Handler};
+use crate::client::settings::{AiSettings, GameSetting};
+use crate::client::util::lock;
+use crate::games::{Session, Spectator};
+use crate::types::*;
+use crate::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    self_ref: Weak<Mutex<Context>>,
+    game: Arc<Mutex<GameBase>>,
+    connection: Arc<Mutex<Connection>>,
+    delta: delta::Context,
+    changes: Changes,
+    invalid_messages: Vec<String>,
+    ai_settings: AiSettings,
+}
+
+impl Context {
+    /// Creates the context for a new game, with no state until the first delta is merged.
+    pub(crate) fn new(
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        ai_settings: AiSettings,
+    ) -> Arc<Mutex<Context>> {
+        Arc::new_cyclic(|self_ref| {
+            Mutex::new(Context {
+                self_ref: self_ref.clone(),
+                game: Default::default(),
+                connection,
+                delta,
+                changes: Changes::default(),
+                invalid_messages: Vec::new(),
+                ai_settings,
+            })
+        })
+    }
+
+    pub(crate) fn get_ref(&self) -> Weak<Mutex<Context>> {
+        self.self_ref.clone()
+    }
+
+    pub(crate) fn game(&self) -> Game {
+        Game::new(Arc::clone(&self.game), self.get_ref())
+    }
+
+    pub(crate) fn ai_settings(&self) -> &AiSettings {
+        &self.ai_settings
+    }
+
+    /// Takes a snapshot of the current state. Holding the context keeps deltas from being merged
+    /// while it is copied.
+    pub(crate) fn snapshot(&self) -> Snapshot {
+        Snapshot::new(lock(&self.game).clone())
+    }
+
+    /// Looks up a game object by its id, if it exists and is of type `T`.
+    pub(crate) fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.game).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// Checks whether a game object is still in the game, rather than removed by a delta.
+    pub(crate) fn contains(&self, obj: &Arc<Mutex<AnyGameObject>>) -> bool {
+        let id = lock(obj).id();
+        let game = lock(&self.game);
+        game.game_objects.get(&id).is_some_and(|handle| Arc::ptr_eq(handle.game_object(), obj))
+    }
+
+    /// Asks the server to run a function on the given game object, and waits for the result.
+    ///
+    /// Deltas sent by the server before the result are merged as they arrive, so any objects
+    /// referred to by the result are already known when it is decoded.
+    pub(crate) fn run<A, R>(&mut self, caller: &str, function_name: &str, args: A) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: FromDelta<Context>,
+    {
+        let args = serde_json::from_value(serde_json::to_value(args)?)?;
+        lock(&self.connection).send(ClientEvent::Run {
+            caller: ObjRef::new(caller),
+            function_name: function_name.to_string(),
+            args,
+        })?;
+        loop {
+            let event = lock(&self.connection).recv()?;
+            match event {
+                ServerEvent::Delta(delta) => self.merge_delta(delta)?,
+                ServerEvent::Invalid { message } => self.invalid_messages.push(message),
+                ServerEvent::Ran(value) => return R::from_delta(value, self),
+                event => return Err(unexpected_event(event)),
+            }
+        }
+    }
+
+    /// Merges a delta from the server into the game state, recording what changed until the
+    /// changes are taken.
+    ///
+    /// New game objects are created before anything else is merged, so that references to them
+    /// can be resolved wherever they appear. Each object is merged into a copy of its state which
+    /// is written back afterwards, so that it can refer to itself without deadlocking.
+    ///
+    /// Any error, like a value of the wrong type, fails with [`Exit::DeltaMergeFailure`].
+    pub(crate) fn merge_delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        self.merge(delta)
+            .map_err(|err| err.with_exit(Exit::DeltaMergeFailure))
+    }
+
+    fn merge(&mut self, mut delta: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(objects) = delta.remove("gameObjects") {
+            self.merge_game_objects(objects)?;
+        }
+
+        let fields: Vec<_> = delta.keys().filter_map(|key| field_name(key)).collect();
+        let mut game = lock(&self.game).clone();
+        game.merge_delta(Value::Object(delta.into_iter().collect()), self)?;
+        *lock(&self.game) = game;
+        for field in fields {
+            self.changes.change_game(field);
+        }
+        Ok(())
+    }
+
+    /// Takes the changes recorded since they were last taken.
+    pub(crate) fn take_changes(&mut self) -> Changes {
+        std::mem::take(&mut self.changes)
+    }
+
+    fn merge_game_objects(&mut self, delta: Value) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+
+        let mut game_objects = lock(&self.game).game_objects.clone();
+        for (id, obj_delta) in &delta {
+            if *obj_delta == self.delta.delta_removed() {
+                game_objects.remove(id.as_str());
+                self.changes.remove(id);
+            } else if game_objects.get(id.as_str()).is_none() {
+                let name = obj_delta.get("gameObjectName")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let obj = AnyGameObject::new(name).ok_or_else(|| {
+                    let message = format!("unknown game object type {:?} for id {:?}", name, id);
+                    Error::from((Exit::DeltaMergeFailure, message))
+                })?;
+                let handle = GameObject::new(Arc::new(Mutex::new(obj)), self.get_ref());
+                game_objects.insert(id.as_str().into(), handle);
+                self.changes.create(id);
+            }
+        }
+        lock(&self.game).game_objects = game_objects.clone();
+
+        for (id, obj_delta) in delta {
+            if obj_delta == self.delta.delta_removed() {
+                continue;
+            }
+            let handle = game_objects.get(id.as_str()).expect("unreachable: object not created");
+            let obj = Arc::clone(handle.game_object());
+            let fields: Vec<_> = obj_delta.as_object()
+                .map(|attrs| attrs.keys().filter_map(|key| field_name(key)).collect())
+                .unwrap_or_default();
+            let mut state = lock(&obj).clone();
+            state.merge_delta(obj_delta, self)?;
+            *lock(&obj) = state;
+            for field in fields {
+                self.changes.change(&id, field);
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the game object referred to by an `{"id": ...}` value.
+    pub(crate) fn resolve<T: Object>(&self, delta: Value) -> Result<T, Error> {
+        let obj_ref = ObjRef::deserialize(&delta)?;
+        self.get_object(obj_ref.id()).ok_or_else(|| {
+            let message = format!("no game object with id {:?}", obj_ref.id());
+            (Exit::DeltaMergeFailure, message).into()
+        })
+    }
+}
+
+impl AsRef<delta::Context> for Context {
+    fn as_ref(&self) -> &delta::Context {
+        &self.delta
+    }
+}
+
+/// An AI waiting for its game to start.
+pub(crate) struct Pending<A> {
+    ai: A,
+}
+
+impl<A> Pending<A> {
+    pub(crate) fn new(ai: A) -> Pending<A> {
+        Pending { ai }
+    }
+}
+
+impl<A: AI> Session for Pending<A> {
+    fn game_name(&self) -> &str {
+        GAME_NAME
+    }
+
+    fn settings(&self) -> &'static [GameSetting] {
+        SETTINGS
+    }
+
+    fn player_name(&self) -> String {
+        self.ai.get_name()
+    }
+
+    fn play(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+        player_id: &str,
+        ai_settings: AiSettings,
+    ) -> Result<GameOutcome, Error> {
+        let context = Context::new(Arc::clone(&connection), delta, ai_settings);
+        let (game, player) = {
+            let mut cx = lock(&context);
+            cx.merge_delta(initial_delta)?;
+            // The AI sees the whole initial state when it starts.
+            cx.take_changes();
+            let player = cx.get_object(player_id).ok_or_else(|| {
+                let message = format!("no player with id {:?}", player_id);
+                Error::from((Exit::DeltaMergeFailure, message))
+            })?;
+            (cx.game(), player)
+        };
+
+        let mut runner = Runner::new(context, game, player, self.ai);
+        runner.start()?;
+        let over = runtime::run(&connection, &mut runner)?;
+        Ok(outcome(&runner.game, &runner.player, over))
+    }
+
+    fn replay(self: Box<Self>, gamelog: &Gamelog, player_index: usize) -> Result<(), Error> {
+        replay(gamelog, player_index, self.ai).map(|_| ())
+    }
+}
+
+pub(crate) fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, mut ai: A) -> Result<A, Error> {
+    let context = offline_context(gamelog)?;
+    let mut started: Option<(Game, Player)> = None;
+    for delta in &gamelog.deltas {
+        if let Some(game_delta) = &delta.game {
+            lock(&context).merge_delta(game_delta.clone())?;
+        }
+        let changes = lock(&context).take_changes();
+        match &started {
+            Some((game, player)) => ai.game_updated(game, player, &changes).map_err(ai_errored)?,
+            None => {
+                let game = lock(&context).game();
+                // The players are not known until the first delta that sets up the game.
+                if let Some(player) = game.players().get(player_index).cloned() {
+                    ai.start(&game, &player).map_err(ai_errored)?;
+                    started = Some((game, player));
+                }
+            }
+        }
+    }
+
+    let (_, player) = started.ok_or_else(|| {
+        let message = format!("gamelog has no player with index {}", player_index);
+        Error::from((Exit::InvalidArgs, message))
+    })?;
+    end(&mut ai, &player);
+    Ok(ai)
+}
+
+/// Creates the context to replay a gamelog of this game in.
+fn offline_context(gamelog: &Gamelog) -> Result<Arc<Mutex<Context>>, Error> {
+    if gamelog.game_name != GAME_NAME {
+        let message = format!("gamelog is for {:?}, not {:?}", gamelog.game_name, GAME_NAME);
+        return Err((Exit::GameNotFound, message).into());
+    }
+
+    // There is no server to run functions, so trying to is treated like a lost connection.
+    let connection = Connection::new(io::empty(), io::sink());
+    let constants = delta::Context::from_constants(&gamelog.constants)?;
+    Ok(Context::new(Arc::new(Mutex::new(connection)), constants, AiSettings::new()))
+}
+
+fn end<A: AI>(ai: &mut A, player: &Player) {
+    let won = player.won();
+    let reason = if won {
+        player.reason_won()
+    } else {
+        player.reason_lost()
+    };
+    ai.end(won, &reason);
+}
+
+/// Marks an error returned by the AI as [`Exit::AiErrored`], unless it already has an exit code,
+/// like the errors of game object functions do.
+fn ai_errored(err: Error) -> Error {
+    match err.exit() {
+        Some(_) => err,
+        None => err.with_exit(Exit::AiErrored),
+    }
+}
+
+fn outcome(game: &Game, player: &Player, over: GameOver) -> GameOutcome {
+    GameOutcome {
+        game_name: GAME_NAME.to_string(),
+        game_session: game.session().to_string(),
+        won: player.won(),
+        lost: player.lost(),
+        reason_won: player.reason_won().to_string(),
+        reason_lost: player.reason_lost().to_string(),
+        final_turn: ${final_turn},
+        gamelog_url: over.gamelog_url,
+        visualizer_url: over.visualizer_url,
+    }
+}
+
+/// Forwards the runtime's events to an [`AI`].
+pub(crate) struct Runner<A> {
+    context: Arc<Mutex<Context>>,
+    game: Game,
+    player: Player,
+    ai: A,
+}
+
+impl<A: AI> Runner<A> {
+    pub(crate) fn new(context: Arc<Mutex<Context>>, game: Game, player: Player, ai: A) -> Runner<A> {
+        Runner { context, game, player, ai }
+    }
+
+    pub(crate) fn start(&mut self) -> Result<(), Error> {
+        self.ai.start(&self.game, &self.player).map_err(ai_errored)
+    }
+
+    fn decode_arg<T>(&self, arg: Option<Value>) -> Result<T, Error>
+    where
+        T: FromDelta<Context>,
+    {
+        let cx = lock(&self.context);
+        T::from_delta(arg.unwrap_or(Value::Null), &cx)
+    }
+}
+
+impl<A: AI> Handler for Runner<A> {
+    fn delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        let changes = {
+            let mut cx = lock(&self.context);
+            cx.merge_delta(delta)?;
+            cx.take_changes()
+        };
+        self.ai.game_updated(&self.game, &self.player, &changes).map_err(ai_errored)
+    }
+
+"#,
+        final_turn = final_turn
+    );
+
+    let takes_args = game
+        .ai
+        .functions
+        .values()
+        .any(|func| !func.arguments.is_empty());
+    if takes_args {
+        emit!(
+            out,
+            r#"
+    fn order(&mut self, name: &str, args: Vec<Value>) -> Result<Value, Error> {
+        let mut args = args.into_iter();
+"#
+        );
+    } else {
+        emit!(
+            out,
+            r#"
+    fn order(&mut self, name: &str, _args: Vec<Value>) -> Result<Value, Error> {
+"#
+        );
+    }
+    out.push_str("        let returned = match name {\n");
+    for (func_name, func) in game.ai.functions.iter() {
+        let name = sanitize(&underscore(func_name));
+        emit!(
+            out,
+            "            \"${func_name}\" => {\n",
+            func_name = func_name
+        );
+        for arg in &func.arguments {
+            emit!(
+                out,
+                "                let ${arg} = self.decode_arg(args.next())?;\n",
+                arg = sanitize(&underscore(&arg.name))
+            );
+        }
+        if func.arguments.is_empty() {
+            emit!(out, "                let returned = self.ai.${name}(&self.game, &self.player).map_err(ai_errored)?;\n",
+                name = name);
+        } else {
+            emit!(
+                out,
+                r#"
+                let returned = self.ai.${name}(
+                    &self.game,
+                    &self.player,
+"#,
+                name = name
+            );
+            for arg in &func.arguments {
+                emit!(
+                    out,
+                    "                    ${arg},\n",
+                    arg = sanitize(&underscore(&arg.name))
+                );
+            }
+            out.push_str("                ).map_err(ai_errored)?;\n");
+        }
+        emit!(
+            out,
+            r#"
+                serde_json::to_value(returned)?
+            }
+"#
+        );
+    }
+    emit!(
+        out,
+        r#"
+            _ => {
+                let message = format!("unknown order {:?}", name);
+                return Err((Exit::UnknownEventFromServer, message).into());
+            }
+        };
+
+        // The AI can't be told about invalid runs while it is still carrying out the order.
+        let messages = std::mem::take(&mut lock(&self.context).invalid_messages);
+        for message in messages {
+            self.ai.invalid(&message);
+        }
+        Ok(returned)
+    }
+
+    fn invalid(&mut self, message: &str) -> Result<(), Error> {
+        self.ai.invalid(message);
+        Ok(())
+    }
+
+    fn over(&mut self) -> Result<(), Error> {
+        end(&mut self.ai, &self.player);
+        Ok(())
+    }
+}
+
+/// A spectator waiting for its game to start, which is shown the state each time it changes.
+pub(crate) struct Watching<F> {
+    on_update: F,
+}
+
+impl<F> Watching<F> {
+    pub(crate) fn new(on_update: F) -> Watching<F> {
+        Watching { on_update }
+    }
+}
+
+impl<F> Spectator for Watching<F>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error>,
+{
+    fn game_name(&self) -> &str {
+        GAME_NAME
+    }
+
+    fn watch(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        let context = Context::new(Arc::clone(&connection), delta, AiSettings::new());
+        let game = lock(&context).game();
+        let mut watcher = Watcher {
+            context,
+            game,
+            on_update: self.on_update,
+        };
+        // Unlike an AI, the spectator is shown the initial state as a change.
+        watcher.delta(initial_delta)?;
+        runtime::run(&connection, &mut watcher).map(|_| ())
+    }
+
+    fn replay(self: Box<Self>, gamelog: &Gamelog) -> Result<(), Error> {
+        let context = offline_context(gamelog)?;
+        let game = lock(&context).game();
+        let mut watcher = Watcher {
+            context,
+            game,
+            on_update: self.on_update,
+        };
+        for delta in &gamelog.deltas {
+            if let Some(game_delta) = &delta.game {
+                watcher.delta(game_delta.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Forwards the runtime's deltas to a spectator. Spectators are never given orders.
+pub(crate) struct Watcher<F> {
+    context: Arc<Mutex<Context>>,
+    game: Game,
+    on_update: F,
+}
+
+impl<F> Handler for Watcher<F>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error>,
+{
+    fn delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        let changes = {
+            let mut cx = lock(&self.context);
+            cx.merge_delta(delta)?;
+            cx.take_changes()
+        };
+        (self.on_update)(&self.game, &changes).map_err(ai_errored)
+    }
+
+    fn order(&mut self, name: &str, _args: Vec<Value>) -> Result<Value, Error> {
+        let message = format!("spectators can't carry out order {:?}", name);
+        Err((Exit::UnknownEventFromServer, message).into())
+    }
+
+    fn invalid(&mut self, _message: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn over(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+pub trait Object: ObjectInner {
+}
+
+pub trait ObjectInner: Sized {
+    fn game_object(&self) -> &Arc<Mutex<AnyGameObject>>;
+
+    fn from_game_object(game_obj: &Arc<Mutex<AnyGameObject>>, context: &Weak<Mutex<Context>>) -> Option<Self>;
+}
+
+#[derive(Debug, Clone)]
+pub enum AnyGameObject {
+"#
+    );
+    for obj_key in game.objects.keys() {
+        emit!(out, "    ${obj_key}(${obj_key}Inner),\n", obj_key = obj_key);
+    }
+    emit!(
+        out,
+        r#"
+}
+
+impl AnyGameObject {
+    /// Creates an object with default state, given its `gameObjectName`.
+    pub fn new(game_object_name: &str) -> Option<AnyGameObject> {
+        match game_object_name {
+"#
+    );
+    for obj_key in game.objects.keys() {
+        emit!(
+            out,
+            "            \"${obj_key}\" => Some(AnyGameObject::${obj_key}(Default::default())),\n",
+            obj_key = obj_key
+        );
+    }
+    emit!(
+        out,
+        r#"
+            _ => None,
+        }
+    }
+
+    pub fn id(&self) -> Str {
+        self.as_game_object().id.clone()
+    }
+
+    pub fn object_type(&self) -> Str {
+        self.as_game_object().game_object_name.clone()
+    }
+"#
+    );
+    for obj_key in game.objects.keys() {
+        let module = underscore(obj_key);
+        emit!(
+            out,
+            r#"
+
+    pub fn try_as_${module}(&self) -> Option< &${obj_key}Base > {
+        match self {
+"#,
+            module = module,
+            obj_key = obj_key
+        );
+        for (sub_key, sub_obj) in game.objects.iter() {
+            if sub_key == obj_key
+                || game
+                    .all_parents(sub_obj)
+                    .iter()
+                    .any(|parent| parent == obj_key)
+            {
+                emit!(
+                    out,
+                    "            AnyGameObject::${sub_key}(obj) => Some(&obj.${module}),\n",
+                    sub_key = sub_key,
+                    module = module
+                );
+            } else {
+                emit!(
+                    out,
+                    "            AnyGameObject::${sub_key}(_obj) => None,\n",
+                    sub_key = sub_key
+                );
+            }
+        }
+        emit!(
+            out,
+            r#"
+        }
+    }
+
+    pub fn as_${module}(&self) -> &${obj_key}Base {
+        self.try_as_${module}().expect("unreachable: unable to cast to ${obj_key}")
+    }
+"#,
+            module = module,
+            obj_key = obj_key
+        );
+    }
+    emit!(
+        out,
+        r#"
+}
+
+impl DeltaMergeable<Context> for AnyGameObject {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        match self {
+"#
+    );
+    for obj_key in game.objects.keys() {
+        emit!(
+            out,
+            "            AnyGameObject::${obj_key}(obj) => obj.merge_delta(delta, cx),\n",
+            obj_key = obj_key
+        );
+    }
+    emit!(
+        out,
+        r#"
+        }
+    }
+}
+
+/// Converts the name of an attribute on the server to the name of its accessor, if it has one.
+fn field_name(attr_name: &str) -> Option<&'static str> {
+    match attr_name {
+"#
+    );
+    let attr_names: BTreeSet<&str> = game
+        .objects
+        .values()
+        .chain(Some(&game.game))
+        .flat_map(|obj| obj.attributes.keys())
+        .collect();
+    for attr_name in attr_names {
+        emit!(
+            out,
+            "        \"${attr_name}\" => Some(\"${name}\"),\n",
+            attr_name = attr_name,
+            name = sanitize(&underscore(attr_name))
+        );
+    }
+    emit!(
+        out,
+        r#"
+        // Unknown attributes are skipped when merging, so they aren't recorded as changes.
+        _ => None,
+    }
+}
+
+/// Servers may send attributes that are newer than these structures. They are skipped so the
+/// game can go on, with a warning the first time each one is seen.
+fn unknown_attribute(obj_key: &str, attr_name: &str) {
+    static WARNED: Mutex<BTreeSet<(String, String)>> = Mutex::new(BTreeSet::new());
+    if lock(&WARNED).insert((obj_key.to_string(), attr_name.to_string())) {
+        eprintln!("Warning: skipping unknown attribute {:?} of {}", attr_name, obj_key);
+    }
+}
+
+/// Object references that can't be null are still stored as options, since objects are created
+/// before they are merged. Checking them after each merge keeps their accessors infallible.
+fn missing_attribute(obj_key: &str, attr_name: &str) -> Error {
+    let message = format!("{} attribute {:?} was not set by the server", obj_key, attr_name);
+    (Exit::DeltaMergeFailure, message).into()
+}
+"#
+    );
+
+    for (obj_key, obj) in game.objects.iter() {
+        emit!(
+            out,
+            r#"
+
+#[derive(Debug, Clone, Default)]
+pub struct ${obj_key}Inner {
+    pub ${module}: ${obj_key}Base,
+"#,
+            obj_key = obj_key,
+            module = underscore(obj_key)
+        );
+        for parent in game.all_parents(obj) {
+            emit!(
+                out,
+                "    pub ${module}: ${parent}Base,\n",
+                module = underscore(&parent),
+                parent = parent
+            );
+        }
+        emit!(
+            out,
+            r#"
+}
+
+impl DeltaMergeable<Context> for ${obj_key}Inner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+"#,
+            obj_key = obj_key
+        );
+        let attributes = game.all_attributes(obj);
+        for (attr_name, _, parent) in &attributes {
+            emit!(out, "                \"${attr_name}\" => self.${base}.${name}.merge_delta(value, cx)?,\n",
+                attr_name = attr_name,
+                base = underscore(parent.as_deref().unwrap_or(obj_key)),
+                name = sanitize(&underscore(attr_name)),
+            );
+        }
+        emit!(
+            out,
+            r#"
+                _ => unknown_attribute("${obj_key}", &key),
+            }
+        }
+"#,
+            obj_key = obj_key
+        );
+        for (attr_name, attr, parent) in &attributes {
+            if game.is_required_object(&attr.ty) {
+                emit!(
+                    out,
+                    r#"
+        if self.${base}.${name}.is_none() {
+            return Err(missing_attribute("${obj_key}", "${attr_name}"));
+        }
+"#,
+                    base = underscore(parent.as_deref().unwrap_or(obj_key)),
+                    name = sanitize(&underscore(attr_name)),
+                    obj_key = obj_key,
+                    attr_name = attr_name,
+                );
+            }
+        }
+        emit!(
+            out,
+            r#"
+        Ok(())
+    }
+}
+"#
+        );
+    }
+
+    let bases = game.objects.iter().chain(Some(("Game", &game.game)));
+    for (obj_key, obj) in bases {
+        emit!(
+            out,
+            r#"
+
+#[derive(Debug, Clone, Default)]
+pub struct ${obj_key}Base {
+"#,
+            obj_key = obj_key
+        );
+        for (attr_name, attr) in obj.attributes.iter() {
+            emit!(
+                out,
+                "    pub ${name}: ${ty},\n",
+                name = sanitize(&underscore(attr_name)),
+                ty = game.internal_type(&attr.ty)
+            );
+        }
+        out.push_str("}\n");
+    }
+
+    emit!(
+        out,
+        r#"
+
+impl DeltaMergeable<Context> for GameBase {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+"#
+    );
+    for (attr_name, _) in game.game.attributes.iter() {
+        emit!(
+            out,
+            "                \"${attr_name}\" => self.${name}.merge_delta(value, cx)?,\n",
+            attr_name = attr_name,
+            name = sanitize(&underscore(attr_name))
+        );
+    }
+    emit!(
+        out,
+        r#"
+                _ => unknown_attribute("Game", &key),
+            }
+        }
+"#
+    );
+    for (attr_name, attr) in game.game.attributes.iter() {
+        if game.is_required_object(&attr.ty) {
+            emit!(
+                out,
+                r#"
+        if self.${name}.is_none() {
+            return Err(missing_attribute("Game", "${attr_name}"));
+        }
+"#,
+                name = sanitize(&underscore(attr_name)),
+                attr_name = attr_name
+            );
+        }
+    }
+    emit!(
+        out,
+        r#"
+        Ok(())
+    }
+}
+"#
+    );
+    out
+}
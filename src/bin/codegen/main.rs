@@ -0,0 +1,138 @@
+//! Generates the game modules in `src/games` from Cerveau's game structure files.
+//!
+//! Each `<game>.json` in the structure directory describes one game, and becomes the module
+//! `src/games/<game>`. Adding a game is a matter of dropping its structure file in and running:
+//!
+//! ```text
+//! cargo run --bin codegen
+//! ```
+//!
+//! Each game is compiled in by the cargo feature of the same name as its module, which has to be
+//! added to `Cargo.toml` along with the structure file.
+//!
+//! The structure files are Cerveau's own, and are replaced as they are when a game changes.
+//! What the client needs besides them goes in `overlays/<game>.json` in the structure
+//! directory, which is merged in when the game is generated:
+//!
+//! - `enums`: Strings with a fixed set of `literals` become enums. Cerveau doesn't name these, so
+//!   each is named after the object and attribute or argument it belongs to, like `PlayerColor`.
+//!   This maps the attribute or argument, like `Player.color` or `Unit.move(direction)`, to
+//!   another name instead, which can be shared by types with the same literals.
+//! - `settings`: The game settings that Cerveau accepts for the game, by attribute name.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+use structopt::StructOpt;
+
+use crate::names::underscore;
+use crate::structure::Game;
+
+/// Replaces each `${name}` in a template with the value given for it.
+///
+/// The first character of the template is dropped if it is a newline, so that templates can
+/// start on the line after the opening quote.
+macro_rules! emit {
+    ($out:expr, $text:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $out.push_str(&crate::template::fill(
+            $text,
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),*],
+        ))
+    };
+}
+
+mod ai;
+mod docs;
+mod enums;
+mod inner;
+mod module;
+mod names;
+mod object;
+mod snapshot;
+mod structure;
+mod template;
+mod traits;
+mod types;
+
+#[derive(StructOpt)]
+struct Args {
+    /// the directory containing the game structure files
+    #[structopt(long = "structures", default_value = "structures")]
+    structures: PathBuf,
+
+    /// the directory to write the game modules to
+    #[structopt(long = "out", default_value = "src/games")]
+    out: PathBuf,
+}
+
+fn main() {
+    let args = Args::from_args();
+    if let Err(err) = run(&args) {
+        eprintln!("codegen: {}", err);
+        exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let games = load(&args.structures)?;
+    for game in &games {
+        let dir = args.out.join(underscore(&game.name));
+        write_game(&dir, game).map_err(|err| format!("{}: {}", dir.display(), err))?;
+    }
+    let mod_rs = args.out.join("mod.rs");
+    fs::write(&mod_rs, module::games(&games))
+        .map_err(|err| format!("{}: {}", mod_rs.display(), err))
+}
+
+/// Reads every structure file in a directory, ordered by file name.
+fn load(dir: &Path) -> Result<Vec<Game>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("{}: {}", dir.display(), err))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths.iter().map(|path| Game::open(path)).collect()
+}
+
+/// Replaces the generated files of a game's module.
+fn write_game(dir: &Path, game: &Game) -> io::Result<()> {
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                fs::remove_file(path)?;
+            }
+        }
+    } else {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(dir.join("mod.rs"), module::game(game))?;
+    fs::write(dir.join("ai.rs"), ai::render(game))?;
+    fs::write(dir.join("inner.rs"), inner::render(game))?;
+    fs::write(dir.join("snapshot.rs"), snapshot::render(game))?;
+    if !game
+        .enums()
+        .expect("unreachable: enums are checked when opened")
+        .is_empty()
+    {
+        fs::write(dir.join("enums.rs"), enums::render(game))?;
+    }
+    fs::write(
+        dir.join("game.rs"),
+        object::render(game, "Game", &game.game),
+    )?;
+    for (obj_key, obj) in game.objects.iter() {
+        let path = dir.join(format!("{}.rs", underscore(obj_key)));
+        fs::write(path, object::render(game, obj_key, obj))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,267 @@
+//! Renders the `mod.rs` of each game, and the list of games in `src/games/mod.rs`.
+
+use crate::docs::obj_doc;
+use crate::names::underscore;
+use crate::structure::{Enum, Game};
+
+pub fn games(games: &[Game]) -> String {
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+//! Per-game APIs and AI implementations.
+//!
+//! Everything here except `printer.rs`, `registry.rs` and `traits.rs` is generated from the game
+//! structures in `structures/` by the `codegen` binary.
+
+mod printer;
+mod registry;
+mod traits;
+
+pub use printer::print_changes;
+pub use registry::{Registry, Session, Spectator};
+pub use traits::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
+
+use crate::error::Error;
+
+"#
+    );
+    for game in games {
+        emit!(
+            out,
+            r#"
+#[cfg(feature = "${module}")]
+pub mod ${module};
+"#,
+            module = underscore(&game.name)
+        );
+    }
+    emit!(
+        out,
+        r#"
+
+/// Every game with a module, by its name on the server, along with the cargo feature that
+/// compiles it in and whether that feature is enabled.
+const FEATURES: &[(&str, &str, bool)] = &[
+"#
+    );
+    for game in games {
+        emit!(
+            out,
+            "    (\"${game_name}\", \"${module}\", cfg!(feature = \"${module}\")),\n",
+            game_name = game.name,
+            module = underscore(&game.name)
+        );
+    }
+    emit!(
+        out,
+        r#"
+];
+
+/// A spectator that prints what changes in the game with the given name, as resolved by the
+/// server.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the game is
+/// unknown or its module was left out of the build.
+pub fn printer(game_name: &str) -> Result<Box<dyn Spectator>, Error> {
+    match game_name {
+"#
+    );
+    for game in games {
+        emit!(
+            out,
+            r#"
+        #[cfg(feature = "${module}")]
+        ${module}::GAME_NAME => Ok(${module}::printer()),
+"#,
+            module = underscore(&game.name)
+        );
+    }
+    emit!(
+        out,
+        r#"
+        _ => Err(registry::not_found(game_name, "there is no module for")),
+    }
+}
+"#
+    );
+    out
+}
+
+pub fn game(game: &Game) -> String {
+    let enums = game
+        .enums()
+        .expect("unreachable: enums are checked when opened");
+    let mut obj_keys: Vec<_> = game.objects.keys().collect();
+    obj_keys.sort_unstable();
+
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+${doc}
+
+mod inner;
+
+mod ai;
+pub mod snapshot;
+"#,
+        doc = obj_doc(&game.game, "//! ")
+    );
+    if !enums.is_empty() {
+        out.push_str("mod enums;\n");
+    }
+    for obj_key in &obj_keys {
+        emit!(out, "mod ${module};\n", module = underscore(obj_key));
+    }
+    out.push_str("mod game;\n\n");
+    for obj_key in &obj_keys {
+        emit!(
+            out,
+            "pub use ${module}::${obj_key};\n",
+            module = underscore(obj_key),
+            obj_key = obj_key
+        );
+    }
+    emit!(
+        out,
+        r#"
+pub use game::Game;
+"#
+    );
+    if !enums.is_empty() {
+        emit!(
+            out,
+            "\n\npub use enums::${names};\n",
+            names = import_list(&enums)
+        );
+    }
+    let settings_import = if game.settings.is_empty() {
+        "GameSetting"
+    } else {
+        "{GameSetting, SettingKind}"
+    };
+    emit!(
+        out,
+        r#"
+
+pub use ai::AI;
+
+pub use snapshot::Snapshot;
+
+pub use inner::Object;
+
+pub use crate::client::delta::Changes;
+
+use crate::client::gamelog::Gamelog;
+use crate::client::settings::${settings_import};
+use crate::client::{Client, GameOutcome};
+use crate::error::Error;
+use crate::games::{print_changes, Registry, Spectator};
+
+/// The name of this game on the server.
+pub const GAME_NAME: &str = "${game_name}";
+
+/// The settings the server accepts for this game, besides the
+/// [`BASE_SETTINGS`](crate::client::settings::BASE_SETTINGS) every game accepts.
+///
+/// Each sets the initial value of the [`Game`] attribute of the same name.
+pub const SETTINGS: &[GameSetting] = &[${settings}];
+
+/// Registers an AI for this game. `new_ai` is called to create a fresh AI for each game played.
+pub fn register<A, F>(registry: &mut Registry, new_ai: F)
+where
+    A: AI + 'static,
+    F: Fn() -> A + 'static,
+{
+    registry.insert(GAME_NAME, move || Box::new(inner::Pending::new(new_ai())));
+}
+
+/// Plays one game of this game on the server the client is configured for, with the given AI.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the server
+/// resolves the client's game to a different game.
+pub fn play<A: AI + 'static>(client: &Client, ai: A) -> Result<GameOutcome, Error> {
+    client.play_session(Box::new(inner::Pending::new(ai)))
+}
+
+/// Watches one game of this game on the server the client is configured for, without playing.
+///
+/// `on_update` is called with the whole initial state as created, and then each time the state
+/// changes, until the game is over. Fails like [`play`] does if the server resolves the
+/// client's game to a different game.
+pub fn spectate<F>(client: &Client, on_update: F) -> Result<(), Error>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error> + 'static,
+{
+    client.spectate_session(Box::new(inner::Watching::new(on_update)))
+}
+
+/// A spectator that prints what changes in the game with [`print_changes`], as used by
+/// `joueur --spectate`.
+pub fn printer() -> Box<dyn Spectator> {
+    Box::new(inner::Watching::new(|game: &Game, changes: &Changes| {
+        print_changes(game, changes);
+        Ok(())
+    }))
+}
+
+/// Replays a gamelog of this game offline, calling the AI's hooks as the state changes.
+///
+/// The AI sees the game from the view of the player with the given index. No orders are given,
+/// and game object functions fail since there is no server to run them. The AI is returned
+/// afterwards, so that anything it recorded along the way can be inspected.
+pub fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, ai: A) -> Result<A, Error> {
+    inner::replay(gamelog, player_index, ai)
+}
+"#,
+        game_name = game.name,
+        settings_import = settings_import,
+        settings = settings(game),
+    );
+    out
+}
+
+/// The entries of the `SETTINGS` list, each on its own line.
+fn settings(game: &Game) -> String {
+    let settings = game
+        .settings()
+        .expect("unreachable: settings are checked when opened");
+    let mut out = String::new();
+    for (name, attr) in settings {
+        let kind = match attr.ty.name.as_str() {
+            "int" => "Int",
+            "float" => "Float",
+            "boolean" => "Boolean",
+            _ => "String",
+        };
+        emit!(
+            out,
+            r#"
+    GameSetting {
+        name: "${name}",
+        kind: SettingKind::${kind},
+        description: ${description},
+    },
+"#,
+            name = name,
+            kind = kind,
+            description = format!("{:?}", attr.description)
+        );
+    }
+    if !out.is_empty() {
+        out.insert(0, '\n');
+    }
+    out
+}
+
+/// The names of enums as the last part of a `use` declaration.
+pub fn import_list(enums: &[Enum]) -> String {
+    match enums {
+        [e] => e.name.to_string(),
+        enums => {
+            let names: Vec<_> = enums.iter().map(|e| e.name).collect();
+            format!("{{{}}}", names.join(", "))
+        }
+    }
+}
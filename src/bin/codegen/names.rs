@@ -0,0 +1,73 @@
+//! Converting names from the game structure into Rust identifiers.
+
+/// Converts a `camelCase` or `PascalCase` name to `snake_case`.
+pub fn underscore(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+
+    // First split before each capitalized word, like "Game|Object"...
+    let mut words = Vec::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let capitalized = chars.get(i + 1).is_some_and(char::is_ascii_uppercase)
+            && chars.get(i + 2).is_some_and(char::is_ascii_lowercase);
+        if capitalized {
+            let end = i
+                + 2
+                + chars[i + 2..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_lowercase())
+                    .count();
+            words.push(chars[i]);
+            words.push('_');
+            words.extend(&chars[i + 1..end]);
+            i = end;
+        } else {
+            words.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    // ...then before capitals that follow lowercase letters or digits, like "tile|X".
+    let mut result = String::with_capacity(words.len());
+    let mut i = 0;
+    while i < words.len() {
+        let c = words[i];
+        result.push(c);
+        let boundary = (c.is_ascii_lowercase() || c.is_ascii_digit())
+            && words.get(i + 1).is_some_and(char::is_ascii_uppercase);
+        if boundary {
+            result.push('_');
+            result.push(words[i + 1]);
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    result.to_lowercase()
+}
+
+/// Renames identifiers that are Rust keywords.
+pub fn sanitize(name: &str) -> String {
+    match name {
+        "move" => "move_".to_string(),
+        "type" => "type_".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// Converts a string literal, like `redium ore`, to the name of an enum variant, like `RediumOre`.
+pub fn variant(literal: &str) -> String {
+    let words = literal.split(|c: char| !c.is_ascii_alphanumeric());
+    let name: String = words
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase());
+            first.into_iter().chain(chars)
+        })
+        .collect();
+    if name.is_empty() {
+        "Empty".to_string()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,350 @@
+//! Renders the handle of the game, or of one game object type, in `<object>.rs`.
+
+use crate::docs::{attr_doc, func_doc, obj_doc};
+use crate::names::{sanitize, underscore};
+use crate::structure::{Game, Object};
+use crate::traits;
+
+pub fn render(game: &Game, obj_key: &str, obj: &Object) -> String {
+    let is_game = obj_key == "Game";
+    let (doc, inner_type) = if is_game {
+        (
+            "/// Holds top-level game state and settings for the current game.".to_string(),
+            "inner::GameBase",
+        )
+    } else {
+        (obj_doc(obj, "/// "), "inner::AnyGameObject")
+    };
+
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+#![allow(dead_code, unused_imports)]
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
+use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
+use crate::types::*;
+use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
+
+${doc}
+#[derive(Debug, Clone)]
+pub struct ${obj_key} {
+    context: Weak<Mutex<inner::Context>>,
+    inner: Arc<Mutex<${inner_type}>>,
+}
+
+impl ${obj_key} {
+    pub(crate) fn new(inner: Arc<Mutex<${inner_type}>>, context: Weak<Mutex<inner::Context>>) -> ${obj_key} {
+        ${obj_key} { inner, context }
+    }
+
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
+    {
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
+        f(&mut handle)
+    }
+"#,
+        doc = doc,
+        obj_key = obj_key,
+        inner_type = inner_type
+    );
+
+    for (attr_name, attr, parent) in game.all_attributes(obj) {
+        let name = sanitize(&underscore(attr_name));
+        emit!(
+            out,
+            r#"
+
+${doc}
+    pub fn ${name}(&self) -> ${ty} {
+        lock(&self.inner)
+"#,
+            doc = attr_doc(attr, parent.as_deref(), "    /// "),
+            name = name,
+            ty = game.return_type(&attr.ty),
+        );
+        if !is_game {
+            emit!(
+                out,
+                "            .as_${base}()\n",
+                base = underscore(parent.as_deref().unwrap_or(obj_key))
+            );
+        }
+        if game.is_copy(&attr.ty) {
+            emit!(out, "            .${name}\n", name = name);
+        } else if game.is_required_object(&attr.ty) {
+            emit!(
+                out,
+                r#"
+            .${name}.clone()
+            .expect("unreachable: required attributes are checked when merged")
+"#,
+                name = name
+            );
+        } else {
+            emit!(out, "            .${name}.clone()\n", name = name);
+        }
+        out.push_str("    }\n");
+    }
+
+    for (func_name, func, parent) in game.all_functions(obj) {
+        emit!(
+            out,
+            r#"
+
+${doc}
+    pub fn ${name}(
+        &self,
+"#,
+            doc = func_doc(func, parent.as_deref(), "    /// "),
+            name = sanitize(&underscore(func_name)),
+        );
+        for arg in &func.arguments {
+            emit!(
+                out,
+                "        ${name}: ${ty},\n",
+                name = sanitize(&underscore(&arg.name)),
+                ty = game.arg_type(&arg.ty, None),
+            );
+        }
+        let returns = match &func.returns {
+            Some(returns) => game.return_type(&returns.ty),
+            None => "()".to_string(),
+        };
+        emit!(
+            out,
+            r#"
+    )
+        -> Result<${returns}, Error>
+    {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+"#,
+            returns = returns
+        );
+        for arg in &func.arguments {
+            emit!(
+                out,
+                "            ${name}: ${ty},\n",
+                name = sanitize(&underscore(&arg.name)),
+                ty = game.arg_type(&arg.ty, Some("a")),
+            );
+        }
+        emit!(
+            out,
+            r#"
+            #[serde(skip)]
+            _a: PhantomData< &'a () >,
+        }
+        let args = Args {
+"#
+        );
+        for arg in &func.arguments {
+            emit!(
+                out,
+                "            ${name},\n",
+                name = sanitize(&underscore(&arg.name))
+            );
+        }
+        emit!(
+            out,
+            r#"
+            _a: PhantomData,
+        };
+        self.with_context(|cx| cx.run(&self.id(), "${func_name}", args))
+    }
+"#,
+            func_name = func_name
+        );
+    }
+
+    if is_game {
+        emit!(
+            out,
+            r#"
+
+    /// Looks up a game object by its id.
+    ///
+    /// Returns `None` if there is no such object, if it has been removed from the game, or if it
+    /// is not a `T`.
+    pub fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.inner).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// The settings given to the AI for this game, like `--aiSettings` on the command line.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn ai_settings(&self) -> Result<AiSettings, Error> {
+        self.with_context(|cx| Ok(cx.ai_settings().clone()))
+    }
+
+    /// Takes an immutable copy of the whole game state, which can be read without locking.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        self.with_context(|cx| Ok(cx.snapshot()))
+    }
+"#
+        );
+    } else {
+        emit!(
+            out,
+            r#"
+
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
+    pub fn try_cast<T: Object>(&self) -> Option<T> {
+        T::from_game_object(&self.inner, &self.context)
+    }
+
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+"#
+        );
+    }
+
+    emit!(
+        out,
+        r#"
+}
+
+/// Handles are equal when they refer to the same ${what}, regardless of its state.
+impl PartialEq for ${obj_key} {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for ${obj_key} {}
+
+/// Handles hash by the identity of the ${what} they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for ${obj_key} {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+"#,
+        what = if is_game { "game" } else { "game object" },
+        obj_key = obj_key
+    );
+
+    if !is_game {
+        emit!(
+            out,
+            r#"
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for ${obj_key} {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ${obj_key} {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
+    }
+}
+
+impl inner::ObjectInner for ${obj_key} {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
+    fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
+        let handle = lock(game_obj);
+        if handle.try_as_${module}().is_some() {
+            Some(${obj_key} {
+                inner: Arc::clone(game_obj),
+                context: context.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+impl Object for ${obj_key} {}
+
+impl Serialize for ${obj_key} {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for ${obj_key} {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for ${obj_key} {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+"#,
+            obj_key = obj_key,
+            module = underscore(obj_key)
+        );
+    }
+    out.push_str(&traits::render(game, obj_key, obj));
+    out
+}
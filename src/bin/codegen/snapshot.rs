@@ -0,0 +1,315 @@
+//! Renders `snapshot.rs`, the immutable copies of the game state.
+
+use crate::docs::attr_doc;
+use crate::module::import_list;
+use crate::names::{sanitize, underscore};
+use crate::structure::{Game, Type};
+
+impl Game {
+    /// The type of a value read from a snapshot, which borrows from it where possible.
+    fn view_type(&self, ty: &Type) -> String {
+        if self.is_copy(ty) {
+            self.owned_type(ty)
+        } else if let (Some(enum_name), false) = (&ty.enum_name, ty.nullable) {
+            format!("&'a {}", enum_name)
+        } else if ty.name == "string" && !ty.nullable {
+            "&'a Str".to_string()
+        } else if ty.name == "list" && !self.is_object(ty.value_type()) {
+            format!("&'a {}", self.owned_type(ty))
+        } else if ty.name == "list" {
+            format!("Vec<{}<'a>>", ty.value_type().name)
+        } else if ty.name == "dictionary" && self.is_object(ty.value_type()) {
+            format!("HashMap<&'a str, {}<'a>>", ty.value_type().name)
+        } else if self.is_object(ty) {
+            format!("Option<{}<'a>>", ty.name)
+        } else {
+            panic!("no snapshot view for type {:?}", ty.name)
+        }
+    }
+
+    /// The expression that reads a field of a snapshot as its view type.
+    fn view_expr(&self, ty: &Type, field: &str) -> String {
+        if self.is_copy(ty) {
+            field.to_string()
+        } else if ty.name == "string" || (ty.name == "list" && !self.is_object(ty.value_type())) {
+            format!("&{}", field)
+        } else if ty.name == "list" {
+            format!(
+                "{}\n            .iter()\n            .filter_map(|obj| {}::resolve(self.snapshot, obj))\n            .collect()",
+                field,
+                ty.value_type().name,
+            )
+        } else if ty.name == "dictionary" {
+            format!(
+                "{}\n            .iter()\n            .filter_map(|(key, obj)| Some((key.as_slice(), {}::resolve(self.snapshot, obj)?)))\n            .collect()",
+                field,
+                ty.value_type().name,
+            )
+        } else {
+            format!(
+                "{}\n            .as_ref()\n            .and_then(|obj| {}::resolve(self.snapshot, obj))",
+                field, ty.name,
+            )
+        }
+    }
+}
+
+pub fn render(game: &Game) -> String {
+    let enums = game
+        .enums()
+        .expect("unreachable: enums are checked when opened");
+    let enum_imports = if enums.is_empty() {
+        String::new()
+    } else {
+        format!("use super::{};\n", import_list(&enums))
+    };
+
+    let mut out = String::new();
+    emit!(
+        out,
+        r#"
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+${enum_imports}
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+"#,
+        enum_imports = enum_imports
+    );
+    for obj_key in game.objects.keys() {
+        emit!(
+            out,
+            r#"
+
+    /// The state of a live [`${obj_key}`](super::${obj_key}) at the time of this snapshot.
+    pub fn ${module}(&self, obj: &super::${obj_key}) -> Option<${obj_key}<'_>> {
+        ${obj_key}::resolve(&self.state, obj)
+    }
+"#,
+            obj_key = obj_key,
+            module = underscore(obj_key)
+        );
+    }
+    emit!(
+        out,
+        r#"
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+"#
+    );
+    for (i, (attr_name, attr)) in game.game.attributes.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let name = sanitize(&underscore(attr_name));
+        emit!(
+            out,
+            r#"
+${doc}
+    pub fn ${name}(&self) -> ${ty} {
+        ${expr}
+    }
+"#,
+            doc = attr_doc(attr, None, "    /// "),
+            name = name,
+            ty = game.view_type(&attr.ty),
+            expr = game.view_expr(&attr.ty, &format!("self.snapshot.game.{}", name)),
+        );
+    }
+    emit!(
+        out,
+        r#"
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+"#
+    );
+
+    for (obj_key, obj) in game.objects.iter() {
+        let module = underscore(obj_key);
+        emit!(
+            out,
+            r#"
+
+/// A read-only view of a [`${obj_key}`](super::${obj_key}) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct ${obj_key}<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> ${obj_key}<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<${obj_key}<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_${module}()?;
+        Some(${obj_key} { snapshot, state })
+    }
+"#,
+            obj_key = obj_key,
+            module = module
+        );
+        for (attr_name, attr, parent) in game.all_attributes(obj) {
+            let name = sanitize(&underscore(attr_name));
+            let field = format!(
+                "self.state.as_{}().{}",
+                underscore(parent.as_deref().unwrap_or(obj_key)),
+                name,
+            );
+            emit!(
+                out,
+                r#"
+
+${doc}
+    pub fn ${name}(&self) -> ${ty} {
+        ${expr}
+    }
+"#,
+                doc = attr_doc(attr, parent.as_deref(), "    /// "),
+                name = name,
+                ty = game.view_type(&attr.ty),
+                expr = game.view_expr(&attr.ty, &field),
+            );
+        }
+        emit!(
+            out,
+            r#"
+}
+
+impl<'a> fmt::Debug for ${obj_key}<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("${obj_key}").field("id", &self.state.id()).finish()
+    }
+}
+"#,
+            obj_key = obj_key
+        );
+        for parent in game.all_parents(obj) {
+            emit!(
+                out,
+                r#"
+
+impl<'a> From<${obj_key}<'a>> for ${parent}<'a> {
+    fn from(obj: ${obj_key}<'a>) -> ${parent}<'a> {
+        ${parent} {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+"#,
+                obj_key = obj_key,
+                parent = parent
+            );
+        }
+        if obj_key != "GameObject" {
+            emit!(
+                out,
+                r#"
+
+impl<'a> TryFrom<GameObject<'a>> for ${obj_key}<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<${obj_key}<'a>, GameObject<'a>> {
+        match obj.state.try_as_${module}() {
+            Some(_) => Ok(${obj_key} {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+"#,
+                obj_key = obj_key,
+                module = module
+            );
+        }
+    }
+    emit!(
+        out,
+        r#"
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
+"#
+    );
+    out
+}
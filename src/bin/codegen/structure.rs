@@ -0,0 +1,472 @@
+//! The game structure files that Cerveau describes each game with.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_derive::Deserialize;
+
+use crate::names::{sanitize, underscore, variant};
+
+/// A JSON object whose entries are kept in the order they appear in the file.
+///
+/// The generated code lists attributes and functions in the same order as the structure, so the
+/// usual sorted map won't do.
+#[derive(Debug)]
+pub struct Ordered<T>(pub Vec<(String, T)>);
+
+impl<T> Ordered<T> {
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(key, _)| key.as_str())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().map(|(_, value)| value)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&T> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+}
+
+impl<T> Default for Ordered<T> {
+    fn default() -> Ordered<T> {
+        Ordered(Vec::new())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Ordered<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for OrderedVisitor<T> {
+            type Value = Ordered<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Ordered<T>, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Ordered(entries))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedVisitor(PhantomData))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Type {
+    pub name: String,
+    pub nullable: bool,
+    pub key_type: Option<Box<Type>>,
+    pub value_type: Option<Box<Type>>,
+    /// The only values a string can take, if it has a fixed set of them.
+    pub literals: Option<Vec<String>>,
+    /// The name of the enum generated for the literals. Cerveau doesn't name them, so unless the
+    /// game's overlay gives one, it is named after where it is used when the file is opened.
+    #[serde(skip)]
+    pub enum_name: Option<String>,
+}
+
+impl Type {
+    pub fn key_type(&self) -> &Type {
+        self.key_type
+            .as_ref()
+            .expect("dictionary without a key type")
+    }
+
+    pub fn value_type(&self) -> &Type {
+        self.value_type
+            .as_ref()
+            .expect("container without a value type")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Attribute {
+    #[serde(rename = "type")]
+    pub ty: Type,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Argument {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Type,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Returns {
+    #[serde(rename = "type")]
+    pub ty: Type,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Function {
+    pub description: String,
+    pub arguments: Vec<Argument>,
+    pub returns: Option<Returns>,
+}
+
+/// The game itself, or one of its game object types.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Object {
+    pub name: Option<String>,
+    pub description: String,
+    #[serde(default)]
+    pub parent_classes: Vec<String>,
+    #[serde(default)]
+    pub attributes: Ordered<Attribute>,
+    #[serde(default)]
+    pub functions: Ordered<Function>,
+}
+
+/// The orders that an AI carries out.
+#[derive(Debug, Default, Deserialize)]
+pub struct Ai {
+    pub functions: Ordered<Function>,
+}
+
+/// What the client needs to know about a game besides what Cerveau's structure says, as read
+/// from `overlays/<game>.json` next to the structure file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Overlay {
+    /// Names for the enums of attributes and arguments, like `"Unit.move(direction)":
+    /// "Direction"`, which lets several of them share one enum.
+    #[serde(default)]
+    pub enums: BTreeMap<String, String>,
+    /// The attributes of the game that can be set through the game settings, which Cerveau
+    /// doesn't list in its structure.
+    #[serde(default)]
+    pub settings: Vec<String>,
+}
+
+impl Overlay {
+    /// Reads the overlay of a structure file, if it has one.
+    fn open(structure: &Path) -> Result<Overlay, String> {
+        let path = match (structure.parent(), structure.file_name()) {
+            (Some(dir), Some(file_name)) => dir.join("overlays").join(file_name),
+            _ => return Ok(Overlay::default()),
+        };
+        if !path.exists() {
+            return Ok(Overlay::default());
+        }
+        let file = File::open(&path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+}
+
+/// The structure of one game, as read from `<game>.json` and its overlay.
+///
+/// The file maps `Game`, `AI` and the name of each game object type to its description.
+#[derive(Debug)]
+pub struct Game {
+    pub name: String,
+    pub game: Object,
+    pub ai: Ai,
+    pub objects: Ordered<Object>,
+    /// The attributes of the game that can be set through the game settings.
+    pub settings: Vec<String>,
+}
+
+impl Game {
+    pub fn open(path: &Path) -> Result<Game, String> {
+        let file = File::open(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        let Entries { game, ai, objects } = serde_json::from_reader(BufReader::new(file))
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        let Overlay {
+            mut enums,
+            settings,
+        } = Overlay::open(path)?;
+
+        let mut game = game.ok_or_else(|| format!("{}: missing Game", path.display()))?;
+        let mut objects = objects;
+        name_enums("Game", &mut game, &mut enums)
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        for (obj_key, obj) in &mut objects {
+            name_enums(obj_key, obj, &mut enums)
+                .map_err(|err| format!("{}: {}", path.display(), err))?;
+        }
+        if let Some(label) = enums.keys().next() {
+            let message = format!("overlay names the enum of {}, which doesn't exist", label);
+            return Err(format!("{}: {}", path.display(), message));
+        }
+        let name = game
+            .name
+            .clone()
+            .ok_or_else(|| format!("{}: missing Game.name", path.display()))?;
+        let game = Game {
+            name,
+            game,
+            ai,
+            objects: Ordered(objects),
+            settings,
+        };
+        game.enums()
+            .and_then(|_| game.settings().map(|_| ()))
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        Ok(game)
+    }
+
+    pub fn object(&self, name: &str) -> &Object {
+        self.objects
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown game object type {:?}", name))
+    }
+
+    pub fn is_object(&self, ty: &Type) -> bool {
+        self.objects.get(&ty.name).is_some()
+    }
+
+    /// Every string type with literals, in the order they first appear.
+    ///
+    /// Strings that share an enum must have the same literals.
+    pub fn enums(&self) -> Result<Vec<Enum<'_>>, String> {
+        let mut enums = Vec::new();
+        let objects = Some(("Game", &self.game))
+            .into_iter()
+            .chain(self.objects.iter());
+        for (obj_key, obj) in objects {
+            for (attr_name, attr) in obj.attributes.iter() {
+                let name = sanitize(&underscore(attr_name));
+                let place = format!("[`{0}::{1}`](super::{0}::{1})", obj_key, name);
+                let label = format!("{}.{}", obj_key, attr_name);
+                add_enum(&mut enums, &attr.ty, place, label)?;
+            }
+            for (func_name, func) in obj.functions.iter() {
+                let name = sanitize(&underscore(func_name));
+                for arg in &func.arguments {
+                    let place = format!(
+                        "the `{2}` argument of [`{0}::{1}`](super::{0}::{1})",
+                        obj_key,
+                        name,
+                        sanitize(&underscore(&arg.name)),
+                    );
+                    let label = format!("{}.{}({})", obj_key, func_name, arg.name);
+                    add_enum(&mut enums, &arg.ty, place, label)?;
+                }
+            }
+        }
+        Ok(enums)
+    }
+
+    /// The game attributes that can be set through the game settings, in the order they are
+    /// listed.
+    ///
+    /// Settings must be attributes of the game with a number or boolean type.
+    pub fn settings(&self) -> Result<Vec<(&str, &Attribute)>, String> {
+        let mut settings = Vec::new();
+        for name in &self.settings {
+            let attr = self
+                .game
+                .attributes
+                .get(name)
+                .ok_or_else(|| format!("setting {:?} is not an attribute of Game", name))?;
+            match attr.ty.name.as_str() {
+                "int" | "float" | "boolean" | "string" => settings.push((name.as_str(), attr)),
+                other => return Err(format!("setting {:?} has unsupported type {}", name, other)),
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Every ancestor of an object, with direct parents first and `GameObject` last.
+    pub fn all_parents(&self, obj: &Object) -> Vec<String> {
+        let mut parents: Vec<String> = obj.parent_classes.clone();
+        for parent in &obj.parent_classes {
+            for ancestor in self.all_parents(self.object(parent)) {
+                // Ancestors shared by several parents move to the back.
+                parents.retain(|name| *name != ancestor);
+                parents.push(ancestor);
+            }
+        }
+        parents
+    }
+
+    /// Every attribute of an object, along with the parent it is inherited from.
+    pub fn all_attributes<'a>(
+        &'a self,
+        obj: &'a Object,
+    ) -> Vec<(&'a str, &'a Attribute, Option<String>)> {
+        let mut attributes: Vec<_> = obj.attributes.iter().map(|(n, a)| (n, a, None)).collect();
+        for parent in self.all_parents(obj) {
+            for (name, attr) in self.object(&parent).attributes.iter() {
+                attributes.push((name, attr, Some(parent.clone())));
+            }
+        }
+        attributes
+    }
+
+    /// Every function of an object, along with the parent it is inherited from.
+    pub fn all_functions<'a>(
+        &'a self,
+        obj: &'a Object,
+    ) -> Vec<(&'a str, &'a Function, Option<String>)> {
+        let mut functions: Vec<_> = obj.functions.iter().map(|(n, f)| (n, f, None)).collect();
+        for parent in self.all_parents(obj) {
+            for (name, func) in self.object(&parent).functions.iter() {
+                functions.push((name, func, Some(parent.clone())));
+            }
+        }
+        functions
+    }
+}
+
+/// The top level of a structure file, which mixes the game, the AI and the game objects.
+///
+/// Entries are told apart by their key, and read straight from the file so that the order of
+/// their attributes is kept.
+struct Entries {
+    game: Option<Object>,
+    ai: Ai,
+    objects: Vec<(String, Object)>,
+}
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = Entries;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a game structure")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Entries, A::Error> {
+                let mut entries = Entries {
+                    game: None,
+                    ai: Ai::default(),
+                    objects: Vec::new(),
+                };
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "AI" => entries.ai = map.next_value()?,
+                        "Game" => entries.game = Some(map.next_value()?),
+                        _ => {
+                            let obj = map.next_value()?;
+                            entries.objects.push((key, obj));
+                        }
+                    }
+                }
+                Ok(entries)
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// A string type with a fixed set of values.
+pub struct Enum<'a> {
+    pub name: &'a str,
+    pub literals: &'a [String],
+    /// Links to where the enum is used, for its documentation.
+    pub uses: Vec<String>,
+}
+
+/// Names the enums of an object's attributes and function arguments, taking the names given in
+/// `names` out of it. The rest are named after where they are used, like `PlayerColor` for
+/// `Player.color` or `UnitAttackTarget` for the `target` argument of `Unit.attack`.
+fn name_enums(
+    obj_key: &str,
+    obj: &mut Object,
+    names: &mut BTreeMap<String, String>,
+) -> Result<(), String> {
+    for (attr_name, attr) in &mut obj.attributes.0 {
+        let label = format!("{}.{}", obj_key, attr_name);
+        let name = format!("{}{}", obj_key, variant(attr_name));
+        name_enum(&mut attr.ty, names.remove(&label), name, &label)?;
+    }
+    for (func_name, func) in &mut obj.functions.0 {
+        for arg in &mut func.arguments {
+            let label = format!("{}.{}({})", obj_key, func_name, arg.name);
+            let name = format!("{}{}{}", obj_key, variant(func_name), variant(&arg.name));
+            name_enum(&mut arg.ty, names.remove(&label), name, &label)?;
+        }
+    }
+    Ok(())
+}
+
+fn name_enum(
+    ty: &mut Type,
+    given: Option<String>,
+    name: String,
+    label: &str,
+) -> Result<(), String> {
+    match &mut ty.value_type {
+        Some(value_type) => name_enum(value_type, given, name, label),
+        None if ty.literals.is_some() => {
+            ty.enum_name = Some(given.unwrap_or(name));
+            Ok(())
+        }
+        None if given.is_some() => Err(format!(
+            "overlay names the enum of {}, which has no literals",
+            label
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Adds a use of a type to the enum of its literals, if it has any.
+///
+/// `place` links to the use in the enum's documentation, and `label` names it in errors.
+fn add_enum<'a>(
+    enums: &mut Vec<Enum<'a>>,
+    ty: &'a Type,
+    place: String,
+    label: String,
+) -> Result<(), String> {
+    let mut ty = ty;
+    let mut place = place;
+    while let Some(value_type) = &ty.value_type {
+        ty = value_type;
+        place = format!("the elements of {}", place);
+    }
+    let literals = match &ty.literals {
+        Some(literals) => literals,
+        None => return Ok(()),
+    };
+    let name = ty
+        .enum_name
+        .as_deref()
+        .expect("unreachable: enums are named when opened");
+    match enums.iter_mut().find(|existing| existing.name == name) {
+        Some(existing) if existing.literals != literals.as_slice() => Err(format!(
+            "{} has different literals than the other uses of {}",
+            label, name
+        )),
+        Some(existing) => {
+            existing.uses.push(place);
+            Ok(())
+        }
+        None => {
+            enums.push(Enum {
+                name,
+                literals,
+                uses: vec![place],
+            });
+            Ok(())
+        }
+    }
+}
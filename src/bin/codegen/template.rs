@@ -0,0 +1,22 @@
+//! The substitution behind the `emit!` macro.
+
+use std::fmt::Display;
+
+pub fn fill(text: &str, vars: &[(&str, &dyn Display)]) -> String {
+    let text = text.strip_prefix('\n').unwrap_or(text);
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let end = start + rest[start..].find('}').expect("unterminated substitution");
+        let name = &rest[start + 2..end];
+        let (_, value) = vars
+            .iter()
+            .find(|(var, _)| *var == name)
+            .unwrap_or_else(|| panic!("no value given for ${{{}}}", name));
+        result.push_str(&value.to_string());
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,197 @@
+//! Renders the impls of the traits in `src/games/traits.rs`, which each object file ends with.
+//!
+//! The trait methods call the inherent methods of the same name, which take precedence.
+
+use crate::structure::{Game, Object};
+
+/// Whether an object has every one of the given attributes.
+fn has_attributes(game: &Game, obj: &Object, names: &[&str]) -> bool {
+    let attributes = game.all_attributes(obj);
+    names
+        .iter()
+        .all(|name| attributes.iter().any(|(attr_name, _, _)| attr_name == name))
+}
+
+fn is_grid_tile(game: &Game) -> bool {
+    match game.objects.get("Tile") {
+        Some(tile) => has_attributes(
+            game,
+            tile,
+            &["x", "y", "tileNorth", "tileEast", "tileSouth", "tileWest"],
+        ),
+        None => false,
+    }
+}
+
+pub fn render(game: &Game, obj_key: &str, obj: &Object) -> String {
+    let mut out = String::new();
+    if obj_key == "Game" {
+        emit!(
+            out,
+            r#"
+
+impl AnyGame for Game {
+    type GameObject = GameObject;
+    type Player = Player;
+
+    fn session(&self) -> Str {
+        self.session()
+    }
+
+    fn players(&self) -> List<Player> {
+        self.players()
+    }
+
+    fn game_objects(&self) -> Map<Str, GameObject> {
+        self.game_objects()
+    }
+}
+"#
+        );
+        if has_attributes(game, obj, &["currentTurn", "maxTurns"]) {
+            emit!(
+                out,
+                r#"
+
+impl TurnBasedGame for Game {
+    fn current_turn(&self) -> i64 {
+        self.current_turn()
+    }
+
+    fn max_turns(&self) -> i64 {
+        self.max_turns()
+    }
+}
+"#
+            );
+        }
+        if is_grid_tile(game) && has_attributes(game, obj, &["mapWidth", "mapHeight", "tiles"]) {
+            emit!(
+                out,
+                r#"
+
+impl GridGame for Game {
+    type Tile = Tile;
+
+    fn map_width(&self) -> i64 {
+        self.map_width()
+    }
+
+    fn map_height(&self) -> i64 {
+        self.map_height()
+    }
+
+    fn tiles(&self) -> List<Tile> {
+        self.tiles()
+    }
+}
+"#
+            );
+        }
+        return out;
+    }
+
+    emit!(
+        out,
+        r#"
+
+impl GameObjectHandle for ${obj_key} {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
+"#,
+        obj_key = obj_key
+    );
+    if obj_key == "Player" {
+        emit!(
+            out,
+            r#"
+
+impl AnyPlayer for Player {
+    fn name(&self) -> Str {
+        self.name()
+    }
+
+    fn client_type(&self) -> Str {
+        self.client_type()
+    }
+
+    fn opponent(&self) -> Player {
+        self.opponent()
+    }
+
+    fn won(&self) -> bool {
+        self.won()
+    }
+
+    fn lost(&self) -> bool {
+        self.lost()
+    }
+
+    fn reason_won(&self) -> Str {
+        self.reason_won()
+    }
+
+    fn reason_lost(&self) -> Str {
+        self.reason_lost()
+    }
+
+    fn time_remaining(&self) -> f64 {
+        self.time_remaining()
+    }
+}
+"#
+        );
+    }
+    if obj_key == "Tile" && is_grid_tile(game) {
+        emit!(
+            out,
+            r#"
+
+impl GridTile for Tile {
+    fn x(&self) -> i64 {
+        self.x()
+    }
+
+    fn y(&self) -> i64 {
+        self.y()
+    }
+
+    fn tile_north(&self) -> Option<Tile> {
+        self.tile_north()
+    }
+
+    fn tile_east(&self) -> Option<Tile> {
+        self.tile_east()
+    }
+
+    fn tile_south(&self) -> Option<Tile> {
+        self.tile_south()
+    }
+
+    fn tile_west(&self) -> Option<Tile> {
+        self.tile_west()
+    }
+}
+"#
+        );
+    }
+    out
+}
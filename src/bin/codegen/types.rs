@@ -0,0 +1,121 @@
+//! The Rust types used for the types in the game structure.
+
+use crate::structure::{Game, Type};
+
+fn fish(name: &str, args: &[String]) -> String {
+    format!("{}<{}>", name, args.join(", "))
+}
+
+fn reference(ty: &str, lifetime: Option<&str>) -> String {
+    match lifetime {
+        Some(lifetime) => format!("&'{} {}", lifetime, ty),
+        None => format!("&{}", ty),
+    }
+}
+
+fn option(ty: String) -> String {
+    fish("Option", &[ty])
+}
+
+impl Game {
+    /// Object references that can't be null.
+    pub fn is_required_object(&self, ty: &Type) -> bool {
+        self.is_object(ty) && !ty.nullable
+    }
+
+    /// Values that are returned by value rather than cloned.
+    pub fn is_copy(&self, ty: &Type) -> bool {
+        ["null", "boolean", "int", "float"].contains(&ty.name.as_str()) && !ty.nullable
+    }
+
+    /// The type that owns a value, as returned by accessors.
+    pub fn owned_type(&self, ty: &Type) -> String {
+        let result = match ty.name.as_str() {
+            "null" => "()".to_string(),
+            "boolean" => "bool".to_string(),
+            "int" => "i64".to_string(),
+            "float" => "f64".to_string(),
+            "string" => match &ty.enum_name {
+                Some(enum_name) => enum_name.clone(),
+                None => "Str".to_string(),
+            },
+            "list" => fish("List", &[self.owned_type(ty.value_type())]),
+            "dictionary" => fish(
+                "Map",
+                &[
+                    self.owned_type(ty.key_type()),
+                    self.owned_type(ty.value_type()),
+                ],
+            ),
+            name if self.is_object(ty) => name.to_string(),
+            name => panic!("unknown type {:?}", name),
+        };
+
+        if ty.nullable {
+            option(result)
+        } else {
+            result
+        }
+    }
+
+    /// The type a value is stored as.
+    ///
+    /// Objects are created before they are merged, so required references start out empty.
+    pub fn internal_type(&self, ty: &Type) -> String {
+        if self.is_required_object(ty) {
+            option(self.owned_type(ty))
+        } else {
+            self.owned_type(ty)
+        }
+    }
+
+    pub fn return_type(&self, ty: &Type) -> String {
+        self.owned_type(ty)
+    }
+
+    /// The type of a value returned by the AI.
+    ///
+    /// Values returned by the AI are built fresh, so an owned string is more convenient.
+    pub fn ai_return_type(&self, ty: &Type) -> String {
+        if ty.name == "string" && ty.enum_name.is_none() && !ty.nullable {
+            "String".to_string()
+        } else {
+            self.owned_type(ty)
+        }
+    }
+
+    /// The type of a function argument, which is borrowed where possible.
+    ///
+    /// Enums are taken by value, so that their variants can be passed directly.
+    pub fn arg_type(&self, ty: &Type, lifetime: Option<&str>) -> String {
+        let result = match ty.name.as_str() {
+            "null" => "()".to_string(),
+            "boolean" => "bool".to_string(),
+            "int" => "i64".to_string(),
+            "float" => "f64".to_string(),
+            "string" => match &ty.enum_name {
+                Some(enum_name) => enum_name.clone(),
+                None => reference("str", lifetime),
+            },
+            "list" => reference(&format!("[{}]", self.owned_type(ty.value_type())), lifetime),
+            "dictionary" => reference(
+                &fish(
+                    "Map",
+                    &[
+                        self.owned_type(ty.key_type()),
+                        self.owned_type(ty.value_type()),
+                    ],
+                ),
+                lifetime,
+            ),
+            name if self.is_object(ty) => reference(name, lifetime),
+            name => panic!("unknown type {:?}", name),
+        };
+
+        if ty.nullable {
+            option(result)
+        } else {
+            result
+        }
+    }
+}
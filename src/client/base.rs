@@ -1,9 +1,24 @@
-use std::borrow::Cow;
+use serde_derive::{Deserialize, Serialize};
 
-use serde_derive::{Serialize, Deserialize};
+/// A reference to a game object, as it is sent over the wire.
+///
+/// This is how game objects appear in deltas and "ran" events, and how they are passed to the
+/// server as arguments or as the caller of a "run" event: an object with just the id, like
+/// `{"id": "14"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ObjRef {
+    id: String,
+}
+
+impl ObjRef {
+    pub fn new<S>(id: S) -> ObjRef
+    where
+        S: Into<String>,
+    {
+        ObjRef { id: id.into() }
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ObjRef<'a> {
-    #[serde(borrow)]
-    id: Cow<'a, str>,
+    pub fn id(&self) -> &str {
+        &self.id
+    }
 }
@@ -0,0 +1,65 @@
+//! A connection to the game server, carrying events in both directions.
+
+use std::fmt;
+use std::io::{BufRead, Write};
+
+use crate::client::exit::Exit;
+use crate::client::proto::{ClientEvent, EventSink, EventStream, ServerEvent};
+use crate::error::Error;
+
+/// The client's end of a game server connection.
+///
+/// The underlying reader and writer are boxed so that the connection can be shared with the
+/// game-specific parts of the client without them having to know what transport is in use.
+pub struct Connection {
+    stream: EventStream<Box<dyn BufRead + Send>, ServerEvent>,
+    sink: EventSink<Box<dyn Write + Send>, ClientEvent>,
+    hostname: Option<String>,
+}
+
+impl Connection {
+    /// Creates a connection that reads server events from `read` and writes client events to
+    /// `write`.
+    pub fn new<R, W>(read: R, write: W) -> Connection
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        Connection {
+            stream: EventStream::new(Box::new(read)),
+            sink: EventSink::new(Box::new(write)),
+            hostname: None,
+        }
+    }
+
+    /// Records the hostname the connection was made to, which the server refers to as
+    /// `__HOSTNAME__` in the "over" event.
+    pub fn with_hostname(mut self, hostname: &str) -> Connection {
+        self.hostname = Some(hostname.to_string());
+        self
+    }
+
+    /// The hostname the connection was made to, if it was made over the network.
+    pub fn hostname(&self) -> Option<&str> {
+        self.hostname.as_deref()
+    }
+
+    /// Sends an event to the server.
+    pub fn send(&mut self, event: ClientEvent) -> Result<(), Error> {
+        self.sink.send(event)
+    }
+
+    /// Waits for the next event from the server.
+    ///
+    /// The server never closes the connection without notice, so reaching the end of the stream
+    /// is reported as [`Exit::DisconnectedUnexpectedly`].
+    pub fn recv(&mut self) -> Result<ServerEvent, Error> {
+        self.stream.recv().ok_or(Exit::DisconnectedUnexpectedly)?
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Connection").finish()
+    }
+}
@@ -1,17 +1,139 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::client::exit::Exit;
 use crate::error::Error;
+use crate::types;
 
 use serde_json::{Map, Number, Value};
 
-pub trait DeltaMergeable {
-    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error>;
+/// Types whose value can be updated by merging a delta into it.
+///
+/// The context type defaults to the protocol constants in [`Context`], which is all that plain
+/// data needs. Game-specific contexts are used where game object references need to be resolved,
+/// and must provide the constants through `AsRef<Context>`.
+pub trait DeltaMergeable<C: ?Sized = Context> {
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error>;
+}
+
+/// Types that can be created from a delta, as the first value seen for them.
+///
+/// This is implemented for every default-constructible [`DeltaMergeable`] by merging into the
+/// default value. Types that have no sensible default, like game object references, implement it
+/// directly.
+pub trait FromDelta<C: ?Sized = Context>: Sized {
+    fn from_delta(delta: Value, cx: &C) -> Result<Self, Error>;
+}
+
+/// What changed in the game state over one or more merged deltas.
+///
+/// Fields are named after the accessors of the types they belong to, like `tile` for
+/// `Unit::tile`. Objects that were created are also listed with the fields they were created
+/// with. Objects that were created and then removed before the changes were collected are left
+/// out entirely.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changes {
+    created: HashSet<types::Str>,
+    removed: HashSet<types::Str>,
+    changed: HashMap<types::Str, HashSet<&'static str>>,
+    game: HashSet<&'static str>,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.game.is_empty()
+    }
+
+    /// The ids of the game objects that were created.
+    pub fn created(&self) -> impl Iterator<Item = &str> {
+        self.created.iter().map(types::Str::as_slice)
+    }
+
+    /// The ids of the game objects that were removed.
+    pub fn removed(&self) -> impl Iterator<Item = &str> {
+        self.removed.iter().map(types::Str::as_slice)
+    }
+
+    /// The ids of the game objects whose fields changed, along with those fields.
+    pub fn changed(&self) -> impl Iterator<Item = (&str, &HashSet<&'static str>)> {
+        self.changed
+            .iter()
+            .map(|(id, fields)| (id.as_slice(), fields))
+    }
+
+    pub fn was_created(&self, id: &str) -> bool {
+        self.created.contains(id)
+    }
+
+    pub fn was_removed(&self, id: &str) -> bool {
+        self.removed.contains(id)
+    }
+
+    /// The fields that changed on the game object with the given id, if any did.
+    pub fn fields(&self, id: &str) -> Option<&HashSet<&'static str>> {
+        self.changed.get(id)
+    }
+
+    pub fn has_changed(&self, id: &str, field: &str) -> bool {
+        self.fields(id).is_some_and(|fields| fields.contains(field))
+    }
+
+    /// The fields that changed on the game itself.
+    pub fn game_fields(&self) -> &HashSet<&'static str> {
+        &self.game
+    }
 }
 
+// Only the generated game modules record changes, and a build may leave all of them out.
+#[allow(dead_code)]
+impl Changes {
+    pub(crate) fn create(&mut self, id: &str) {
+        self.created.insert(id.into());
+    }
+
+    pub(crate) fn remove(&mut self, id: &str) {
+        self.changed.remove(id);
+        if !self.created.remove(id) {
+            self.removed.insert(id.into());
+        }
+    }
+
+    pub(crate) fn change(&mut self, id: &str, field: &'static str) {
+        self.changed.entry(id.into()).or_default().insert(field);
+    }
+
+    pub(crate) fn change_game(&mut self, field: &'static str) {
+        self.game.insert(field);
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Context {
     delta_list_length: String,
     delta_removed: String,
 }
 
 impl Context {
+    /// Reads the constants sent by the server in the "lobbied" event.
+    ///
+    /// Fails with [`Exit::DeltaMergeFailure`] if either `DELTA_LIST_LENGTH` or `DELTA_REMOVED` is
+    /// missing, since no delta could be merged correctly without them.
+    pub fn from_constants(constants: &HashMap<String, String>) -> Result<Context, Error> {
+        let get = |name: &str| {
+            constants.get(name).cloned().ok_or_else(|| {
+                let message = format!("server did not send the {} constant", name);
+                Error::from((Exit::DeltaMergeFailure, message))
+            })
+        };
+        Ok(Context {
+            delta_list_length: get("DELTA_LIST_LENGTH")?,
+            delta_removed: get("DELTA_REMOVED")?,
+        })
+    }
+
     pub fn delta_list_length(&self) -> &str {
         &self.delta_list_length
     }
@@ -21,19 +143,48 @@ impl Context {
     }
 }
 
-impl<T> DeltaMergeable for &mut T
+/// The constants used by every Cerveau server to date.
+impl Default for Context {
+    fn default() -> Context {
+        Context {
+            delta_list_length: "&LEN".into(),
+            delta_removed: "&RM".into(),
+        }
+    }
+}
+
+impl AsRef<Context> for Context {
+    fn as_ref(&self) -> &Context {
+        self
+    }
+}
+
+impl<C, T> FromDelta<C> for T
 where
-    T: DeltaMergeable,
+    C: ?Sized,
+    T: DeltaMergeable<C> + Default,
 {
-    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+    fn from_delta(delta: Value, cx: &C) -> Result<Self, Error> {
+        let mut value = T::default();
+        value.merge_delta(delta, cx)?;
+        Ok(value)
+    }
+}
+
+impl<C, T> DeltaMergeable<C> for &mut T
+where
+    C: ?Sized,
+    T: DeltaMergeable<C>,
+{
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error> {
         T::merge_delta(*self, delta, cx)
     }
 }
 
 macro_rules! merge_deserialize {
     ($($t:ty,)*) => {$(
-        impl DeltaMergeable for $t {
-            fn merge_delta(&mut self, delta: Value, _cx: &Context) -> Result<(), Error> {
+        impl<C: ?Sized> DeltaMergeable<C> for $t {
+            fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
                 *self = serde_json::from_value(delta)?;
                 Ok(())
             }
@@ -56,30 +207,139 @@ merge_deserialize! {
     u64,
     u128,
     usize,
+    f32,
+    f64,
     Number,
     String,
 }
 
-impl<T> DeltaMergeable for Vec<T>
+/// `None` is encoded as `null`. Any other value is merged into the contained value, or used to
+/// create one if there is none yet.
+impl<C, T> DeltaMergeable<C> for Option<T>
 where
-    T: DeltaMergeable + Default,
+    C: ?Sized,
+    T: DeltaMergeable<C> + FromDelta<C>,
 {
-    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error> {
+        match self {
+            _ if delta.is_null() => *self = None,
+            Some(value) => value.merge_delta(delta, cx)?,
+            None => *self = Some(T::from_delta(delta, cx)?),
+        }
+        Ok(())
+    }
+}
+
+impl<C, T> DeltaMergeable<C> for Vec<T>
+where
+    C: AsRef<Context> + ?Sized,
+    T: DeltaMergeable<C> + Default,
+{
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error> {
+        let constants = cx.as_ref();
         let mut map: Map<String, Value> = serde_json::from_value(delta)?;
-        if let Some(len_value) = map.remove(cx.delta_list_length()) {
+        if let Some(len_value) = map.remove(constants.delta_list_length()) {
             let len = serde_json::from_value(len_value)?;
             self.resize_with(len, Default::default);
         }
 
         for (key, value) in map {
             let idx: usize = key.parse()?;
-            if value == cx.delta_removed() {
-                self[idx] = Default::default();
+            let item = self.get_mut(idx).ok_or_else(|| {
+                let message = format!("list index {} is out of bounds", idx);
+                Error::from((Exit::DeltaMergeFailure, message))
+            })?;
+            if value == constants.delta_removed() {
+                *item = Default::default();
+            } else {
+                item.merge_delta(value, cx)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for types::Str {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value::<String>(delta)?.into();
+        Ok(())
+    }
+}
+
+/// Elements past the old length of the list are sent in full, so they are created from their
+/// delta instead of being merged into a default value. This lets lists hold game objects.
+///
+/// Elements are merged in place, so they are only cloned if the list is shared or shrinks.
+impl<C, T> DeltaMergeable<C> for types::List<T>
+where
+    C: AsRef<Context> + ?Sized,
+    T: DeltaMergeable<C> + FromDelta<C> + Clone,
+{
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error> {
+        let constants = cx.as_ref();
+        let mut map: Map<String, Value> = serde_json::from_value(delta)?;
+        let len = match map.remove(constants.delta_list_length()) {
+            Some(len_value) => serde_json::from_value(len_value)?,
+            None => self.len(),
+        };
+        if len < self.len() {
+            *self = self[..len].into();
+        }
+
+        let mut added = BTreeMap::new();
+        for (key, value) in map {
+            let idx: usize = key.parse()?;
+            if idx < self.len() {
+                self.as_mut_slice()[idx].merge_delta(value, cx)?;
             } else {
-                self[idx].merge_delta(value, cx)?;
+                added.insert(idx, value);
+            }
+        }
+        let mut items = Vec::with_capacity(added.len());
+        for (idx, value) in added {
+            if idx != self.len() + items.len() || idx >= len {
+                let message = format!("list index {} is out of order or out of bounds", idx);
+                return Err((Exit::DeltaMergeFailure, message).into());
             }
+            items.push(T::from_delta(value, cx)?);
+        }
+        if self.len() + items.len() != len {
+            let message = format!(
+                "list of length {} only has {} elements",
+                len,
+                self.len() + items.len()
+            );
+            return Err((Exit::DeltaMergeFailure, message).into());
+        }
+        if !items.is_empty() {
+            self.extend(items);
         }
+        Ok(())
+    }
+}
 
+/// Entries equal to `DELTA_REMOVED` are removed from the map. Like lists, the map is only cloned
+/// if it is shared.
+impl<C, K, V> DeltaMergeable<C> for types::Map<K, V>
+where
+    C: AsRef<Context> + ?Sized,
+    K: From<String> + Hash + Eq + Clone,
+    V: DeltaMergeable<C> + FromDelta<C> + Clone,
+{
+    fn merge_delta(&mut self, delta: Value, cx: &C) -> Result<(), Error> {
+        let constants = cx.as_ref();
+        let map: Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in map {
+            let key = K::from(key);
+            if value == constants.delta_removed() {
+                self.remove(&key);
+            } else if let Some(existing) = self.get_mut(&key) {
+                existing.merge_delta(value, cx)?;
+            } else {
+                self.insert(key, V::from_delta(value, cx)?);
+            }
+        }
         Ok(())
     }
 }
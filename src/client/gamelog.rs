@@ -0,0 +1,82 @@
+//! Gamelogs saved by the server, which record every change in state over the course of a game.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::client::exit::Exit;
+use crate::error::Error;
+
+use serde_derive::Deserialize;
+use serde_json::Value;
+
+/// A complete record of one game.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gamelog {
+    /// The name of the game that was played.
+    pub game_name: String,
+
+    /// The session the game was played in.
+    pub game_session: String,
+
+    /// The constants that were sent in the "lobbied" event, which the deltas are encoded with.
+    pub constants: HashMap<String, String>,
+
+    /// Every change in state, in order, starting with the initial state of the game.
+    pub deltas: Vec<GamelogDelta>,
+
+    #[serde(default)]
+    pub winners: Vec<GamelogPlayer>,
+
+    #[serde(default)]
+    pub losers: Vec<GamelogPlayer>,
+}
+
+impl Gamelog {
+    /// Reads an uncompressed gamelog from a file.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if the file can't be opened.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Gamelog, Error> {
+        let file =
+            File::open(path).map_err(|e| Error::from_error(e).with_exit(Exit::InvalidArgs))?;
+        Gamelog::from_reader(BufReader::new(file))
+    }
+
+    pub fn from_reader<R: Read>(read: R) -> Result<Gamelog, Error> {
+        Ok(serde_json::from_reader(read)?)
+    }
+}
+
+/// One step of a game, along with the change in state that it caused.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GamelogDelta {
+    /// What caused the change, such as "start", "ran", "finished" or "over".
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// Details of the cause, which depend on its kind.
+    #[serde(default)]
+    pub data: Value,
+
+    /// The change in game state, in the same form as a "delta" event.
+    #[serde(default)]
+    pub game: Option<HashMap<String, Value>>,
+}
+
+/// How a game ended for one player.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogPlayer {
+    pub index: usize,
+    pub id: String,
+    pub name: String,
+    pub reason: String,
+
+    #[serde(default)]
+    pub disconnected: bool,
+
+    #[serde(default)]
+    pub timed_out: bool,
+}
@@ -1,7 +1,19 @@
 //! Implementation of the connection to the game server.
 
 pub mod base;
+pub mod connection;
 pub mod delta;
 pub mod exit;
+pub mod gamelog;
+pub mod outcome;
+mod play;
 pub mod proto;
+pub mod runtime;
+pub mod settings;
 pub mod util;
+
+#[doc(inline)]
+pub use self::outcome::GameOutcome;
+pub use self::play::{Client, ClientConfig};
+#[doc(inline)]
+pub use self::settings::{AiSettings, GameSettings};
@@ -0,0 +1,50 @@
+//! The result of a game, as seen by the player the AI played as.
+
+use std::fs::File;
+use std::path::Path;
+
+use serde_derive::Serialize;
+
+use crate::error::Error;
+
+/// How a game ended for the AI's player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameOutcome {
+    /// The name of the game that was played, as resolved by the server.
+    pub game_name: String,
+
+    /// The session the game was played in.
+    pub game_session: String,
+
+    /// Whether the AI's player won the game.
+    pub won: bool,
+
+    /// Whether the AI's player lost the game. Neither this nor `won` is set if the game ended
+    /// without a result, for example in a draw.
+    pub lost: bool,
+
+    /// Why the AI's player won, or an empty string if it didn't.
+    pub reason_won: String,
+
+    /// Why the AI's player lost, or an empty string if it didn't.
+    pub reason_lost: String,
+
+    /// The turn the game ended on, for games that count turns.
+    pub final_turn: Option<i64>,
+
+    /// The url to download the gamelog from, if the server gave one.
+    pub gamelog_url: Option<String>,
+
+    /// The url to watch the gamelog in the visualizer, if the server gave one.
+    pub visualizer_url: Option<String>,
+}
+
+impl GameOutcome {
+    /// Writes the outcome to a file as JSON, with the same field names as the struct.
+    ///
+    /// Fails without an exit code if the file can't be written, since the game itself went fine.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let file = File::create(path)?;
+        Ok(serde_json::to_writer_pretty(file, self)?)
+    }
+}
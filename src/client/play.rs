@@ -0,0 +1,372 @@
+//! Joining a game on a server and playing it to the end.
+
+use std::collections::HashMap;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
+
+use crate::client::connection::Connection;
+use crate::client::delta;
+use crate::client::exit::Exit;
+use crate::client::outcome::GameOutcome;
+use crate::client::proto::{ClientEvent, ServerEvent};
+use crate::client::runtime::unexpected_event;
+use crate::client::settings::{AiSettings, GameSettings};
+use crate::error::Error;
+use crate::games::{self, Registry, Session, Spectator};
+
+use serde_json::Value;
+
+/// Where to play a game, and as whom.
+///
+/// Only the game is required. Everything else defaults to what the `joueur` binary uses when
+/// its options are left out:
+///
+/// ```
+/// use joueur::client::{Client, ClientConfig};
+///
+/// let config = ClientConfig::new("chess")
+///     .server("localhost:3000")
+///     .session("my-session")
+///     .name("Chess Bot");
+/// let client = Client::new(config);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    game: String,
+    server: String,
+    port: u16,
+    session: String,
+    name: Option<String>,
+    index: Option<isize>,
+    password: Option<String>,
+    game_settings: GameSettings,
+    ai_settings: AiSettings,
+    print_io: bool,
+}
+
+impl ClientConfig {
+    /// Creates a config for playing a game, given by any name the server knows it by.
+    pub fn new(game: &str) -> ClientConfig {
+        ClientConfig {
+            game: game.to_string(),
+            server: "localhost".to_string(),
+            port: 3000,
+            session: "*".to_string(),
+            name: None,
+            index: None,
+            password: None,
+            game_settings: GameSettings::new(),
+            ai_settings: AiSettings::new(),
+            print_io: false,
+        }
+    }
+
+    /// The hostname of the server, which may end with `:port` to override [`port`](Self::port).
+    pub fn server(mut self, server: &str) -> ClientConfig {
+        self.server = server.to_string();
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> ClientConfig {
+        self.port = port;
+        self
+    }
+
+    /// The session to play in. `"*"` joins the first open session, and `"new"` starts one.
+    pub fn session(mut self, session: &str) -> ClientConfig {
+        self.session = session.to_string();
+        self
+    }
+
+    /// The name to play as, instead of the one the AI gives.
+    pub fn name(mut self, name: &str) -> ClientConfig {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The index of the player to play as, with 0 being the first player.
+    pub fn index(mut self, index: isize) -> ClientConfig {
+        self.index = Some(index);
+        self
+    }
+
+    /// The password required for authentication on official servers.
+    pub fn password(mut self, password: &str) -> ClientConfig {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Settings for the server to force on the game. They are checked against the settings the
+    /// game is known to accept before it is joined, and are not sent when spectating.
+    pub fn game_settings(mut self, game_settings: GameSettings) -> ClientConfig {
+        self.game_settings = game_settings;
+        self
+    }
+
+    /// Settings for the AI, which it can read through its game's `ai_settings`.
+    pub fn ai_settings(mut self, ai_settings: AiSettings) -> ClientConfig {
+        self.ai_settings = ai_settings;
+        self
+    }
+
+    /// Prints everything sent and received to stderr, for debugging.
+    pub fn print_io(mut self, print_io: bool) -> ClientConfig {
+        self.print_io = print_io;
+        self
+    }
+}
+
+/// Plays or watches games on a server.
+///
+/// Each call to [`play`](Client::play) or [`play_session`](Client::play_session) opens a new
+/// connection and plays one game on it, and each call to [`spectate`](Client::spectate) or
+/// [`spectate_session`](Client::spectate_session) watches one. A single AI can also be played with the `play` function
+/// of its game's module, like `joueur::games::chess::play(&client, MyChessAi::new())`.
+#[derive(Debug, Clone)]
+pub struct Client {
+    config: ClientConfig,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Client {
+        Client { config }
+    }
+
+    /// Plays one game with the AI registered for it.
+    ///
+    /// Fails with [`Exit::GameNotFound`] if there is no AI for the game the server resolves the
+    /// configured game to, or with [`Exit::InvalidArgs`] if a game setting has the wrong type.
+    pub fn play(&self, registry: &Registry) -> Result<GameOutcome, Error> {
+        self.play_with(|game_name| registry.session(game_name))
+    }
+
+    /// Plays one game with the given session.
+    ///
+    /// Fails with [`Exit::GameNotFound`] if the server resolves the configured game to a
+    /// different game than the session's, or with [`Exit::InvalidArgs`] if a game setting has
+    /// the wrong type.
+    pub fn play_session(&self, session: Box<dyn Session>) -> Result<GameOutcome, Error> {
+        self.play_with(|game_name| {
+            self.check_game(game_name, session.game_name())?;
+            Ok(session)
+        })
+    }
+
+    /// Watches one game without playing, printing what changes in it with the printer of the
+    /// game the server resolves the configured game to.
+    ///
+    /// Fails with [`Exit::GameNotFound`] if that game's module was left out of the build.
+    pub fn spectate(&self) -> Result<(), Error> {
+        self.spectate_with(games::printer)
+    }
+
+    /// Watches one game without playing, with the given spectator.
+    ///
+    /// Fails with [`Exit::GameNotFound`] if the server resolves the configured game to a
+    /// different game than the spectator's.
+    pub fn spectate_session(&self, spectator: Box<dyn Spectator>) -> Result<(), Error> {
+        self.spectate_with(|game_name| {
+            self.check_game(game_name, spectator.game_name())?;
+            Ok(spectator)
+        })
+    }
+
+    fn check_game(&self, game_name: &str, expected: &str) -> Result<(), Error> {
+        if game_name == expected {
+            return Ok(());
+        }
+        let message = format!(
+            "the server plays {:?} as {:?}, not {:?}",
+            self.config.game, game_name, expected,
+        );
+        Err((Exit::GameNotFound, message).into())
+    }
+
+    fn play_with<F>(&self, new_session: F) -> Result<GameOutcome, Error>
+    where
+        F: FnOnce(&str) -> Result<Box<dyn Session>, Error>,
+    {
+        let config = &self.config;
+        let mut connection = self.connect()?;
+        let game_name = self.alias(&mut connection)?;
+
+        let session = new_session(&game_name)?;
+        for warning in config.game_settings.check(session.settings())? {
+            eprintln!("Warning: {}", warning);
+        }
+
+        let joined = self.join(
+            connection,
+            ClientEvent::Play {
+                game_name,
+                requested_session: config.session.clone(),
+                client_type: "Rust".into(),
+                player_name: config.name.clone().unwrap_or_else(|| session.player_name()),
+                player_index: config.index,
+                password: config.password.clone(),
+                game_settings: Some(config.game_settings.to_string())
+                    .filter(|game_settings| !game_settings.is_empty()),
+                spectating: None,
+            },
+        )?;
+        session.play(
+            Arc::new(Mutex::new(joined.connection)),
+            joined.constants,
+            joined.initial_delta,
+            &joined.player_id,
+            config.ai_settings.clone(),
+        )
+    }
+
+    fn spectate_with<F>(&self, new_spectator: F) -> Result<(), Error>
+    where
+        F: FnOnce(&str) -> Result<Box<dyn Spectator>, Error>,
+    {
+        let config = &self.config;
+        let mut connection = self.connect()?;
+        let game_name = self.alias(&mut connection)?;
+
+        let spectator = new_spectator(&game_name)?;
+
+        let joined = self.join(
+            connection,
+            ClientEvent::Play {
+                game_name,
+                requested_session: config.session.clone(),
+                client_type: "Rust".into(),
+                player_name: config.name.clone().unwrap_or_else(|| "Spectator".into()),
+                player_index: None,
+                password: config.password.clone(),
+                game_settings: None,
+                spectating: Some(true),
+            },
+        )?;
+        spectator.watch(
+            Arc::new(Mutex::new(joined.connection)),
+            joined.constants,
+            joined.initial_delta,
+        )
+    }
+
+    /// Asks the server which game the configured game name refers to.
+    fn alias(&self, connection: &mut Connection) -> Result<String, Error> {
+        connection.send(ClientEvent::Alias(self.config.game.clone()))?;
+        match connection.recv()? {
+            ServerEvent::Named(name) => Ok(name),
+            event => Err(unexpected_event(event)),
+        }
+    }
+
+    /// Sends the "play" event, and waits in the lobby until the game starts.
+    fn join(&self, mut connection: Connection, play: ClientEvent) -> Result<Joined, Error> {
+        connection.send(play)?;
+        let constants = match connection.recv()? {
+            ServerEvent::Lobbied {
+                game_name,
+                game_session,
+                constants,
+            } => {
+                println!(
+                    "In lobby for game '{}' in session '{}'",
+                    game_name, game_session
+                );
+                delta::Context::from_constants(&constants)?
+            }
+            event => return Err(unexpected_event(event)),
+        };
+
+        let initial_delta = match connection.recv()? {
+            ServerEvent::Delta(delta) => delta,
+            event => return Err(unexpected_event(event)),
+        };
+        let player_id = match connection.recv()? {
+            ServerEvent::Start { player_id } => player_id,
+            event => return Err(unexpected_event(event)),
+        };
+
+        Ok(Joined {
+            connection,
+            constants,
+            initial_delta,
+            player_id,
+        })
+    }
+
+    fn connect(&self) -> Result<Connection, Error> {
+        let config = &self.config;
+        let mut server_parts = config.server.split(':');
+        let server_name = server_parts.next().unwrap();
+        let server_port = match server_parts.next() {
+            Some(port_str) => port_str
+                .parse()
+                .map_err(|e| Error::from_error(e).with_exit(Exit::InvalidArgs))?,
+            None => config.port,
+        };
+
+        let socket =
+            TcpStream::connect((server_name, server_port)).map_err(Error::from_socket_error)?;
+        let read_half = socket.try_clone().map_err(Error::from_socket_error)?;
+        let write_half = socket;
+
+        let connection = Connection::new(
+            BufReader::new(PrintRead {
+                read: read_half,
+                print_io: config.print_io,
+            }),
+            BufWriter::new(PrintWrite {
+                write: write_half,
+                print_io: config.print_io,
+            }),
+        );
+        Ok(connection.with_hostname(server_name))
+    }
+}
+
+/// A connection to a game that has just started.
+struct Joined {
+    connection: Connection,
+    constants: delta::Context,
+    initial_delta: HashMap<String, Value>,
+    player_id: String,
+}
+
+struct PrintRead<R> {
+    read: R,
+    print_io: bool,
+}
+
+impl<R> Read for PrintRead<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.read.read(buf)?;
+        if self.print_io && n > 0 {
+            eprintln!("FROM SERVER <-- {}", String::from_utf8_lossy(&buf[..n]));
+        }
+        Ok(n)
+    }
+}
+
+struct PrintWrite<W> {
+    write: W,
+    print_io: bool,
+}
+
+impl<W> Write for PrintWrite<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.write.write(buf)?;
+        if self.print_io && n > 0 {
+            eprintln!("TO SERVER --> {}", String::from_utf8_lossy(&buf[..n]));
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write.flush()
+    }
+}
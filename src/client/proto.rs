@@ -6,6 +6,7 @@ use std::io::{self, BufRead, Write};
 use std::marker::PhantomData;
 use std::time::UNIX_EPOCH;
 
+use crate::client::base::ObjRef;
 use crate::error::Error;
 
 use serde::de::DeserializeOwned;
@@ -13,11 +14,6 @@ use serde::ser::Serialize;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Id {
-    id: String,
-}
-
 /// The list of events that clients are allowed to send.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event", content = "data", rename_all = "camelCase")]
@@ -148,7 +144,7 @@ pub enum ClientEvent {
     Run {
         /// The game object's reference (object with just its ID) to the game object calling this
         /// run command
-        caller: Id,
+        caller: ObjRef,
 
         /// The name of the function to run on the caller
         function_name: String,
@@ -255,8 +251,8 @@ pub enum ServerEvent {
     #[serde(rename_all = "camelCase")]
     Start {
         /// The player index of your player in the `Game.players` array. Synonymous with
-        /// `PlayerIndex`.
-        #[serde(rename = "playerID")]
+        /// `PlayerIndex`. Spectators have no player, so this is left out and read as empty.
+        #[serde(rename = "playerID", default)]
         player_id: String,
     },
 
@@ -440,7 +436,8 @@ where
 }
 
 fn parse_event<T: DeserializeOwned>(result: io::Result<Vec<u8>>) -> Result<T, Error> {
-    Ok(serde_json::from_slice(&result?)?)
+    let bytes = result.map_err(Error::from_socket_error)?;
+    Ok(serde_json::from_slice(&bytes)?)
 }
 
 impl<R, T> Iterator for EventStream<R, T>
@@ -483,9 +480,16 @@ where
             event,
             sent_time: UNIX_EPOCH.elapsed().expect("invalid system time").as_secs(),
         };
-        serde_json::to_writer(&mut self.write, &message)?;
-        self.write.write_all(&[EOT])?;
-        self.write.flush()?;
-        Ok(())
+        serde_json::to_writer(&mut self.write, &message).map_err(|err| {
+            if err.is_io() {
+                Error::from_socket_error(err.into())
+            } else {
+                err.into()
+            }
+        })?;
+        self.write
+            .write_all(&[EOT])
+            .map_err(Error::from_socket_error)?;
+        self.write.flush().map_err(Error::from_socket_error)
     }
 }
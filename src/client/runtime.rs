@@ -0,0 +1,106 @@
+//! The event loop that drives a game after the server has sent the "start" event.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::client::connection::Connection;
+use crate::client::exit::Exit;
+use crate::client::proto::{ClientEvent, ServerEvent};
+use crate::client::util::lock;
+use crate::error::Error;
+
+use serde_json::Value;
+
+/// What the server sent in the "over" event, with `__HOSTNAME__` replaced by the hostname of the
+/// connection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameOver {
+    /// The url to download the gamelog from.
+    pub gamelog_url: Option<String>,
+
+    /// The url to watch the gamelog in the visualizer.
+    pub visualizer_url: Option<String>,
+
+    /// A message for whoever is running the client, usually pointing to the visualizer.
+    pub message: Option<String>,
+}
+
+/// The game-specific half of the runtime, which receives the events read by [`run`].
+pub trait Handler {
+    /// Applies a change in game state sent by the server.
+    fn delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error>;
+
+    /// Carries out an order from the server, returning the value to send back in the
+    /// "finished" event.
+    fn order(&mut self, name: &str, args: Vec<Value>) -> Result<Value, Error>;
+
+    /// Reports that something this client sent was rejected by the server.
+    fn invalid(&mut self, message: &str) -> Result<(), Error>;
+
+    /// Called once when the game is over, just before [`run`] returns.
+    fn over(&mut self) -> Result<(), Error>;
+}
+
+/// Processes server events until the game is over.
+///
+/// The connection is only locked while an event is being sent or received, so the handler is
+/// free to use it as well, for example to send "run" events while it carries out an order.
+///
+/// Returns what the server sent in the "over" event once it has been handled, after printing its
+/// message. A "fatal" event, an event that is not expected during gameplay, or a lost
+/// connection ends the loop with the matching [`Exit`] code.
+pub fn run<H>(connection: &Mutex<Connection>, handler: &mut H) -> Result<GameOver, Error>
+where
+    H: Handler + ?Sized,
+{
+    loop {
+        let event = lock(connection).recv()?;
+        match event {
+            ServerEvent::Delta(delta) => handler.delta(delta)?,
+            ServerEvent::Order { name, index, args } => {
+                let returned = handler.order(&name, args)?;
+                lock(connection).send(ClientEvent::Finished {
+                    order_index: index as isize,
+                    returned,
+                })?;
+            }
+            ServerEvent::Invalid { message } => handler.invalid(&message)?,
+            ServerEvent::Over {
+                gamelog_url,
+                visualizer_url,
+                message,
+            } => {
+                let hostname = lock(connection).hostname().map(str::to_string);
+                let fill = |text: Option<String>| match &hostname {
+                    Some(hostname) => text.map(|text| text.replace("__HOSTNAME__", hostname)),
+                    None => text,
+                };
+                let over = GameOver {
+                    gamelog_url: fill(gamelog_url),
+                    visualizer_url: fill(visualizer_url),
+                    message: fill(message),
+                };
+                if let Some(message) = &over.message {
+                    println!("{}", message);
+                }
+                handler.over()?;
+                return Ok(over);
+            }
+            event => return Err(unexpected_event(event)),
+        }
+    }
+}
+
+/// Converts an event that the client was not expecting at this point into an error.
+///
+/// A "fatal" event is reported with the server's message as [`Exit::FatalEvent`]; anything else
+/// is [`Exit::UnknownEventFromServer`].
+pub fn unexpected_event(event: ServerEvent) -> Error {
+    match event {
+        ServerEvent::Fatal { message } => {
+            let message = message.unwrap_or_else(|| "unknown cause".into());
+            (Exit::FatalEvent, message).into()
+        }
+        event => (Exit::UnknownEventFromServer, format!("{:?}", event)).into(),
+    }
+}
@@ -0,0 +1,316 @@
+//! Settings that can be given to a client on the command line.
+
+use std::any::type_name;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::{self, FromStr};
+
+use crate::client::exit::Exit;
+use crate::error::Error;
+
+/// Settings for the AI, as given by `--aiSettings` on the command line.
+///
+/// They are written as ampersand-delimited `key=value` pairs, like `depth=4&aggressive=true`,
+/// and are read by the AI through [`get`](AiSettings::get). Later values for the same key
+/// replace earlier ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AiSettings {
+    values: HashMap<String, String>,
+}
+
+impl AiSettings {
+    pub fn new() -> AiSettings {
+        AiSettings::default()
+    }
+
+    /// Parses settings in the `--aiSettings` format.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if a pair has no `=`, or has an empty key.
+    pub fn parse(settings: &str) -> Result<AiSettings, Error> {
+        let mut values = HashMap::new();
+        for pair in settings.split('&').filter(|pair| !pair.is_empty()) {
+            match pair.split_once('=') {
+                Some((key, value)) if !key.is_empty() => {
+                    values.insert(key.to_string(), value.to_string());
+                }
+                _ => {
+                    let message = format!("AI setting {:?} is not of the form key=value", pair);
+                    return Err((Exit::InvalidArgs, message).into());
+                }
+            }
+        }
+        Ok(AiSettings { values })
+    }
+
+    /// Adds a setting, replacing any other value for the same key.
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// The value of a setting as it was given.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Parses the value of a setting, returning `None` if it wasn't given.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if the value can't be parsed as a `T`.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, Error> {
+        let value = match self.values.get(key) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        value.parse().map(Some).map_err(|_| {
+            let message = format!(
+                "AI setting {}={:?} is not a valid {}",
+                key,
+                value,
+                type_name::<T>(),
+            );
+            (Exit::InvalidArgs, message).into()
+        })
+    }
+
+    /// Parses the value of a setting, or returns `default` if it wasn't given.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if the value can't be parsed as a `T`.
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, Error> {
+        Ok(self.get(key)?.unwrap_or(default))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+/// The type of value a game setting takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    Int,
+    Float,
+    Boolean,
+    String,
+}
+
+impl SettingKind {
+    fn accepts(self, value: &str) -> bool {
+        match self {
+            SettingKind::Int => value.parse::<i64>().is_ok(),
+            SettingKind::Float => value.parse::<f64>().is_ok(),
+            SettingKind::Boolean => value.parse::<bool>().is_ok(),
+            SettingKind::String => true,
+        }
+    }
+}
+
+impl fmt::Display for SettingKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SettingKind::Int => "int",
+            SettingKind::Float => "float",
+            SettingKind::Boolean => "boolean",
+            SettingKind::String => "string",
+        })
+    }
+}
+
+/// A setting that the server accepts for a game.
+///
+/// Each game module lists its own as `SETTINGS`, and every game also accepts the
+/// [`BASE_SETTINGS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameSetting {
+    /// The key of the setting in the query string.
+    pub name: &'static str,
+    pub kind: SettingKind,
+    pub description: &'static str,
+}
+
+/// The settings that the server accepts for every game.
+pub const BASE_SETTINGS: &[GameSetting] = &[
+    GameSetting {
+        name: "randomSeed",
+        kind: SettingKind::String,
+        description: "The seed for the game's random number generator, to repeat a game.",
+    },
+    GameSetting {
+        name: "playerStartTime",
+        kind: SettingKind::Int,
+        description: "The time each player starts with, in nanoseconds.",
+    },
+    GameSetting {
+        name: "playerAddTimePerTurn",
+        kind: SettingKind::Int,
+        description: "The time added to a player's clock each turn, in nanoseconds.",
+    },
+];
+
+/// Settings for the server to force on a game, as given by `--gameSettings` on the command line.
+///
+/// They are sent as a query string, like `maxTurns=100&randomSeed=a%20seed`. Keys may be
+/// repeated, and are kept in the order they are added:
+///
+/// ```
+/// use joueur::client::GameSettings;
+///
+/// let settings = GameSettings::new()
+///     .set("maxTurns", 100)
+///     .set("randomSeed", "a seed");
+/// assert_eq!(settings.to_string(), "maxTurns=100&randomSeed=a%20seed");
+/// assert_eq!(GameSettings::parse("maxTurns=100&randomSeed=a+seed").unwrap(), settings);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameSettings {
+    pairs: Vec<(String, String)>,
+}
+
+impl GameSettings {
+    pub fn new() -> GameSettings {
+        GameSettings::default()
+    }
+
+    /// Parses a percent-encoded query string, where `+` also stands for a space.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if a key is empty, or if a key or value is not valid
+    /// percent-encoded UTF-8.
+    pub fn parse(query: &str) -> Result<GameSettings, Error> {
+        let mut pairs = Vec::new();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = percent_decode(key)?;
+            if key.is_empty() {
+                let message = format!("game setting {:?} has no key", pair);
+                return Err((Exit::InvalidArgs, message).into());
+            }
+            pairs.push((key, percent_decode(value)?));
+        }
+        Ok(GameSettings { pairs })
+    }
+
+    /// Sets a setting, replacing every other value for the same key.
+    pub fn set<T: fmt::Display>(mut self, key: &str, value: T) -> GameSettings {
+        self.pairs.retain(|(k, _)| k != key);
+        self.add(key, value)
+    }
+
+    /// Adds a value for a setting, after any others for the same key.
+    pub fn add<T: fmt::Display>(mut self, key: &str, value: T) -> GameSettings {
+        self.pairs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// The first value of a setting.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// Every value of a setting, in the order they were added.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Checks the settings against those a game is known to accept, along with the
+    /// [`BASE_SETTINGS`], returning warnings about anything the server might not expect.
+    ///
+    /// The lists of known settings may be incomplete, and the server has the final say, so
+    /// settings that aren't known or are given more than once are only warned about.
+    ///
+    /// Fails with [`Exit::InvalidArgs`] if a known setting has a value of the wrong type.
+    pub fn check(&self, game_settings: &[GameSetting]) -> Result<Vec<String>, Error> {
+        let mut warnings = Vec::new();
+        for (i, (key, value)) in self.iter().enumerate() {
+            let setting = BASE_SETTINGS
+                .iter()
+                .chain(game_settings)
+                .find(|setting| setting.name == key);
+            match setting {
+                None => warnings.push(format!("unknown game setting {:?}", key)),
+                Some(_) if self.iter().take(i).any(|(k, _)| k == key) => {
+                    warnings.push(format!("game setting {:?} is given more than once", key))
+                }
+                Some(setting) if !setting.kind.accepts(value) => {
+                    let message = format!(
+                        "game setting {}={:?} is not a valid {}",
+                        key, value, setting.kind,
+                    );
+                    return Err((Exit::InvalidArgs, message).into());
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+/// Formats the settings as a percent-encoded query string.
+impl fmt::Display for GameSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (key, value)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            write!(f, "{}={}", percent_encode(key), percent_encode(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes everything but unreserved characters, as in RFC 3986.
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> Result<String, Error> {
+    let invalid = || {
+        Error::from((
+            Exit::InvalidArgs,
+            format!("invalid percent-encoding in {:?}", s),
+        ))
+    };
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = rest
+                    .get(..2)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .ok_or_else(invalid)?;
+                let hex = str::from_utf8(hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                rest = &rest[2..];
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
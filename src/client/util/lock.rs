@@ -0,0 +1,9 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Locks a mutex, recovering the guard if another thread panicked while holding it.
+///
+/// Game state is only ever replaced as a whole while it is locked, so a panic can't leave it half
+/// updated. Ignoring the poison keeps one panicking turn from breaking every turn after it.
+pub fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
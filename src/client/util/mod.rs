@@ -1,3 +1,5 @@
 mod borrowable;
+mod lock;
 
 pub use borrowable::Borrowable;
+pub use lock::lock;
@@ -26,25 +26,14 @@ impl Error {
     {
         let err = err.into();
         let mut exit = None;
-        if let Some(io_error) = err.downcast_ref::<io::Error>() {
-            match io_error.kind() {
-                ErrorKind::ConnectionReset
-                | ErrorKind::ConnectionAborted
-                | ErrorKind::BrokenPipe => exit = Some(Exit::DisconnectedUnexpectedly),
-                ErrorKind::ConnectionRefused | ErrorKind::NotConnected => {
-                    exit = Some(Exit::CouldNotConnect)
-                }
-                ErrorKind::TimedOut => exit = Some(Exit::ServerTimeout),
-                _ => exit = Some(Exit::CannotReadSocket),
-            }
-        } else if let Some(json_error) = err.downcast_ref::<serde_json::Error>() {
+        if let Some(json_error) = err.downcast_ref::<serde_json::Error>() {
             match json_error.classify() {
-                Category::Io | Category::Eof => {
+                Category::Io => {
                     return Error::from_error(io::Error::from(
                         *err.downcast::<serde_json::Error>().unwrap(),
                     ))
                 }
-                Category::Syntax => exit = Some(Exit::MalformedJson),
+                Category::Syntax | Category::Eof => exit = Some(Exit::MalformedJson),
                 Category::Data => exit = Some(Exit::UnknownEventFromServer),
             }
         } else if let Some(_clap_error) = err.downcast_ref::<clap::Error>() {
@@ -56,6 +45,21 @@ impl Error {
         }
     }
 
+    /// Wraps an error from the connection to the server, with the exit code for its kind.
+    ///
+    /// Other I/O errors, like those of an AI reading its own files, have no exit code.
+    pub fn from_socket_error(err: io::Error) -> Error {
+        let exit = match err.kind() {
+            ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe => {
+                Exit::DisconnectedUnexpectedly
+            }
+            ErrorKind::ConnectionRefused | ErrorKind::NotConnected => Exit::CouldNotConnect,
+            ErrorKind::TimedOut => Exit::ServerTimeout,
+            _ => Exit::CannotReadSocket,
+        };
+        Error::from_error(err).with_exit(exit)
+    }
+
     pub fn with_exit(self, exit: Exit) -> Self {
         Error {
             exit: Some(exit),
@@ -129,6 +133,7 @@ from_error! {
     std::thread::AccessError,
     std::time::SystemTimeError,
     String,
+    &str,
 }
 
 impl fmt::Display for Error {
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A basic building. It does nothing besides burn down. Other Buildings inherit from this class.
 #[derive(Debug, Clone)]
@@ -19,93 +31,96 @@ impl Building {
         Building { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// How much health this building currently has. When this reaches 0 the Building has been
     /// burned down.
     pub fn health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .health.clone()
+            .health
     }
 
     /// The player that owns this building. If it burns down (health reaches 0) that player gets an
     /// additional bribe(s).
     pub fn owner(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .owner.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
     /// wins the game for the other Player.
     pub fn is_headquarters(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .is_headquarters.clone()
+            .is_headquarters
     }
 
     /// When true this building has already been bribed this turn and cannot be bribed again this
     /// turn.
     pub fn bribed(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .bribed.clone()
+            .bribed
     }
 
     /// The location of the Building along the x-axis.
     pub fn x(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .x.clone()
+            .x
     }
 
     /// The location of the Building along the y-axis.
     pub fn y(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .y.clone()
+            .y
     }
 
     /// How much fire is currently burning the building, and thus how much damage it will take at
     /// the end of its owner's turn. 0 means no fire.
     pub fn fire(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .fire.clone()
+            .fire
     }
 
     /// The Building directly to the north of this building, or None if not present.
     pub fn building_north(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_north.clone()
     }
 
     /// The Building directly to the east of this building, or None if not present.
     pub fn building_east(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_east.clone()
     }
 
     /// The Building directly to the south of this building, or None if not present.
     pub fn building_south(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_south.clone()
     }
 
     /// The Building directly to the west of this building, or None if not present.
     pub fn building_west(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_west.clone()
     }
@@ -115,7 +130,7 @@ impl Building {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -126,7 +141,7 @@ impl Building {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -135,7 +150,7 @@ impl Building {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -154,8 +169,11 @@ impl Building {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -165,21 +183,87 @@ impl Building {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Building {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Building {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Building {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Building {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Building {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Building {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_building().is_some() {
             Some(Building {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -188,3 +272,47 @@ impl inner::ObjectInner for Building {
     }
 }
 impl Object for Building {}
+
+impl Serialize for Building {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Building {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Building {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Building {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -0,0 +1,89 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Forecast::direction`](super::Forecast::direction)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// `"north"`
+    North,
+    /// `"east"`
+    East,
+    /// `"south"`
+    South,
+    /// `"west"`
+    West,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Direction::North => "north",
+            Direction::East => "east",
+            Direction::South => "south",
+            Direction::West => "west",
+            Direction::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Direction {
+    fn from(s: &str) -> Direction {
+        match s {
+            "north" => Direction::North,
+            "east" => Direction::East,
+            "south" => Direction::South,
+            "west" => Direction::West,
+            other => Direction::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::from("")
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Direction::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Direction {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// Can put out fires completely.
 #[derive(Debug, Clone)]
@@ -19,20 +31,22 @@ impl FireDepartment {
         FireDepartment { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The amount of fire removed from a building when bribed to extinguish a building.
     pub fn fire_extinguished(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_fire_department()
-            .fire_extinguished.clone()
+            .fire_extinguished
     }
 
     /// _Inherited from [`Building`]_
@@ -40,9 +54,9 @@ impl FireDepartment {
     /// How much health this building currently has. When this reaches 0 the Building has been
     /// burned down.
     pub fn health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .health.clone()
+            .health
     }
 
     /// _Inherited from [`Building`]_
@@ -50,9 +64,10 @@ impl FireDepartment {
     /// The player that owns this building. If it burns down (health reaches 0) that player gets an
     /// additional bribe(s).
     pub fn owner(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .owner.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// _Inherited from [`Building`]_
@@ -60,9 +75,9 @@ impl FireDepartment {
     /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
     /// wins the game for the other Player.
     pub fn is_headquarters(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .is_headquarters.clone()
+            .is_headquarters
     }
 
     /// _Inherited from [`Building`]_
@@ -70,27 +85,27 @@ impl FireDepartment {
     /// When true this building has already been bribed this turn and cannot be bribed again this
     /// turn.
     pub fn bribed(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .bribed.clone()
+            .bribed
     }
 
     /// _Inherited from [`Building`]_
     ///
     /// The location of the Building along the x-axis.
     pub fn x(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .x.clone()
+            .x
     }
 
     /// _Inherited from [`Building`]_
     ///
     /// The location of the Building along the y-axis.
     pub fn y(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .y.clone()
+            .y
     }
 
     /// _Inherited from [`Building`]_
@@ -98,16 +113,16 @@ impl FireDepartment {
     /// How much fire is currently burning the building, and thus how much damage it will take at
     /// the end of its owner's turn. 0 means no fire.
     pub fn fire(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
-            .fire.clone()
+            .fire
     }
 
     /// _Inherited from [`Building`]_
     ///
     /// The Building directly to the north of this building, or None if not present.
     pub fn building_north(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_north.clone()
     }
@@ -116,7 +131,7 @@ impl FireDepartment {
     ///
     /// The Building directly to the east of this building, or None if not present.
     pub fn building_east(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_east.clone()
     }
@@ -125,7 +140,7 @@ impl FireDepartment {
     ///
     /// The Building directly to the south of this building, or None if not present.
     pub fn building_south(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_south.clone()
     }
@@ -134,7 +149,7 @@ impl FireDepartment {
     ///
     /// The Building directly to the west of this building, or None if not present.
     pub fn building_west(&self) -> Option<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_building()
             .building_west.clone()
     }
@@ -144,7 +159,7 @@ impl FireDepartment {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -155,7 +170,7 @@ impl FireDepartment {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -164,7 +179,7 @@ impl FireDepartment {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -184,8 +199,11 @@ impl FireDepartment {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             building: &'a Building,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -209,8 +227,11 @@ impl FireDepartment {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -220,21 +241,87 @@ impl FireDepartment {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for FireDepartment {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for FireDepartment {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for FireDepartment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for FireDepartment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FireDepartment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for FireDepartment {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_fire_department().is_some() {
             Some(FireDepartment {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -243,3 +330,47 @@ impl inner::ObjectInner for FireDepartment {
     }
 }
 impl Object for FireDepartment {}
+
+impl Serialize for FireDepartment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for FireDepartment {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for FireDepartment {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for FireDepartment {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// The weather effect that will be applied at the end of a turn, which causes fires to spread.
 #[derive(Debug, Clone)]
@@ -19,18 +31,20 @@ impl Forecast {
         Forecast { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The direction the wind will blow fires in. Can be 'north', 'east', 'south', or 'west'.
-    pub fn direction(&self) -> Str {
-        self.inner.lock().unwrap()
+    pub fn direction(&self) -> Direction {
+        lock(&self.inner)
             .as_forecast()
             .direction.clone()
     }
@@ -38,16 +52,17 @@ impl Forecast {
     /// How much of a Building's fire that can be blown in the direction of this Forecast. Fire is
     /// duplicated (copied), not moved (transfered).
     pub fn intensity(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_forecast()
-            .intensity.clone()
+            .intensity
     }
 
     /// The Player that can use WeatherStations to control this Forecast when its the nextForecast.
     pub fn controlling_player(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_forecast()
             .controlling_player.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// _Inherited from [`GameObject`]_
@@ -55,7 +70,7 @@ impl Forecast {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -66,7 +81,7 @@ impl Forecast {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -75,7 +90,7 @@ impl Forecast {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -94,8 +109,11 @@ impl Forecast {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -105,21 +123,87 @@ impl Forecast {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Forecast {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Forecast {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Forecast {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Forecast {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Forecast {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Forecast {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_forecast().is_some() {
             Some(Forecast {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -128,3 +212,47 @@ impl inner::ObjectInner for Forecast {
     }
 }
 impl Object for Forecast {}
+
+impl Serialize for Forecast {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Forecast {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Forecast {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Forecast {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
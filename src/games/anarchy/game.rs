@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// Holds top-level game state and settings for the current game.
 #[derive(Debug, Clone)]
@@ -19,111 +31,184 @@ impl Game {
         Game { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// A mapping of every game object's ID to the actual game object. Primarily used by the server
     /// and client to easily refer to the game objects via ID.
     pub fn game_objects(&self) -> Map<Str, GameObject> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .game_objects.clone()
     }
 
     /// List of all the players in the game.
     pub fn players(&self) -> List<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .players.clone()
     }
 
     /// A unique identifier for the game instance that is being played.
     pub fn session(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .session.clone()
     }
 
     /// The player whose turn it is currently. That player can send commands. Other players cannot.
     pub fn current_player(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .current_player.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The current turn number, starting at 0 for the first player's turn.
     pub fn current_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .current_turn.clone()
+        lock(&self.inner)
+            .current_turn
     }
 
     /// The maximum number of turns before the game will automatically end.
     pub fn max_turns(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_turns.clone()
+        lock(&self.inner)
+            .max_turns
     }
 
     /// The amount of time (in nano-seconds) added after each player performs a turn.
     pub fn time_added_per_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .time_added_per_turn.clone()
+        lock(&self.inner)
+            .time_added_per_turn
     }
 
     /// The width of the entire map along the horizontal (x) axis.
     pub fn map_width(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_width.clone()
+        lock(&self.inner)
+            .map_width
     }
 
     /// The width of the entire map along the vertical (y) axis.
     pub fn map_height(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_height.clone()
+        lock(&self.inner)
+            .map_height
     }
 
     /// All the buildings in the game.
     pub fn buildings(&self) -> List<Building> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .buildings.clone()
     }
 
     /// All the forecasts in the game, indexed by turn number.
     pub fn forecasts(&self) -> List<Forecast> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .forecasts.clone()
     }
 
     /// The current Forecast, which will be applied at the end of the turn.
     pub fn current_forecast(&self) -> Forecast {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .current_forecast.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The next Forecast, which will be applied at the end of your opponent's turn. This is also
     /// the Forecast WeatherStations can control this turn.
     pub fn next_forecast(&self) -> Option<Forecast> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .next_forecast.clone()
     }
 
     /// How many bribes players get at the beginning of their turn, not counting their burned down
     /// Buildings.
     pub fn base_bribes_per_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .base_bribes_per_turn.clone()
+        lock(&self.inner)
+            .base_bribes_per_turn
     }
 
     /// The maximum amount of fire value for any Building.
     pub fn max_fire(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_fire.clone()
+        lock(&self.inner)
+            .max_fire
     }
 
     /// The maximum amount of intensity value for any Forecast.
     pub fn max_forecast_intensity(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_forecast_intensity.clone()
+        lock(&self.inner)
+            .max_forecast_intensity
+    }
+
+    /// Looks up a game object by its id.
+    ///
+    /// Returns `None` if there is no such object, if it has been removed from the game, or if it
+    /// is not a `T`.
+    pub fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.inner).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// The settings given to the AI for this game, like `--aiSettings` on the command line.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn ai_settings(&self) -> Result<AiSettings, Error> {
+        self.with_context(|cx| Ok(cx.ai_settings().clone()))
+    }
+
+    /// Takes an immutable copy of the whole game state, which can be read without locking.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        self.with_context(|cx| Ok(cx.snapshot()))
+    }
+}
+
+/// Handles are equal when they refer to the same game, regardless of its state.
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Game {}
+
+/// Handles hash by the identity of the game they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+impl AnyGame for Game {
+    type GameObject = GameObject;
+    type Player = Player;
+
+    fn session(&self) -> Str {
+        self.session()
+    }
+
+    fn players(&self) -> List<Player> {
+        self.players()
+    }
+
+    fn game_objects(&self) -> Map<Str, GameObject> {
+        self.game_objects()
+    }
+}
+
+impl TurnBasedGame for Game {
+    fn current_turn(&self) -> i64 {
+        self.current_turn()
+    }
+
+    fn max_turns(&self) -> i64 {
+        self.max_turns()
     }
 }
@@ -1,24 +1,490 @@
 #![allow(unused_imports, dead_code)]
 
+use std::collections::{BTreeSet, HashMap};
+use std::io;
 use std::sync::{Arc, Mutex, Weak};
 
+use serde::de::Deserialize;
+use serde::ser::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::connection::Connection;
+use crate::client::delta::{self, Changes, DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::gamelog::Gamelog;
+use crate::client::outcome::GameOutcome;
+use crate::client::proto::{ClientEvent, ServerEvent};
+use crate::client::runtime::{self, unexpected_event, GameOver, Handler};
+use crate::client::settings::{AiSettings, GameSetting};
+use crate::client::util::lock;
+use crate::games::{Session, Spectator};
 use crate::types::*;
 use crate::error::Error;
 
 #[derive(Debug, Clone)]
 pub struct Context {
-    self_ref: Option<Weak<Mutex<Context>>>,
-    game: Game,
+    self_ref: Weak<Mutex<Context>>,
+    game: Arc<Mutex<GameBase>>,
+    connection: Arc<Mutex<Connection>>,
+    delta: delta::Context,
+    changes: Changes,
+    invalid_messages: Vec<String>,
+    ai_settings: AiSettings,
 }
 
 impl Context {
+    /// Creates the context for a new game, with no state until the first delta is merged.
+    pub(crate) fn new(
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        ai_settings: AiSettings,
+    ) -> Arc<Mutex<Context>> {
+        Arc::new_cyclic(|self_ref| {
+            Mutex::new(Context {
+                self_ref: self_ref.clone(),
+                game: Default::default(),
+                connection,
+                delta,
+                changes: Changes::default(),
+                invalid_messages: Vec::new(),
+                ai_settings,
+            })
+        })
+    }
+
     pub(crate) fn get_ref(&self) -> Weak<Mutex<Context>> {
-        self.self_ref.clone().unwrap()
+        self.self_ref.clone()
+    }
+
+    pub(crate) fn game(&self) -> Game {
+        Game::new(Arc::clone(&self.game), self.get_ref())
+    }
+
+    pub(crate) fn ai_settings(&self) -> &AiSettings {
+        &self.ai_settings
+    }
+
+    /// Takes a snapshot of the current state. Holding the context keeps deltas from being merged
+    /// while it is copied.
+    pub(crate) fn snapshot(&self) -> Snapshot {
+        Snapshot::new(lock(&self.game).clone())
+    }
+
+    /// Looks up a game object by its id, if it exists and is of type `T`.
+    pub(crate) fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.game).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// Checks whether a game object is still in the game, rather than removed by a delta.
+    pub(crate) fn contains(&self, obj: &Arc<Mutex<AnyGameObject>>) -> bool {
+        let id = lock(obj).id();
+        let game = lock(&self.game);
+        game.game_objects.get(&id).is_some_and(|handle| Arc::ptr_eq(handle.game_object(), obj))
+    }
+
+    /// Asks the server to run a function on the given game object, and waits for the result.
+    ///
+    /// Deltas sent by the server before the result are merged as they arrive, so any objects
+    /// referred to by the result are already known when it is decoded.
+    pub(crate) fn run<A, R>(&mut self, caller: &str, function_name: &str, args: A) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: FromDelta<Context>,
+    {
+        let args = serde_json::from_value(serde_json::to_value(args)?)?;
+        lock(&self.connection).send(ClientEvent::Run {
+            caller: ObjRef::new(caller),
+            function_name: function_name.to_string(),
+            args,
+        })?;
+        loop {
+            let event = lock(&self.connection).recv()?;
+            match event {
+                ServerEvent::Delta(delta) => self.merge_delta(delta)?,
+                ServerEvent::Invalid { message } => self.invalid_messages.push(message),
+                ServerEvent::Ran(value) => return R::from_delta(value, self),
+                event => return Err(unexpected_event(event)),
+            }
+        }
+    }
+
+    /// Merges a delta from the server into the game state, recording what changed until the
+    /// changes are taken.
+    ///
+    /// New game objects are created before anything else is merged, so that references to them
+    /// can be resolved wherever they appear. Each object is merged into a copy of its state which
+    /// is written back afterwards, so that it can refer to itself without deadlocking.
+    ///
+    /// Any error, like a value of the wrong type, fails with [`Exit::DeltaMergeFailure`].
+    pub(crate) fn merge_delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        self.merge(delta)
+            .map_err(|err| err.with_exit(Exit::DeltaMergeFailure))
+    }
+
+    fn merge(&mut self, mut delta: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(objects) = delta.remove("gameObjects") {
+            self.merge_game_objects(objects)?;
+        }
+
+        let fields: Vec<_> = delta.keys().filter_map(|key| field_name(key)).collect();
+        let mut game = lock(&self.game).clone();
+        game.merge_delta(Value::Object(delta.into_iter().collect()), self)?;
+        *lock(&self.game) = game;
+        for field in fields {
+            self.changes.change_game(field);
+        }
+        Ok(())
+    }
+
+    /// Takes the changes recorded since they were last taken.
+    pub(crate) fn take_changes(&mut self) -> Changes {
+        std::mem::take(&mut self.changes)
+    }
+
+    fn merge_game_objects(&mut self, delta: Value) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+
+        let mut game_objects = lock(&self.game).game_objects.clone();
+        for (id, obj_delta) in &delta {
+            if *obj_delta == self.delta.delta_removed() {
+                game_objects.remove(id.as_str());
+                self.changes.remove(id);
+            } else if game_objects.get(id.as_str()).is_none() {
+                let name = obj_delta.get("gameObjectName")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let obj = AnyGameObject::new(name).ok_or_else(|| {
+                    let message = format!("unknown game object type {:?} for id {:?}", name, id);
+                    Error::from((Exit::DeltaMergeFailure, message))
+                })?;
+                let handle = GameObject::new(Arc::new(Mutex::new(obj)), self.get_ref());
+                game_objects.insert(id.as_str().into(), handle);
+                self.changes.create(id);
+            }
+        }
+        lock(&self.game).game_objects = game_objects.clone();
+
+        for (id, obj_delta) in delta {
+            if obj_delta == self.delta.delta_removed() {
+                continue;
+            }
+            let handle = game_objects.get(id.as_str()).expect("unreachable: object not created");
+            let obj = Arc::clone(handle.game_object());
+            let fields: Vec<_> = obj_delta.as_object()
+                .map(|attrs| attrs.keys().filter_map(|key| field_name(key)).collect())
+                .unwrap_or_default();
+            let mut state = lock(&obj).clone();
+            state.merge_delta(obj_delta, self)?;
+            *lock(&obj) = state;
+            for field in fields {
+                self.changes.change(&id, field);
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the game object referred to by an `{"id": ...}` value.
+    pub(crate) fn resolve<T: Object>(&self, delta: Value) -> Result<T, Error> {
+        let obj_ref = ObjRef::deserialize(&delta)?;
+        self.get_object(obj_ref.id()).ok_or_else(|| {
+            let message = format!("no game object with id {:?}", obj_ref.id());
+            (Exit::DeltaMergeFailure, message).into()
+        })
+    }
+}
+
+impl AsRef<delta::Context> for Context {
+    fn as_ref(&self) -> &delta::Context {
+        &self.delta
+    }
+}
+
+/// An AI waiting for its game to start.
+pub(crate) struct Pending<A> {
+    ai: A,
+}
+
+impl<A> Pending<A> {
+    pub(crate) fn new(ai: A) -> Pending<A> {
+        Pending { ai }
+    }
+}
+
+impl<A: AI> Session for Pending<A> {
+    fn game_name(&self) -> &str {
+        GAME_NAME
+    }
+
+    fn settings(&self) -> &'static [GameSetting] {
+        SETTINGS
+    }
+
+    fn player_name(&self) -> String {
+        self.ai.get_name()
+    }
+
+    fn play(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+        player_id: &str,
+        ai_settings: AiSettings,
+    ) -> Result<GameOutcome, Error> {
+        let context = Context::new(Arc::clone(&connection), delta, ai_settings);
+        let (game, player) = {
+            let mut cx = lock(&context);
+            cx.merge_delta(initial_delta)?;
+            // The AI sees the whole initial state when it starts.
+            cx.take_changes();
+            let player = cx.get_object(player_id).ok_or_else(|| {
+                let message = format!("no player with id {:?}", player_id);
+                Error::from((Exit::DeltaMergeFailure, message))
+            })?;
+            (cx.game(), player)
+        };
+
+        let mut runner = Runner::new(context, game, player, self.ai);
+        runner.start()?;
+        let over = runtime::run(&connection, &mut runner)?;
+        Ok(outcome(&runner.game, &runner.player, over))
+    }
+
+    fn replay(self: Box<Self>, gamelog: &Gamelog, player_index: usize) -> Result<(), Error> {
+        replay(gamelog, player_index, self.ai).map(|_| ())
+    }
+}
+
+pub(crate) fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, mut ai: A) -> Result<A, Error> {
+    let context = offline_context(gamelog)?;
+    let mut started: Option<(Game, Player)> = None;
+    for delta in &gamelog.deltas {
+        if let Some(game_delta) = &delta.game {
+            lock(&context).merge_delta(game_delta.clone())?;
+        }
+        let changes = lock(&context).take_changes();
+        match &started {
+            Some((game, player)) => ai.game_updated(game, player, &changes).map_err(ai_errored)?,
+            None => {
+                let game = lock(&context).game();
+                // The players are not known until the first delta that sets up the game.
+                if let Some(player) = game.players().get(player_index).cloned() {
+                    ai.start(&game, &player).map_err(ai_errored)?;
+                    started = Some((game, player));
+                }
+            }
+        }
+    }
+
+    let (_, player) = started.ok_or_else(|| {
+        let message = format!("gamelog has no player with index {}", player_index);
+        Error::from((Exit::InvalidArgs, message))
+    })?;
+    end(&mut ai, &player);
+    Ok(ai)
+}
+
+/// Creates the context to replay a gamelog of this game in.
+fn offline_context(gamelog: &Gamelog) -> Result<Arc<Mutex<Context>>, Error> {
+    if gamelog.game_name != GAME_NAME {
+        let message = format!("gamelog is for {:?}, not {:?}", gamelog.game_name, GAME_NAME);
+        return Err((Exit::GameNotFound, message).into());
+    }
+
+    // There is no server to run functions, so trying to is treated like a lost connection.
+    let connection = Connection::new(io::empty(), io::sink());
+    let constants = delta::Context::from_constants(&gamelog.constants)?;
+    Ok(Context::new(Arc::new(Mutex::new(connection)), constants, AiSettings::new()))
+}
+
+fn end<A: AI>(ai: &mut A, player: &Player) {
+    let won = player.won();
+    let reason = if won {
+        player.reason_won()
+    } else {
+        player.reason_lost()
+    };
+    ai.end(won, &reason);
+}
+
+/// Marks an error returned by the AI as [`Exit::AiErrored`], unless it already has an exit code,
+/// like the errors of game object functions do.
+fn ai_errored(err: Error) -> Error {
+    match err.exit() {
+        Some(_) => err,
+        None => err.with_exit(Exit::AiErrored),
+    }
+}
+
+fn outcome(game: &Game, player: &Player, over: GameOver) -> GameOutcome {
+    GameOutcome {
+        game_name: GAME_NAME.to_string(),
+        game_session: game.session().to_string(),
+        won: player.won(),
+        lost: player.lost(),
+        reason_won: player.reason_won().to_string(),
+        reason_lost: player.reason_lost().to_string(),
+        final_turn: Some(game.current_turn()),
+        gamelog_url: over.gamelog_url,
+        visualizer_url: over.visualizer_url,
+    }
+}
+
+/// Forwards the runtime's events to an [`AI`].
+pub(crate) struct Runner<A> {
+    context: Arc<Mutex<Context>>,
+    game: Game,
+    player: Player,
+    ai: A,
+}
+
+impl<A: AI> Runner<A> {
+    pub(crate) fn new(context: Arc<Mutex<Context>>, game: Game, player: Player, ai: A) -> Runner<A> {
+        Runner { context, game, player, ai }
+    }
+
+    pub(crate) fn start(&mut self) -> Result<(), Error> {
+        self.ai.start(&self.game, &self.player).map_err(ai_errored)
+    }
+
+    fn decode_arg<T>(&self, arg: Option<Value>) -> Result<T, Error>
+    where
+        T: FromDelta<Context>,
+    {
+        let cx = lock(&self.context);
+        T::from_delta(arg.unwrap_or(Value::Null), &cx)
+    }
+}
+
+impl<A: AI> Handler for Runner<A> {
+    fn delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        let changes = {
+            let mut cx = lock(&self.context);
+            cx.merge_delta(delta)?;
+            cx.take_changes()
+        };
+        self.ai.game_updated(&self.game, &self.player, &changes).map_err(ai_errored)
+    }
+
+    fn order(&mut self, name: &str, _args: Vec<Value>) -> Result<Value, Error> {
+        let returned = match name {
+            "runTurn" => {
+                let returned = self.ai.run_turn(&self.game, &self.player).map_err(ai_errored)?;
+                serde_json::to_value(returned)?
+            }
+            _ => {
+                let message = format!("unknown order {:?}", name);
+                return Err((Exit::UnknownEventFromServer, message).into());
+            }
+        };
+
+        // The AI can't be told about invalid runs while it is still carrying out the order.
+        let messages = std::mem::take(&mut lock(&self.context).invalid_messages);
+        for message in messages {
+            self.ai.invalid(&message);
+        }
+        Ok(returned)
+    }
+
+    fn invalid(&mut self, message: &str) -> Result<(), Error> {
+        self.ai.invalid(message);
+        Ok(())
+    }
+
+    fn over(&mut self) -> Result<(), Error> {
+        end(&mut self.ai, &self.player);
+        Ok(())
+    }
+}
+
+/// A spectator waiting for its game to start, which is shown the state each time it changes.
+pub(crate) struct Watching<F> {
+    on_update: F,
+}
+
+impl<F> Watching<F> {
+    pub(crate) fn new(on_update: F) -> Watching<F> {
+        Watching { on_update }
+    }
+}
+
+impl<F> Spectator for Watching<F>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error>,
+{
+    fn game_name(&self) -> &str {
+        GAME_NAME
     }
 
-    pub(crate) fn run<A, R>(&mut self, _caller: &str, _function_name: &str, _args: A) -> Result<R, Error> {
-        unimplemented!()
+    fn watch(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        let context = Context::new(Arc::clone(&connection), delta, AiSettings::new());
+        let game = lock(&context).game();
+        let mut watcher = Watcher {
+            context,
+            game,
+            on_update: self.on_update,
+        };
+        // Unlike an AI, the spectator is shown the initial state as a change.
+        watcher.delta(initial_delta)?;
+        runtime::run(&connection, &mut watcher).map(|_| ())
+    }
+
+    fn replay(self: Box<Self>, gamelog: &Gamelog) -> Result<(), Error> {
+        let context = offline_context(gamelog)?;
+        let game = lock(&context).game();
+        let mut watcher = Watcher {
+            context,
+            game,
+            on_update: self.on_update,
+        };
+        for delta in &gamelog.deltas {
+            if let Some(game_delta) = &delta.game {
+                watcher.delta(game_delta.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Forwards the runtime's deltas to a spectator. Spectators are never given orders.
+pub(crate) struct Watcher<F> {
+    context: Arc<Mutex<Context>>,
+    game: Game,
+    on_update: F,
+}
+
+impl<F> Handler for Watcher<F>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error>,
+{
+    fn delta(&mut self, delta: HashMap<String, Value>) -> Result<(), Error> {
+        let changes = {
+            let mut cx = lock(&self.context);
+            cx.merge_delta(delta)?;
+            cx.take_changes()
+        };
+        (self.on_update)(&self.game, &changes).map_err(ai_errored)
+    }
+
+    fn order(&mut self, name: &str, _args: Vec<Value>) -> Result<Value, Error> {
+        let message = format!("spectators can't carry out order {:?}", name);
+        Err((Exit::UnknownEventFromServer, message).into())
+    }
+
+    fn invalid(&mut self, _message: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn over(&mut self) -> Result<(), Error> {
+        Ok(())
     }
 }
 
@@ -26,6 +492,8 @@ pub trait Object: ObjectInner {
 }
 
 pub trait ObjectInner: Sized {
+    fn game_object(&self) -> &Arc<Mutex<AnyGameObject>>;
+
     fn from_game_object(game_obj: &Arc<Mutex<AnyGameObject>>, context: &Weak<Mutex<Context>>) -> Option<Self>;
 }
 
@@ -42,6 +510,21 @@ pub enum AnyGameObject {
 }
 
 impl AnyGameObject {
+    /// Creates an object with default state, given its `gameObjectName`.
+    pub fn new(game_object_name: &str) -> Option<AnyGameObject> {
+        match game_object_name {
+            "GameObject" => Some(AnyGameObject::GameObject(Default::default())),
+            "Player" => Some(AnyGameObject::Player(Default::default())),
+            "Building" => Some(AnyGameObject::Building(Default::default())),
+            "Warehouse" => Some(AnyGameObject::Warehouse(Default::default())),
+            "FireDepartment" => Some(AnyGameObject::FireDepartment(Default::default())),
+            "WeatherStation" => Some(AnyGameObject::WeatherStation(Default::default())),
+            "PoliceDepartment" => Some(AnyGameObject::PoliceDepartment(Default::default())),
+            "Forecast" => Some(AnyGameObject::Forecast(Default::default())),
+            _ => None,
+        }
+    }
+
     pub fn id(&self) -> Str {
         self.as_game_object().id.clone()
     }
@@ -187,65 +670,374 @@ impl AnyGameObject {
     }
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for AnyGameObject {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        match self {
+            AnyGameObject::GameObject(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::Player(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::Building(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::Warehouse(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::FireDepartment(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::WeatherStation(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::PoliceDepartment(obj) => obj.merge_delta(delta, cx),
+            AnyGameObject::Forecast(obj) => obj.merge_delta(delta, cx),
+        }
+    }
+}
+
+/// Converts the name of an attribute on the server to the name of its accessor, if it has one.
+fn field_name(attr_name: &str) -> Option<&'static str> {
+    match attr_name {
+        "baseBribesPerTurn" => Some("base_bribes_per_turn"),
+        "bribed" => Some("bribed"),
+        "bribesRemaining" => Some("bribes_remaining"),
+        "buildingEast" => Some("building_east"),
+        "buildingNorth" => Some("building_north"),
+        "buildingSouth" => Some("building_south"),
+        "buildingWest" => Some("building_west"),
+        "buildings" => Some("buildings"),
+        "clientType" => Some("client_type"),
+        "controllingPlayer" => Some("controlling_player"),
+        "currentForecast" => Some("current_forecast"),
+        "currentPlayer" => Some("current_player"),
+        "currentTurn" => Some("current_turn"),
+        "direction" => Some("direction"),
+        "exposure" => Some("exposure"),
+        "fire" => Some("fire"),
+        "fireAdded" => Some("fire_added"),
+        "fireDepartments" => Some("fire_departments"),
+        "fireExtinguished" => Some("fire_extinguished"),
+        "forecasts" => Some("forecasts"),
+        "gameObjectName" => Some("game_object_name"),
+        "gameObjects" => Some("game_objects"),
+        "headquarters" => Some("headquarters"),
+        "health" => Some("health"),
+        "id" => Some("id"),
+        "intensity" => Some("intensity"),
+        "isHeadquarters" => Some("is_headquarters"),
+        "logs" => Some("logs"),
+        "lost" => Some("lost"),
+        "mapHeight" => Some("map_height"),
+        "mapWidth" => Some("map_width"),
+        "maxFire" => Some("max_fire"),
+        "maxForecastIntensity" => Some("max_forecast_intensity"),
+        "maxTurns" => Some("max_turns"),
+        "name" => Some("name"),
+        "nextForecast" => Some("next_forecast"),
+        "opponent" => Some("opponent"),
+        "owner" => Some("owner"),
+        "players" => Some("players"),
+        "policeDepartments" => Some("police_departments"),
+        "reasonLost" => Some("reason_lost"),
+        "reasonWon" => Some("reason_won"),
+        "session" => Some("session"),
+        "timeAddedPerTurn" => Some("time_added_per_turn"),
+        "timeRemaining" => Some("time_remaining"),
+        "warehouses" => Some("warehouses"),
+        "weatherStations" => Some("weather_stations"),
+        "won" => Some("won"),
+        "x" => Some("x"),
+        "y" => Some("y"),
+        // Unknown attributes are skipped when merging, so they aren't recorded as changes.
+        _ => None,
+    }
+}
+
+/// Servers may send attributes that are newer than these structures. They are skipped so the
+/// game can go on, with a warning the first time each one is seen.
+fn unknown_attribute(obj_key: &str, attr_name: &str) {
+    static WARNED: Mutex<BTreeSet<(String, String)>> = Mutex::new(BTreeSet::new());
+    if lock(&WARNED).insert((obj_key.to_string(), attr_name.to_string())) {
+        eprintln!("Warning: skipping unknown attribute {:?} of {}", attr_name, obj_key);
+    }
+}
+
+/// Object references that can't be null are still stored as options, since objects are created
+/// before they are merged. Checking them after each merge keeps their accessors infallible.
+fn missing_attribute(obj_key: &str, attr_name: &str) -> Error {
+    let message = format!("{} attribute {:?} was not set by the server", obj_key, attr_name);
+    (Exit::DeltaMergeFailure, message).into()
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct GameObjectInner {
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for GameObjectInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("GameObject", &key),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct PlayerInner {
     pub player: PlayerBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for PlayerInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "name" => self.player.name.merge_delta(value, cx)?,
+                "clientType" => self.player.client_type.merge_delta(value, cx)?,
+                "won" => self.player.won.merge_delta(value, cx)?,
+                "lost" => self.player.lost.merge_delta(value, cx)?,
+                "reasonWon" => self.player.reason_won.merge_delta(value, cx)?,
+                "reasonLost" => self.player.reason_lost.merge_delta(value, cx)?,
+                "timeRemaining" => self.player.time_remaining.merge_delta(value, cx)?,
+                "opponent" => self.player.opponent.merge_delta(value, cx)?,
+                "bribesRemaining" => self.player.bribes_remaining.merge_delta(value, cx)?,
+                "headquarters" => self.player.headquarters.merge_delta(value, cx)?,
+                "buildings" => self.player.buildings.merge_delta(value, cx)?,
+                "warehouses" => self.player.warehouses.merge_delta(value, cx)?,
+                "fireDepartments" => self.player.fire_departments.merge_delta(value, cx)?,
+                "policeDepartments" => self.player.police_departments.merge_delta(value, cx)?,
+                "weatherStations" => self.player.weather_stations.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("Player", &key),
+            }
+        }
+        if self.player.opponent.is_none() {
+            return Err(missing_attribute("Player", "opponent"));
+        }
+        if self.player.headquarters.is_none() {
+            return Err(missing_attribute("Player", "headquarters"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct BuildingInner {
     pub building: BuildingBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for BuildingInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "health" => self.building.health.merge_delta(value, cx)?,
+                "owner" => self.building.owner.merge_delta(value, cx)?,
+                "isHeadquarters" => self.building.is_headquarters.merge_delta(value, cx)?,
+                "bribed" => self.building.bribed.merge_delta(value, cx)?,
+                "x" => self.building.x.merge_delta(value, cx)?,
+                "y" => self.building.y.merge_delta(value, cx)?,
+                "fire" => self.building.fire.merge_delta(value, cx)?,
+                "buildingNorth" => self.building.building_north.merge_delta(value, cx)?,
+                "buildingEast" => self.building.building_east.merge_delta(value, cx)?,
+                "buildingSouth" => self.building.building_south.merge_delta(value, cx)?,
+                "buildingWest" => self.building.building_west.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("Building", &key),
+            }
+        }
+        if self.building.owner.is_none() {
+            return Err(missing_attribute("Building", "owner"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct WarehouseInner {
     pub warehouse: WarehouseBase,
     pub building: BuildingBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for WarehouseInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "fireAdded" => self.warehouse.fire_added.merge_delta(value, cx)?,
+                "exposure" => self.warehouse.exposure.merge_delta(value, cx)?,
+                "health" => self.building.health.merge_delta(value, cx)?,
+                "owner" => self.building.owner.merge_delta(value, cx)?,
+                "isHeadquarters" => self.building.is_headquarters.merge_delta(value, cx)?,
+                "bribed" => self.building.bribed.merge_delta(value, cx)?,
+                "x" => self.building.x.merge_delta(value, cx)?,
+                "y" => self.building.y.merge_delta(value, cx)?,
+                "fire" => self.building.fire.merge_delta(value, cx)?,
+                "buildingNorth" => self.building.building_north.merge_delta(value, cx)?,
+                "buildingEast" => self.building.building_east.merge_delta(value, cx)?,
+                "buildingSouth" => self.building.building_south.merge_delta(value, cx)?,
+                "buildingWest" => self.building.building_west.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("Warehouse", &key),
+            }
+        }
+        if self.building.owner.is_none() {
+            return Err(missing_attribute("Warehouse", "owner"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct FireDepartmentInner {
     pub fire_department: FireDepartmentBase,
     pub building: BuildingBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for FireDepartmentInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "fireExtinguished" => self.fire_department.fire_extinguished.merge_delta(value, cx)?,
+                "health" => self.building.health.merge_delta(value, cx)?,
+                "owner" => self.building.owner.merge_delta(value, cx)?,
+                "isHeadquarters" => self.building.is_headquarters.merge_delta(value, cx)?,
+                "bribed" => self.building.bribed.merge_delta(value, cx)?,
+                "x" => self.building.x.merge_delta(value, cx)?,
+                "y" => self.building.y.merge_delta(value, cx)?,
+                "fire" => self.building.fire.merge_delta(value, cx)?,
+                "buildingNorth" => self.building.building_north.merge_delta(value, cx)?,
+                "buildingEast" => self.building.building_east.merge_delta(value, cx)?,
+                "buildingSouth" => self.building.building_south.merge_delta(value, cx)?,
+                "buildingWest" => self.building.building_west.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("FireDepartment", &key),
+            }
+        }
+        if self.building.owner.is_none() {
+            return Err(missing_attribute("FireDepartment", "owner"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct WeatherStationInner {
     pub weather_station: WeatherStationBase,
     pub building: BuildingBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for WeatherStationInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "health" => self.building.health.merge_delta(value, cx)?,
+                "owner" => self.building.owner.merge_delta(value, cx)?,
+                "isHeadquarters" => self.building.is_headquarters.merge_delta(value, cx)?,
+                "bribed" => self.building.bribed.merge_delta(value, cx)?,
+                "x" => self.building.x.merge_delta(value, cx)?,
+                "y" => self.building.y.merge_delta(value, cx)?,
+                "fire" => self.building.fire.merge_delta(value, cx)?,
+                "buildingNorth" => self.building.building_north.merge_delta(value, cx)?,
+                "buildingEast" => self.building.building_east.merge_delta(value, cx)?,
+                "buildingSouth" => self.building.building_south.merge_delta(value, cx)?,
+                "buildingWest" => self.building.building_west.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("WeatherStation", &key),
+            }
+        }
+        if self.building.owner.is_none() {
+            return Err(missing_attribute("WeatherStation", "owner"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct PoliceDepartmentInner {
     pub police_department: PoliceDepartmentBase,
     pub building: BuildingBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for PoliceDepartmentInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "health" => self.building.health.merge_delta(value, cx)?,
+                "owner" => self.building.owner.merge_delta(value, cx)?,
+                "isHeadquarters" => self.building.is_headquarters.merge_delta(value, cx)?,
+                "bribed" => self.building.bribed.merge_delta(value, cx)?,
+                "x" => self.building.x.merge_delta(value, cx)?,
+                "y" => self.building.y.merge_delta(value, cx)?,
+                "fire" => self.building.fire.merge_delta(value, cx)?,
+                "buildingNorth" => self.building.building_north.merge_delta(value, cx)?,
+                "buildingEast" => self.building.building_east.merge_delta(value, cx)?,
+                "buildingSouth" => self.building.building_south.merge_delta(value, cx)?,
+                "buildingWest" => self.building.building_west.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("PoliceDepartment", &key),
+            }
+        }
+        if self.building.owner.is_none() {
+            return Err(missing_attribute("PoliceDepartment", "owner"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct ForecastInner {
     pub forecast: ForecastBase,
     pub game_object: GameObjectBase,
 }
 
-#[derive(Debug, Clone)]
+impl DeltaMergeable<Context> for ForecastInner {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "direction" => self.forecast.direction.merge_delta(value, cx)?,
+                "intensity" => self.forecast.intensity.merge_delta(value, cx)?,
+                "controllingPlayer" => self.forecast.controlling_player.merge_delta(value, cx)?,
+                "id" => self.game_object.id.merge_delta(value, cx)?,
+                "gameObjectName" => self.game_object.game_object_name.merge_delta(value, cx)?,
+                "logs" => self.game_object.logs.merge_delta(value, cx)?,
+                _ => unknown_attribute("Forecast", &key),
+            }
+        }
+        if self.forecast.controlling_player.is_none() {
+            return Err(missing_attribute("Forecast", "controllingPlayer"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct GameObjectBase {
     pub id: Str,
     pub game_object_name: Str,
     pub logs: List<Str>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PlayerBase {
     pub name: Str,
     pub client_type: Str,
@@ -254,9 +1046,9 @@ pub struct PlayerBase {
     pub reason_won: Str,
     pub reason_lost: Str,
     pub time_remaining: f64,
-    pub opponent: Player,
+    pub opponent: Option<Player>,
     pub bribes_remaining: i64,
-    pub headquarters: Warehouse,
+    pub headquarters: Option<Warehouse>,
     pub buildings: List<Building>,
     pub warehouses: List<Warehouse>,
     pub fire_departments: List<FireDepartment>,
@@ -264,10 +1056,10 @@ pub struct PlayerBase {
     pub weather_stations: List<WeatherStation>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct BuildingBase {
     pub health: i64,
-    pub owner: Player,
+    pub owner: Option<Player>,
     pub is_headquarters: bool,
     pub bribed: bool,
     pub x: i64,
@@ -279,38 +1071,38 @@ pub struct BuildingBase {
     pub building_west: Option<Building>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct WarehouseBase {
     pub fire_added: i64,
     pub exposure: i64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct FireDepartmentBase {
     pub fire_extinguished: i64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct WeatherStationBase {
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PoliceDepartmentBase {
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ForecastBase {
-    pub direction: Str,
+    pub direction: Direction,
     pub intensity: i64,
-    pub controlling_player: Player,
+    pub controlling_player: Option<Player>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct GameBase {
     pub game_objects: Map<Str, GameObject>,
     pub players: List<Player>,
     pub session: Str,
-    pub current_player: Player,
+    pub current_player: Option<Player>,
     pub current_turn: i64,
     pub max_turns: i64,
     pub time_added_per_turn: i64,
@@ -318,9 +1110,43 @@ pub struct GameBase {
     pub map_height: i64,
     pub buildings: List<Building>,
     pub forecasts: List<Forecast>,
-    pub current_forecast: Forecast,
+    pub current_forecast: Option<Forecast>,
     pub next_forecast: Option<Forecast>,
     pub base_bribes_per_turn: i64,
     pub max_fire: i64,
     pub max_forecast_intensity: i64,
 }
+
+impl DeltaMergeable<Context> for GameBase {
+    fn merge_delta(&mut self, delta: Value, cx: &Context) -> Result<(), Error> {
+        let delta: serde_json::Map<String, Value> = serde_json::from_value(delta)?;
+        for (key, value) in delta {
+            match key.as_str() {
+                "gameObjects" => self.game_objects.merge_delta(value, cx)?,
+                "players" => self.players.merge_delta(value, cx)?,
+                "session" => self.session.merge_delta(value, cx)?,
+                "currentPlayer" => self.current_player.merge_delta(value, cx)?,
+                "currentTurn" => self.current_turn.merge_delta(value, cx)?,
+                "maxTurns" => self.max_turns.merge_delta(value, cx)?,
+                "timeAddedPerTurn" => self.time_added_per_turn.merge_delta(value, cx)?,
+                "mapWidth" => self.map_width.merge_delta(value, cx)?,
+                "mapHeight" => self.map_height.merge_delta(value, cx)?,
+                "buildings" => self.buildings.merge_delta(value, cx)?,
+                "forecasts" => self.forecasts.merge_delta(value, cx)?,
+                "currentForecast" => self.current_forecast.merge_delta(value, cx)?,
+                "nextForecast" => self.next_forecast.merge_delta(value, cx)?,
+                "baseBribesPerTurn" => self.base_bribes_per_turn.merge_delta(value, cx)?,
+                "maxFire" => self.max_fire.merge_delta(value, cx)?,
+                "maxForecastIntensity" => self.max_forecast_intensity.merge_delta(value, cx)?,
+                _ => unknown_attribute("Game", &key),
+            }
+        }
+        if self.current_player.is_none() {
+            return Err(missing_attribute("Game", "currentPlayer"));
+        }
+        if self.current_forecast.is_none() {
+            return Err(missing_attribute("Game", "currentForecast"));
+        }
+        Ok(())
+    }
+}
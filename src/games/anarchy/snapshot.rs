@@ -0,0 +1,1353 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::Direction;
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Building`](super::Building) at the time of this snapshot.
+    pub fn building(&self, obj: &super::Building) -> Option<Building<'_>> {
+        Building::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Warehouse`](super::Warehouse) at the time of this snapshot.
+    pub fn warehouse(&self, obj: &super::Warehouse) -> Option<Warehouse<'_>> {
+        Warehouse::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`FireDepartment`](super::FireDepartment) at the time of this snapshot.
+    pub fn fire_department(&self, obj: &super::FireDepartment) -> Option<FireDepartment<'_>> {
+        FireDepartment::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`WeatherStation`](super::WeatherStation) at the time of this snapshot.
+    pub fn weather_station(&self, obj: &super::WeatherStation) -> Option<WeatherStation<'_>> {
+        WeatherStation::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`PoliceDepartment`](super::PoliceDepartment) at the time of this snapshot.
+    pub fn police_department(&self, obj: &super::PoliceDepartment) -> Option<PoliceDepartment<'_>> {
+        PoliceDepartment::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Forecast`](super::Forecast) at the time of this snapshot.
+    pub fn forecast(&self, obj: &super::Forecast) -> Option<Forecast<'_>> {
+        Forecast::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The width of the entire map along the horizontal (x) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The width of the entire map along the vertical (y) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the buildings in the game.
+    pub fn buildings(&self) -> Vec<Building<'a>> {
+        self.snapshot.game.buildings
+            .iter()
+            .filter_map(|obj| Building::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the forecasts in the game, indexed by turn number.
+    pub fn forecasts(&self) -> Vec<Forecast<'a>> {
+        self.snapshot.game.forecasts
+            .iter()
+            .filter_map(|obj| Forecast::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The current Forecast, which will be applied at the end of the turn.
+    pub fn current_forecast(&self) -> Option<Forecast<'a>> {
+        self.snapshot.game.current_forecast
+            .as_ref()
+            .and_then(|obj| Forecast::resolve(self.snapshot, obj))
+    }
+
+    /// The next Forecast, which will be applied at the end of your opponent's turn. This is also
+    /// the Forecast WeatherStations can control this turn.
+    pub fn next_forecast(&self) -> Option<Forecast<'a>> {
+        self.snapshot.game.next_forecast
+            .as_ref()
+            .and_then(|obj| Forecast::resolve(self.snapshot, obj))
+    }
+
+    /// How many bribes players get at the beginning of their turn, not counting their burned down
+    /// Buildings.
+    pub fn base_bribes_per_turn(&self) -> i64 {
+        self.snapshot.game.base_bribes_per_turn
+    }
+
+    /// The maximum amount of fire value for any Building.
+    pub fn max_fire(&self) -> i64 {
+        self.snapshot.game.max_fire
+    }
+
+    /// The maximum amount of intensity value for any Forecast.
+    pub fn max_forecast_intensity(&self) -> i64 {
+        self.snapshot.game.max_forecast_intensity
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// How many bribes this player has remaining to use during their turn. Each action a Building
+    /// does costs 1 bribe. Any unused bribes are lost at the end of the player's turn.
+    pub fn bribes_remaining(&self) -> i64 {
+        self.state.as_player().bribes_remaining
+    }
+
+    /// The Warehouse that serves as this player's headquarters and has extra health. If this gets
+    /// destroyed they lose.
+    pub fn headquarters(&self) -> Option<Warehouse<'a>> {
+        self.state.as_player().headquarters
+            .as_ref()
+            .and_then(|obj| Warehouse::resolve(self.snapshot, obj))
+    }
+
+    /// All the buildings owned by this player.
+    pub fn buildings(&self) -> Vec<Building<'a>> {
+        self.state.as_player().buildings
+            .iter()
+            .filter_map(|obj| Building::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the warehouses owned by this player. Includes the Headquarters.
+    pub fn warehouses(&self) -> Vec<Warehouse<'a>> {
+        self.state.as_player().warehouses
+            .iter()
+            .filter_map(|obj| Warehouse::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the FireDepartments owned by this player.
+    pub fn fire_departments(&self) -> Vec<FireDepartment<'a>> {
+        self.state.as_player().fire_departments
+            .iter()
+            .filter_map(|obj| FireDepartment::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the PoliceDepartments owned by this player.
+    pub fn police_departments(&self) -> Vec<PoliceDepartment<'a>> {
+        self.state.as_player().police_departments
+            .iter()
+            .filter_map(|obj| PoliceDepartment::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the WeatherStations owned by this player.
+    pub fn weather_stations(&self) -> Vec<WeatherStation<'a>> {
+        self.state.as_player().weather_stations
+            .iter()
+            .filter_map(|obj| WeatherStation::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Building`](super::Building) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Building<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Building<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Building<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_building()?;
+        Some(Building { snapshot, state })
+    }
+
+    /// How much health this building currently has. When this reaches 0 the Building has been
+    /// burned down.
+    pub fn health(&self) -> i64 {
+        self.state.as_building().health
+    }
+
+    /// The player that owns this building. If it burns down (health reaches 0) that player gets an
+    /// additional bribe(s).
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_building().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
+    /// wins the game for the other Player.
+    pub fn is_headquarters(&self) -> bool {
+        self.state.as_building().is_headquarters
+    }
+
+    /// When true this building has already been bribed this turn and cannot be bribed again this
+    /// turn.
+    pub fn bribed(&self) -> bool {
+        self.state.as_building().bribed
+    }
+
+    /// The location of the Building along the x-axis.
+    pub fn x(&self) -> i64 {
+        self.state.as_building().x
+    }
+
+    /// The location of the Building along the y-axis.
+    pub fn y(&self) -> i64 {
+        self.state.as_building().y
+    }
+
+    /// How much fire is currently burning the building, and thus how much damage it will take at
+    /// the end of its owner's turn. 0 means no fire.
+    pub fn fire(&self) -> i64 {
+        self.state.as_building().fire
+    }
+
+    /// The Building directly to the north of this building, or None if not present.
+    pub fn building_north(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_north
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// The Building directly to the east of this building, or None if not present.
+    pub fn building_east(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_east
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// The Building directly to the south of this building, or None if not present.
+    pub fn building_south(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_south
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// The Building directly to the west of this building, or None if not present.
+    pub fn building_west(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_west
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Building<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Building").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Building<'a>> for GameObject<'a> {
+    fn from(obj: Building<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Building<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Building<'a>, GameObject<'a>> {
+        match obj.state.try_as_building() {
+            Some(_) => Ok(Building {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Warehouse`](super::Warehouse) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Warehouse<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Warehouse<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Warehouse<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_warehouse()?;
+        Some(Warehouse { snapshot, state })
+    }
+
+    /// The amount of fire added to buildings when bribed to ignite a building. Headquarters add
+    /// more fire than normal Warehouses.
+    pub fn fire_added(&self) -> i64 {
+        self.state.as_warehouse().fire_added
+    }
+
+    /// How exposed the anarchists in this warehouse are to PoliceDepartments. Raises when bribed
+    /// to ignite buildings, and drops each turn if not bribed.
+    pub fn exposure(&self) -> i64 {
+        self.state.as_warehouse().exposure
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much health this building currently has. When this reaches 0 the Building has been
+    /// burned down.
+    pub fn health(&self) -> i64 {
+        self.state.as_building().health
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The player that owns this building. If it burns down (health reaches 0) that player gets an
+    /// additional bribe(s).
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_building().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
+    /// wins the game for the other Player.
+    pub fn is_headquarters(&self) -> bool {
+        self.state.as_building().is_headquarters
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// When true this building has already been bribed this turn and cannot be bribed again this
+    /// turn.
+    pub fn bribed(&self) -> bool {
+        self.state.as_building().bribed
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the x-axis.
+    pub fn x(&self) -> i64 {
+        self.state.as_building().x
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the y-axis.
+    pub fn y(&self) -> i64 {
+        self.state.as_building().y
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much fire is currently burning the building, and thus how much damage it will take at
+    /// the end of its owner's turn. 0 means no fire.
+    pub fn fire(&self) -> i64 {
+        self.state.as_building().fire
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the north of this building, or None if not present.
+    pub fn building_north(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_north
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the east of this building, or None if not present.
+    pub fn building_east(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_east
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the south of this building, or None if not present.
+    pub fn building_south(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_south
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the west of this building, or None if not present.
+    pub fn building_west(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_west
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Warehouse<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Warehouse").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Warehouse<'a>> for Building<'a> {
+    fn from(obj: Warehouse<'a>) -> Building<'a> {
+        Building {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Warehouse<'a>> for GameObject<'a> {
+    fn from(obj: Warehouse<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Warehouse<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Warehouse<'a>, GameObject<'a>> {
+        match obj.state.try_as_warehouse() {
+            Some(_) => Ok(Warehouse {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`FireDepartment`](super::FireDepartment) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct FireDepartment<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> FireDepartment<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<FireDepartment<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_fire_department()?;
+        Some(FireDepartment { snapshot, state })
+    }
+
+    /// The amount of fire removed from a building when bribed to extinguish a building.
+    pub fn fire_extinguished(&self) -> i64 {
+        self.state.as_fire_department().fire_extinguished
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much health this building currently has. When this reaches 0 the Building has been
+    /// burned down.
+    pub fn health(&self) -> i64 {
+        self.state.as_building().health
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The player that owns this building. If it burns down (health reaches 0) that player gets an
+    /// additional bribe(s).
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_building().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
+    /// wins the game for the other Player.
+    pub fn is_headquarters(&self) -> bool {
+        self.state.as_building().is_headquarters
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// When true this building has already been bribed this turn and cannot be bribed again this
+    /// turn.
+    pub fn bribed(&self) -> bool {
+        self.state.as_building().bribed
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the x-axis.
+    pub fn x(&self) -> i64 {
+        self.state.as_building().x
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the y-axis.
+    pub fn y(&self) -> i64 {
+        self.state.as_building().y
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much fire is currently burning the building, and thus how much damage it will take at
+    /// the end of its owner's turn. 0 means no fire.
+    pub fn fire(&self) -> i64 {
+        self.state.as_building().fire
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the north of this building, or None if not present.
+    pub fn building_north(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_north
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the east of this building, or None if not present.
+    pub fn building_east(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_east
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the south of this building, or None if not present.
+    pub fn building_south(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_south
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the west of this building, or None if not present.
+    pub fn building_west(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_west
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for FireDepartment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FireDepartment").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<FireDepartment<'a>> for Building<'a> {
+    fn from(obj: FireDepartment<'a>) -> Building<'a> {
+        Building {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<FireDepartment<'a>> for GameObject<'a> {
+    fn from(obj: FireDepartment<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for FireDepartment<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<FireDepartment<'a>, GameObject<'a>> {
+        match obj.state.try_as_fire_department() {
+            Some(_) => Ok(FireDepartment {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`WeatherStation`](super::WeatherStation) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct WeatherStation<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> WeatherStation<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<WeatherStation<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_weather_station()?;
+        Some(WeatherStation { snapshot, state })
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much health this building currently has. When this reaches 0 the Building has been
+    /// burned down.
+    pub fn health(&self) -> i64 {
+        self.state.as_building().health
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The player that owns this building. If it burns down (health reaches 0) that player gets an
+    /// additional bribe(s).
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_building().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
+    /// wins the game for the other Player.
+    pub fn is_headquarters(&self) -> bool {
+        self.state.as_building().is_headquarters
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// When true this building has already been bribed this turn and cannot be bribed again this
+    /// turn.
+    pub fn bribed(&self) -> bool {
+        self.state.as_building().bribed
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the x-axis.
+    pub fn x(&self) -> i64 {
+        self.state.as_building().x
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the y-axis.
+    pub fn y(&self) -> i64 {
+        self.state.as_building().y
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much fire is currently burning the building, and thus how much damage it will take at
+    /// the end of its owner's turn. 0 means no fire.
+    pub fn fire(&self) -> i64 {
+        self.state.as_building().fire
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the north of this building, or None if not present.
+    pub fn building_north(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_north
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the east of this building, or None if not present.
+    pub fn building_east(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_east
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the south of this building, or None if not present.
+    pub fn building_south(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_south
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the west of this building, or None if not present.
+    pub fn building_west(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_west
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for WeatherStation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WeatherStation").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<WeatherStation<'a>> for Building<'a> {
+    fn from(obj: WeatherStation<'a>) -> Building<'a> {
+        Building {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<WeatherStation<'a>> for GameObject<'a> {
+    fn from(obj: WeatherStation<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for WeatherStation<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<WeatherStation<'a>, GameObject<'a>> {
+        match obj.state.try_as_weather_station() {
+            Some(_) => Ok(WeatherStation {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`PoliceDepartment`](super::PoliceDepartment) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct PoliceDepartment<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> PoliceDepartment<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<PoliceDepartment<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_police_department()?;
+        Some(PoliceDepartment { snapshot, state })
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much health this building currently has. When this reaches 0 the Building has been
+    /// burned down.
+    pub fn health(&self) -> i64 {
+        self.state.as_building().health
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The player that owns this building. If it burns down (health reaches 0) that player gets an
+    /// additional bribe(s).
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_building().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// True if this is the Headquarters of the owning player, false otherwise. Burning this down
+    /// wins the game for the other Player.
+    pub fn is_headquarters(&self) -> bool {
+        self.state.as_building().is_headquarters
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// When true this building has already been bribed this turn and cannot be bribed again this
+    /// turn.
+    pub fn bribed(&self) -> bool {
+        self.state.as_building().bribed
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the x-axis.
+    pub fn x(&self) -> i64 {
+        self.state.as_building().x
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The location of the Building along the y-axis.
+    pub fn y(&self) -> i64 {
+        self.state.as_building().y
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// How much fire is currently burning the building, and thus how much damage it will take at
+    /// the end of its owner's turn. 0 means no fire.
+    pub fn fire(&self) -> i64 {
+        self.state.as_building().fire
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the north of this building, or None if not present.
+    pub fn building_north(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_north
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the east of this building, or None if not present.
+    pub fn building_east(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_east
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the south of this building, or None if not present.
+    pub fn building_south(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_south
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Building`]_
+    ///
+    /// The Building directly to the west of this building, or None if not present.
+    pub fn building_west(&self) -> Option<Building<'a>> {
+        self.state.as_building().building_west
+            .as_ref()
+            .and_then(|obj| Building::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for PoliceDepartment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PoliceDepartment").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<PoliceDepartment<'a>> for Building<'a> {
+    fn from(obj: PoliceDepartment<'a>) -> Building<'a> {
+        Building {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<PoliceDepartment<'a>> for GameObject<'a> {
+    fn from(obj: PoliceDepartment<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for PoliceDepartment<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<PoliceDepartment<'a>, GameObject<'a>> {
+        match obj.state.try_as_police_department() {
+            Some(_) => Ok(PoliceDepartment {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Forecast`](super::Forecast) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Forecast<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Forecast<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Forecast<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_forecast()?;
+        Some(Forecast { snapshot, state })
+    }
+
+    /// The direction the wind will blow fires in. Can be 'north', 'east', 'south', or 'west'.
+    pub fn direction(&self) -> &'a Direction {
+        &self.state.as_forecast().direction
+    }
+
+    /// How much of a Building's fire that can be blown in the direction of this Forecast. Fire is
+    /// duplicated (copied), not moved (transfered).
+    pub fn intensity(&self) -> i64 {
+        self.state.as_forecast().intensity
+    }
+
+    /// The Player that can use WeatherStations to control this Forecast when its the nextForecast.
+    pub fn controlling_player(&self) -> Option<Player<'a>> {
+        self.state.as_forecast().controlling_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Forecast<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Forecast").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Forecast<'a>> for GameObject<'a> {
+    fn from(obj: Forecast<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Forecast<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Forecast<'a>, GameObject<'a>> {
+        match obj.state.try_as_forecast() {
+            Some(_) => Ok(Forecast {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -0,0 +1,254 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Structure::type_`](super::Structure::type_)
+/// - the `type_` argument of [`Unit::construct`](super::Unit::construct)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StructureType {
+    /// `"shelter"`
+    Shelter,
+    /// `"monument"`
+    Monument,
+    /// `"wall"`
+    Wall,
+    /// `"road"`
+    Road,
+    /// `"neutral"`
+    Neutral,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl StructureType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            StructureType::Shelter => "shelter",
+            StructureType::Monument => "monument",
+            StructureType::Wall => "wall",
+            StructureType::Road => "road",
+            StructureType::Neutral => "neutral",
+            StructureType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for StructureType {
+    fn from(s: &str) -> StructureType {
+        match s {
+            "shelter" => StructureType::Shelter,
+            "monument" => StructureType::Monument,
+            "wall" => StructureType::Wall,
+            "road" => StructureType::Road,
+            "neutral" => StructureType::Neutral,
+            other => StructureType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for StructureType {
+    fn default() -> StructureType {
+        StructureType::from("")
+    }
+}
+
+impl fmt::Display for StructureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for StructureType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StructureType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| StructureType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for StructureType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `resource` argument of [`Unit::drop`](super::Unit::drop)
+/// - the `resource` argument of [`Unit::pickup`](super::Unit::pickup)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// `"materials"`
+    Materials,
+    /// `"food"`
+    Food,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Resource {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Resource::Materials => "materials",
+            Resource::Food => "food",
+            Resource::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Resource {
+    fn from(s: &str) -> Resource {
+        match s {
+            "materials" => Resource::Materials,
+            "food" => Resource::Food,
+            other => Resource::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Resource {
+    fn default() -> Resource {
+        Resource::from("")
+    }
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Resource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Resource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Resource::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Resource {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `job` argument of [`Unit::change_job`](super::Unit::change_job)
+/// - [`Job::title`](super::Job::title)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JobTitle {
+    /// `"fresh human"`
+    FreshHuman,
+    /// `"cat overlord"`
+    CatOverlord,
+    /// `"soldier"`
+    Soldier,
+    /// `"gatherer"`
+    Gatherer,
+    /// `"builder"`
+    Builder,
+    /// `"missionary"`
+    Missionary,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl JobTitle {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JobTitle::FreshHuman => "fresh human",
+            JobTitle::CatOverlord => "cat overlord",
+            JobTitle::Soldier => "soldier",
+            JobTitle::Gatherer => "gatherer",
+            JobTitle::Builder => "builder",
+            JobTitle::Missionary => "missionary",
+            JobTitle::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for JobTitle {
+    fn from(s: &str) -> JobTitle {
+        match s {
+            "fresh human" => JobTitle::FreshHuman,
+            "cat overlord" => JobTitle::CatOverlord,
+            "soldier" => JobTitle::Soldier,
+            "gatherer" => JobTitle::Gatherer,
+            "builder" => JobTitle::Builder,
+            "missionary" => JobTitle::Missionary,
+            other => JobTitle::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for JobTitle {
+    fn default() -> JobTitle {
+        JobTitle::from("")
+    }
+}
+
+impl fmt::Display for JobTitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for JobTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobTitle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| JobTitle::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for JobTitle {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A player in this game. Every AI controls one player.
 #[derive(Debug, Clone)]
@@ -19,18 +31,20 @@ impl Player {
         Player { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The name of the player.
     pub fn name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .name.clone()
     }
@@ -38,87 +52,89 @@ impl Player {
     /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
     /// potential data mining purposes.
     pub fn client_type(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .client_type.clone()
     }
 
     /// If the player won the game or not.
     pub fn won(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
-            .won.clone()
+            .won
     }
 
     /// If the player lost the game or not.
     pub fn lost(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
-            .lost.clone()
+            .lost
     }
 
     /// The reason why the player won the game.
     pub fn reason_won(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .reason_won.clone()
     }
 
     /// The reason why the player lost the game.
     pub fn reason_lost(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .reason_lost.clone()
     }
 
     /// The amount of time (in ns) remaining for this AI to send commands.
     pub fn time_remaining(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
-            .time_remaining.clone()
+            .time_remaining
     }
 
     /// This player's opponent in the game.
     pub fn opponent(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .opponent.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// Every Unit owned by this Player.
     pub fn units(&self) -> List<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .units.clone()
     }
 
     /// The overlord cat Unit owned by this Player.
     pub fn cat(&self) -> Unit {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .cat.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The total upkeep of every Unit owned by this Player. If there isn't enough food for every
     /// Unit, all Units become starved and do not consume food.
     pub fn upkeep(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
-            .upkeep.clone()
+            .upkeep
     }
 
     /// Every Structure owned by this Player.
     pub fn structures(&self) -> List<Structure> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
             .structures.clone()
     }
 
     /// The amount of food owned by this player.
     pub fn food(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_player()
-            .food.clone()
+            .food
     }
 
     /// _Inherited from [`GameObject`]_
@@ -126,7 +142,7 @@ impl Player {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -137,7 +153,7 @@ impl Player {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -146,7 +162,7 @@ impl Player {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -165,8 +181,11 @@ impl Player {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -176,21 +195,87 @@ impl Player {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Player {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Player {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Player {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Player {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Player {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Player {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_player().is_some() {
             Some(Player {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -199,3 +284,81 @@ impl inner::ObjectInner for Player {
     }
 }
 impl Object for Player {}
+
+impl Serialize for Player {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Player {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Player {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Player {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
+
+impl AnyPlayer for Player {
+    fn name(&self) -> Str {
+        self.name()
+    }
+
+    fn client_type(&self) -> Str {
+        self.client_type()
+    }
+
+    fn opponent(&self) -> Player {
+        self.opponent()
+    }
+
+    fn won(&self) -> bool {
+        self.won()
+    }
+
+    fn lost(&self) -> bool {
+        self.lost()
+    }
+
+    fn reason_won(&self) -> Str {
+        self.reason_won()
+    }
+
+    fn reason_lost(&self) -> Str {
+        self.reason_lost()
+    }
+
+    fn time_remaining(&self) -> f64 {
+        self.time_remaining()
+    }
+}
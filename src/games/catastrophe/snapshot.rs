@@ -0,0 +1,940 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{StructureType, Resource, JobTitle};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Structure`](super::Structure) at the time of this snapshot.
+    pub fn structure(&self, obj: &super::Structure) -> Option<Structure<'_>> {
+        Structure::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Unit`](super::Unit) at the time of this snapshot.
+    pub fn unit(&self, obj: &super::Unit) -> Option<Unit<'_>> {
+        Unit::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Job`](super::Job) at the time of this snapshot.
+    pub fn job(&self, obj: &super::Job) -> Option<Job<'_>> {
+        Job::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Unit in the game.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.snapshot.game.units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Structure in the game.
+    pub fn structures(&self) -> Vec<Structure<'a>> {
+        self.snapshot.game.structures
+            .iter()
+            .filter_map(|obj| Structure::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the Jobs that Units can have in the game.
+    pub fn jobs(&self) -> Vec<Job<'a>> {
+        self.snapshot.game.jobs
+            .iter()
+            .filter_map(|obj| Job::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The multiplier for the amount of energy regenerated when resting in a shelter with the cat
+    /// overlord.
+    pub fn cat_energy_mult(&self) -> f64 {
+        self.snapshot.game.cat_energy_mult
+    }
+
+    /// The multiplier for the amount of energy regenerated when resting while starving.
+    pub fn starving_energy_mult(&self) -> f64 {
+        self.snapshot.game.starving_energy_mult
+    }
+
+    /// The multiplier for the cost of actions when performing them in range of a monument. Does
+    /// not effect pickup cost.
+    pub fn monument_cost_mult(&self) -> f64 {
+        self.snapshot.game.monument_cost_mult
+    }
+
+    /// The amount of turns it takes for a Tile that was just harvested to grow food again.
+    pub fn harvest_cooldown(&self) -> i64 {
+        self.snapshot.game.harvest_cooldown
+    }
+
+    /// The number of turns between fresh humans being spawned on the road.
+    pub fn turns_to_create_human(&self) -> i64 {
+        self.snapshot.game.turns_to_create_human
+    }
+
+    /// The number of turns before the harvest rate is lowered (length of each season basically).
+    pub fn turns_to_lower_harvest(&self) -> i64 {
+        self.snapshot.game.turns_to_lower_harvest
+    }
+
+    /// The amount that the harvest rate is lowered each season.
+    pub fn lower_harvest_amount(&self) -> i64 {
+        self.snapshot.game.lower_harvest_amount
+    }
+
+    /// After a food tile is harvested, the number of turns before it can be harvested again.
+    pub fn turns_between_harvests(&self) -> i64 {
+        self.snapshot.game.turns_between_harvests
+    }
+
+    /// The number of materials in a neutral Structure.
+    pub fn neutral_materials(&self) -> i64 {
+        self.snapshot.game.neutral_materials
+    }
+
+    /// The number of materials in a wall.
+    pub fn wall_materials(&self) -> i64 {
+        self.snapshot.game.wall_materials
+    }
+
+    /// The number of materials in a shelter.
+    pub fn shelter_materials(&self) -> i64 {
+        self.snapshot.game.shelter_materials
+    }
+
+    /// The number of materials in a monument.
+    pub fn monument_materials(&self) -> i64 {
+        self.snapshot.game.monument_materials
+    }
+
+    /// The amount of food Players start with.
+    pub fn starting_food(&self) -> i64 {
+        self.snapshot.game.starting_food
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// Every Unit owned by this Player.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.state.as_player().units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The overlord cat Unit owned by this Player.
+    pub fn cat(&self) -> Option<Unit<'a>> {
+        self.state.as_player().cat
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The total upkeep of every Unit owned by this Player. If there isn't enough food for every
+    /// Unit, all Units become starved and do not consume food.
+    pub fn upkeep(&self) -> i64 {
+        self.state.as_player().upkeep
+    }
+
+    /// Every Structure owned by this Player.
+    pub fn structures(&self) -> Vec<Structure<'a>> {
+        self.state.as_player().structures
+            .iter()
+            .filter_map(|obj| Structure::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of food owned by this player.
+    pub fn food(&self) -> i64 {
+        self.state.as_player().food
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Unit on this Tile if present, otherwise None.
+    pub fn unit(&self) -> Option<Unit<'a>> {
+        self.state.as_tile().unit
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The Structure on this Tile if present, otherwise None.
+    pub fn structure(&self) -> Option<Structure<'a>> {
+        self.state.as_tile().structure
+            .as_ref()
+            .and_then(|obj| Structure::resolve(self.snapshot, obj))
+    }
+
+    /// The amount of food that can be harvested from this Tile per turn.
+    pub fn harvest_rate(&self) -> i64 {
+        self.state.as_tile().harvest_rate
+    }
+
+    /// The amount of turns before this resource can be harvested.
+    pub fn turns_to_harvest(&self) -> i64 {
+        self.state.as_tile().turns_to_harvest
+    }
+
+    /// The number of materials dropped on this Tile.
+    pub fn materials(&self) -> i64 {
+        self.state.as_tile().materials
+    }
+
+    /// The number of food dropped on this Tile.
+    pub fn food(&self) -> i64 {
+        self.state.as_tile().food
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Structure`](super::Structure) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Structure<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Structure<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Structure<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_structure()?;
+        Some(Structure { snapshot, state })
+    }
+
+    /// The type of Structure this is ('shelter', 'monument', 'wall', 'road', 'neutral').
+    pub fn type_(&self) -> &'a StructureType {
+        &self.state.as_structure().type_
+    }
+
+    /// The Tile this Structure is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_structure().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The owner of this Structure if any, otherwise None.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_structure().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The number of materials in this Structure. Once this number reaches 0, this Structure is
+    /// destroyed.
+    pub fn materials(&self) -> i64 {
+        self.state.as_structure().materials
+    }
+
+    /// The range of this Structure's effect. For example, a radius of 1 means this Structure
+    /// affects a 3x3 square centered on this Structure.
+    pub fn effect_radius(&self) -> i64 {
+        self.state.as_structure().effect_radius
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Structure<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Structure").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Structure<'a>> for GameObject<'a> {
+    fn from(obj: Structure<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Structure<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Structure<'a>, GameObject<'a>> {
+        match obj.state.try_as_structure() {
+            Some(_) => Ok(Structure {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Unit`](super::Unit) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Unit<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Unit<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Unit<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit()?;
+        Some(Unit { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Unit, or None if the Unit is neutral.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_unit().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this Unit is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_unit().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Job this Unit was recruited to do.
+    pub fn job(&self) -> Option<Job<'a>> {
+        self.state.as_unit().job
+            .as_ref()
+            .and_then(|obj| Job::resolve(self.snapshot, obj))
+    }
+
+    /// How many moves this Unit has left this turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_unit().moves
+    }
+
+    /// The amount of energy this Unit has (from 0.0 to 100.0).
+    pub fn energy(&self) -> f64 {
+        self.state.as_unit().energy
+    }
+
+    /// The Units in the same squad as this Unit. Units in the same squad attack and defend
+    /// together.
+    pub fn squad(&self) -> Vec<Unit<'a>> {
+        self.state.as_unit().squad
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Whether this Unit has performed its action this turn.
+    pub fn acted(&self) -> bool {
+        self.state.as_unit().acted
+    }
+
+    /// The amount of food this Unit is holding.
+    pub fn food(&self) -> i64 {
+        self.state.as_unit().food
+    }
+
+    /// The amount of materials this Unit is holding.
+    pub fn materials(&self) -> i64 {
+        self.state.as_unit().materials
+    }
+
+    /// Whether this Unit is starving. Starving Units regenerate energy at half the rate they
+    /// normally would while resting.
+    pub fn starving(&self) -> bool {
+        self.state.as_unit().starving
+    }
+
+    /// The number of turns before this Unit dies. This only applies to neutral fresh humans
+    /// created from combat. Otherwise, 0.
+    pub fn turns_to_die(&self) -> i64 {
+        self.state.as_unit().turns_to_die
+    }
+
+    /// The tile this Unit is moving to. This only applies to neutral fresh humans spawned on the
+    /// road. Otherwise, the tile this Unit is on.
+    pub fn movement_target(&self) -> Option<Tile<'a>> {
+        self.state.as_unit().movement_target
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Unit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unit").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Unit<'a>> for GameObject<'a> {
+    fn from(obj: Unit<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Unit<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Unit<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit() {
+            Some(_) => Ok(Unit {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Job`](super::Job) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Job<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Job<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_job()?;
+        Some(Job { snapshot, state })
+    }
+
+    /// The Job title.
+    pub fn title(&self) -> &'a JobTitle {
+        &self.state.as_job().title
+    }
+
+    /// The number of moves this Job can make per turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_job().moves
+    }
+
+    /// The amount of energy this Job normally uses to perform its actions.
+    pub fn action_cost(&self) -> f64 {
+        self.state.as_job().action_cost
+    }
+
+    /// The amount of energy normally regenerated when resting at a shelter.
+    pub fn regen_rate(&self) -> f64 {
+        self.state.as_job().regen_rate
+    }
+
+    /// How many combined resources a Unit with this Job can hold at once.
+    pub fn carry_limit(&self) -> i64 {
+        self.state.as_job().carry_limit
+    }
+
+    /// The amount of food per turn this Unit consumes. If there isn't enough food for every Unit,
+    /// all Units become starved and do not consume food.
+    pub fn upkeep(&self) -> i64 {
+        self.state.as_job().upkeep
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Job<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Job").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Job<'a>> for GameObject<'a> {
+    fn from(obj: Job<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Job<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Job<'a>, GameObject<'a>> {
+        match obj.state.try_as_job() {
+            Some(_) => Ok(Job {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A unit in the game.
 #[derive(Debug, Clone)]
@@ -19,99 +31,102 @@ impl Unit {
         Unit { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The Player that owns and can control this Unit, or None if the Unit is neutral.
     pub fn owner(&self) -> Option<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .owner.clone()
     }
 
     /// The Tile this Unit is on.
     pub fn tile(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .tile.clone()
     }
 
     /// The Job this Unit was recruited to do.
     pub fn job(&self) -> Job {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .job.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// How many moves this Unit has left this turn.
     pub fn moves(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .moves.clone()
+            .moves
     }
 
     /// The amount of energy this Unit has (from 0.0 to 100.0).
     pub fn energy(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .energy.clone()
+            .energy
     }
 
     /// The Units in the same squad as this Unit. Units in the same squad attack and defend
     /// together.
     pub fn squad(&self) -> List<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .squad.clone()
     }
 
     /// Whether this Unit has performed its action this turn.
     pub fn acted(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .acted.clone()
+            .acted
     }
 
     /// The amount of food this Unit is holding.
     pub fn food(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .food.clone()
+            .food
     }
 
     /// The amount of materials this Unit is holding.
     pub fn materials(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .materials.clone()
+            .materials
     }
 
     /// Whether this Unit is starving. Starving Units regenerate energy at half the rate they
     /// normally would while resting.
     pub fn starving(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .starving.clone()
+            .starving
     }
 
     /// The number of turns before this Unit dies. This only applies to neutral fresh humans
     /// created from combat. Otherwise, 0.
     pub fn turns_to_die(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .turns_to_die.clone()
+            .turns_to_die
     }
 
     /// The tile this Unit is moving to. This only applies to neutral fresh humans spawned on the
     /// road. Otherwise, the tile this Unit is on.
     pub fn movement_target(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .movement_target.clone()
     }
@@ -121,7 +136,7 @@ impl Unit {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -132,7 +147,7 @@ impl Unit {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -141,7 +156,7 @@ impl Unit {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -161,8 +176,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -187,8 +205,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -215,8 +236,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -241,8 +265,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -257,7 +284,7 @@ impl Unit {
     /// # Arguments
     ///
     /// - _tile_ - The Tile to construct the Structure on. It must have enough materials on it for
-    /// a Structure to be constructed.
+    ///   a Structure to be constructed.
     ///
     /// - _type__ - The type of Structure to construct on that Tile.
     ///
@@ -267,13 +294,16 @@ impl Unit {
     pub fn construct(
         &self,
         tile: &Tile,
-        type_: &str,
+        type_: StructureType,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            type_: &'a str,
+            type_: StructureType,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -300,8 +330,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -321,7 +354,7 @@ impl Unit {
     /// - _resource_ - The type of resource to drop ('materials' or 'food').
     ///
     /// - _amount_ - The amount of the resource to drop. Amounts <= 0 will drop as much as
-    /// possible.
+    ///   possible.
     ///
     /// # Returns
     ///
@@ -329,15 +362,18 @@ impl Unit {
     pub fn drop(
         &self,
         tile: &Tile,
-        resource: &str,
+        resource: Resource,
         amount: i64,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            resource: &'a str,
+            resource: Resource,
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -359,7 +395,7 @@ impl Unit {
     /// - _resource_ - The type of resource to pickup ('materials' or 'food').
     ///
     /// - _amount_ - The amount of the resource to pickup. Amounts <= 0 will pickup as much as
-    /// possible.
+    ///   possible.
     ///
     /// # Returns
     ///
@@ -367,15 +403,18 @@ impl Unit {
     pub fn pickup(
         &self,
         tile: &Tile,
-        resource: &str,
+        resource: Resource,
         amount: i64,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            resource: &'a str,
+            resource: Resource,
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -398,12 +437,15 @@ impl Unit {
     /// True if successfully changed Jobs, false otherwise.
     pub fn change_job(
         &self,
-        job: &str,
+        job: JobTitle,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
-            job: &'a str,
+            job: JobTitle,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -424,7 +466,10 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -447,8 +492,11 @@ impl Unit {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -458,21 +506,87 @@ impl Unit {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Unit {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Unit {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Unit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Unit {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_unit().is_some() {
             Some(Unit {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -481,3 +595,47 @@ impl inner::ObjectInner for Unit {
     }
 }
 impl Object for Unit {}
+
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Unit {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Unit {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Unit {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
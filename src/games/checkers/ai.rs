@@ -0,0 +1,51 @@
+#![allow(unused_imports)]
+
+use super::*;
+use crate::types::*;
+use crate::error::Error;
+
+/// An AI that plays Checkers.
+///
+/// The game's orders must be implemented; every other hook has a default that does nothing
+/// special. Errors returned from any hook end the game for this client.
+pub trait AI {
+    /// The name of the player this AI plays as, unless another is given on the command line.
+    fn get_name(&self) -> String {
+        "Checkers Rust Player".into()
+    }
+
+    /// Called once when the game starts, before the first order.
+    fn start(&mut self, _game: &Game, _player: &Player) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called each time the server updates the game state between orders.
+    ///
+    /// `changes` lists what changed since the last call, including anything that changed while
+    /// the AI was carrying out an order.
+    fn game_updated(&mut self, _game: &Game, _player: &Player, _changes: &Changes) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called when the server rejects something this AI tried to do.
+    fn invalid(&mut self, message: &str) {
+        eprintln!("Invalid: {}", message);
+    }
+
+    /// Called once when the game is over.
+    ///
+    /// # Arguments
+    ///
+    /// - _won_ - True if this AI's player won, false otherwise.
+    ///
+    /// - _reason_ - The reason this AI's player won or lost.
+    fn end(&mut self, _won: bool, _reason: &str) {}
+
+    /// This is called every time it is this AI.player's turn.
+    ///
+    /// # Returns
+    ///
+    /// Represents if you want to end your turn. True means end your turn, False means to keep your
+    /// turn going and re-call this function.
+    fn run_turn(&mut self, game: &Game, player: &Player) -> Result<bool, Error>;
+}
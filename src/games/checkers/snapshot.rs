@@ -0,0 +1,433 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Checker`](super::Checker) at the time of this snapshot.
+    pub fn checker(&self, obj: &super::Checker) -> Option<Checker<'_>> {
+        Checker::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The width of the board for X component of a checker.
+    pub fn board_width(&self) -> i64 {
+        self.snapshot.game.board_width
+    }
+
+    /// The height of the board for the Y component of a checker.
+    pub fn board_height(&self) -> i64 {
+        self.snapshot.game.board_height
+    }
+
+    /// All the checkers currently in the game.
+    pub fn checkers(&self) -> Vec<Checker<'a>> {
+        self.snapshot.game.checkers
+            .iter()
+            .filter_map(|obj| Checker::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The checker that last moved and must be moved because only one checker can move during each
+    /// players turn.
+    pub fn checker_moved(&self) -> Option<Checker<'a>> {
+        self.snapshot.game.checker_moved
+            .as_ref()
+            .and_then(|obj| Checker::resolve(self.snapshot, obj))
+    }
+
+    /// If the last checker that moved jumped, meaning it can move again.
+    pub fn checker_moved_jumped(&self) -> bool {
+        self.snapshot.game.checker_moved_jumped
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// All the checkers currently in the game owned by this player.
+    pub fn checkers(&self) -> Vec<Checker<'a>> {
+        self.state.as_player().checkers
+            .iter()
+            .filter_map(|obj| Checker::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The direction your checkers must go along the y-axis until kinged.
+    pub fn y_direction(&self) -> i64 {
+        self.state.as_player().y_direction
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Checker`](super::Checker) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Checker<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Checker<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Checker<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_checker()?;
+        Some(Checker { snapshot, state })
+    }
+
+    /// The player that controls this Checker.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_checker().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The x coordinate of the checker.
+    pub fn x(&self) -> i64 {
+        self.state.as_checker().x
+    }
+
+    /// The y coordinate of the checker.
+    pub fn y(&self) -> i64 {
+        self.state.as_checker().y
+    }
+
+    /// If the checker has been kinged and can move backwards.
+    pub fn kinged(&self) -> bool {
+        self.state.as_checker().kinged
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Checker<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Checker").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Checker<'a>> for GameObject<'a> {
+    fn from(obj: Checker<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Checker<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Checker<'a>, GameObject<'a>> {
+        match obj.state.try_as_checker() {
+            Some(_) => Ok(Checker {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -0,0 +1,81 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Player::color`](super::Player::color)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// `"white"`
+    White,
+    /// `"black"`
+    Black,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Color {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Color::White => "white",
+            Color::Black => "black",
+            Color::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Color {
+    fn from(s: &str) -> Color {
+        match s {
+            "white" => Color::White,
+            "black" => Color::Black,
+            other => Color::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Color {
+    fn default() -> Color {
+        Color::from("")
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Color::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Color {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
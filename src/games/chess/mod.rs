@@ -2,6 +2,9 @@
 
 mod inner;
 
+mod ai;
+pub mod snapshot;
+mod enums;
 mod game_object;
 mod player;
 mod game;
@@ -10,4 +13,80 @@ pub use game_object::GameObject;
 pub use player::Player;
 pub use game::Game;
 
+pub use enums::Color;
+
+pub use ai::AI;
+
+pub use snapshot::Snapshot;
+
 pub use inner::Object;
+
+pub use crate::client::delta::Changes;
+
+use crate::client::gamelog::Gamelog;
+use crate::client::settings::{GameSetting, SettingKind};
+use crate::client::{Client, GameOutcome};
+use crate::error::Error;
+use crate::games::{print_changes, Registry, Spectator};
+
+/// The name of this game on the server.
+pub const GAME_NAME: &str = "Chess";
+
+/// The settings the server accepts for this game, besides the
+/// [`BASE_SETTINGS`](crate::client::settings::BASE_SETTINGS) every game accepts.
+///
+/// Each sets the initial value of the [`Game`] attribute of the same name.
+pub const SETTINGS: &[GameSetting] = &[
+    GameSetting {
+        name: "fen",
+        kind: SettingKind::String,
+        description: "Forsyth-Edwards Notation (fen), a notation that describes the game board state.",
+    },
+];
+
+/// Registers an AI for this game. `new_ai` is called to create a fresh AI for each game played.
+pub fn register<A, F>(registry: &mut Registry, new_ai: F)
+where
+    A: AI + 'static,
+    F: Fn() -> A + 'static,
+{
+    registry.insert(GAME_NAME, move || Box::new(inner::Pending::new(new_ai())));
+}
+
+/// Plays one game of this game on the server the client is configured for, with the given AI.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the server
+/// resolves the client's game to a different game.
+pub fn play<A: AI + 'static>(client: &Client, ai: A) -> Result<GameOutcome, Error> {
+    client.play_session(Box::new(inner::Pending::new(ai)))
+}
+
+/// Watches one game of this game on the server the client is configured for, without playing.
+///
+/// `on_update` is called with the whole initial state as created, and then each time the state
+/// changes, until the game is over. Fails like [`play`] does if the server resolves the
+/// client's game to a different game.
+pub fn spectate<F>(client: &Client, on_update: F) -> Result<(), Error>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error> + 'static,
+{
+    client.spectate_session(Box::new(inner::Watching::new(on_update)))
+}
+
+/// A spectator that prints what changes in the game with [`print_changes`], as used by
+/// `joueur --spectate`.
+pub fn printer() -> Box<dyn Spectator> {
+    Box::new(inner::Watching::new(|game: &Game, changes: &Changes| {
+        print_changes(game, changes);
+        Ok(())
+    }))
+}
+
+/// Replays a gamelog of this game offline, calling the AI's hooks as the state changes.
+///
+/// The AI sees the game from the view of the player with the given index. No orders are given,
+/// and game object functions fail since there is no server to run them. The AI is returned
+/// afterwards, so that anything it recorded along the way can be inspected.
+pub fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, ai: A) -> Result<A, Error> {
+    inner::replay(gamelog, player_index, ai)
+}
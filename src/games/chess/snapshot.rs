@@ -0,0 +1,290 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::Color;
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The list of \[known\] moves that have occurred in the game, in Standard Algebraic Notation
+    /// (SAN) format. The first element is the first move, with the last being the most recent.
+    pub fn history(&self) -> &'a List<Str> {
+        &self.snapshot.game.history
+    }
+
+    /// Forsyth-Edwards Notation (fen), a notation that describes the game board state.
+    pub fn fen(&self) -> &'a Str {
+        &self.snapshot.game.fen
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The color (side) of this player. Either 'white' or 'black', with the 'white' player having
+    /// the first move.
+    pub fn color(&self) -> &'a Color {
+        &self.state.as_player().color
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
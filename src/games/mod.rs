@@ -1,14 +1,86 @@
 //! Per-game APIs and AI implementations.
+//!
+//! Everything here except `printer.rs`, `registry.rs` and `traits.rs` is generated from the game
+//! structures in `structures/` by the `codegen` binary.
 
-// Due to limitations in Creer, this list needs to be maintained manually.
+mod printer;
+mod registry;
+mod traits;
+
+pub use printer::print_changes;
+pub use registry::{Registry, Session, Spectator};
+pub use traits::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
+
+use crate::error::Error;
+
+#[cfg(feature = "anarchy")]
 pub mod anarchy;
+#[cfg(feature = "catastrophe")]
 pub mod catastrophe;
+#[cfg(feature = "checkers")]
 pub mod checkers;
+#[cfg(feature = "chess")]
 pub mod chess;
+#[cfg(feature = "necrowar")]
 pub mod necrowar;
+#[cfg(feature = "newtonian")]
 pub mod newtonian;
+#[cfg(feature = "pirates")]
 pub mod pirates;
+#[cfg(feature = "saloon")]
 pub mod saloon;
+#[cfg(feature = "spiders")]
 pub mod spiders;
+#[cfg(feature = "stardash")]
 pub mod stardash;
+#[cfg(feature = "stumped")]
 pub mod stumped;
+
+/// Every game with a module, by its name on the server, along with the cargo feature that
+/// compiles it in and whether that feature is enabled.
+const FEATURES: &[(&str, &str, bool)] = &[
+    ("Anarchy", "anarchy", cfg!(feature = "anarchy")),
+    ("Catastrophe", "catastrophe", cfg!(feature = "catastrophe")),
+    ("Checkers", "checkers", cfg!(feature = "checkers")),
+    ("Chess", "chess", cfg!(feature = "chess")),
+    ("Necrowar", "necrowar", cfg!(feature = "necrowar")),
+    ("Newtonian", "newtonian", cfg!(feature = "newtonian")),
+    ("Pirates", "pirates", cfg!(feature = "pirates")),
+    ("Saloon", "saloon", cfg!(feature = "saloon")),
+    ("Spiders", "spiders", cfg!(feature = "spiders")),
+    ("Stardash", "stardash", cfg!(feature = "stardash")),
+    ("Stumped", "stumped", cfg!(feature = "stumped")),
+];
+
+/// A spectator that prints what changes in the game with the given name, as resolved by the
+/// server.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the game is
+/// unknown or its module was left out of the build.
+pub fn printer(game_name: &str) -> Result<Box<dyn Spectator>, Error> {
+    match game_name {
+        #[cfg(feature = "anarchy")]
+        anarchy::GAME_NAME => Ok(anarchy::printer()),
+        #[cfg(feature = "catastrophe")]
+        catastrophe::GAME_NAME => Ok(catastrophe::printer()),
+        #[cfg(feature = "checkers")]
+        checkers::GAME_NAME => Ok(checkers::printer()),
+        #[cfg(feature = "chess")]
+        chess::GAME_NAME => Ok(chess::printer()),
+        #[cfg(feature = "necrowar")]
+        necrowar::GAME_NAME => Ok(necrowar::printer()),
+        #[cfg(feature = "newtonian")]
+        newtonian::GAME_NAME => Ok(newtonian::printer()),
+        #[cfg(feature = "pirates")]
+        pirates::GAME_NAME => Ok(pirates::printer()),
+        #[cfg(feature = "saloon")]
+        saloon::GAME_NAME => Ok(saloon::printer()),
+        #[cfg(feature = "spiders")]
+        spiders::GAME_NAME => Ok(spiders::printer()),
+        #[cfg(feature = "stardash")]
+        stardash::GAME_NAME => Ok(stardash::printer()),
+        #[cfg(feature = "stumped")]
+        stumped::GAME_NAME => Ok(stumped::printer()),
+        _ => Err(registry::not_found(game_name, "there is no module for")),
+    }
+}
@@ -0,0 +1,186 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `title` argument of [`Tile::spawn_unit`](super::Tile::spawn_unit)
+/// - [`UnitJob::title`](super::UnitJob::title)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UnitTitle {
+    /// `"worker"`
+    Worker,
+    /// `"zombie"`
+    Zombie,
+    /// `"ghoul"`
+    Ghoul,
+    /// `"hound"`
+    Hound,
+    /// `"abomination"`
+    Abomination,
+    /// `"wraith"`
+    Wraith,
+    /// `"horseman"`
+    Horseman,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl UnitTitle {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UnitTitle::Worker => "worker",
+            UnitTitle::Zombie => "zombie",
+            UnitTitle::Ghoul => "ghoul",
+            UnitTitle::Hound => "hound",
+            UnitTitle::Abomination => "abomination",
+            UnitTitle::Wraith => "wraith",
+            UnitTitle::Horseman => "horseman",
+            UnitTitle::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for UnitTitle {
+    fn from(s: &str) -> UnitTitle {
+        match s {
+            "worker" => UnitTitle::Worker,
+            "zombie" => UnitTitle::Zombie,
+            "ghoul" => UnitTitle::Ghoul,
+            "hound" => UnitTitle::Hound,
+            "abomination" => UnitTitle::Abomination,
+            "wraith" => UnitTitle::Wraith,
+            "horseman" => UnitTitle::Horseman,
+            other => UnitTitle::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for UnitTitle {
+    fn default() -> UnitTitle {
+        UnitTitle::from("")
+    }
+}
+
+impl fmt::Display for UnitTitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UnitTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitTitle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| UnitTitle::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for UnitTitle {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `title` argument of [`Unit::build`](super::Unit::build)
+/// - [`TowerJob::title`](super::TowerJob::title)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TowerTitle {
+    /// `"arrow"`
+    Arrow,
+    /// `"aoe"`
+    Aoe,
+    /// `"ballista"`
+    Ballista,
+    /// `"cleansing"`
+    Cleansing,
+    /// `"castle"`
+    Castle,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl TowerTitle {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TowerTitle::Arrow => "arrow",
+            TowerTitle::Aoe => "aoe",
+            TowerTitle::Ballista => "ballista",
+            TowerTitle::Cleansing => "cleansing",
+            TowerTitle::Castle => "castle",
+            TowerTitle::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for TowerTitle {
+    fn from(s: &str) -> TowerTitle {
+        match s {
+            "arrow" => TowerTitle::Arrow,
+            "aoe" => TowerTitle::Aoe,
+            "ballista" => TowerTitle::Ballista,
+            "cleansing" => TowerTitle::Cleansing,
+            "castle" => TowerTitle::Castle,
+            other => TowerTitle::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for TowerTitle {
+    fn default() -> TowerTitle {
+        TowerTitle::from("")
+    }
+}
+
+impl fmt::Display for TowerTitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TowerTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TowerTitle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| TowerTitle::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for TowerTitle {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// Holds top-level game state and settings for the current game.
 #[derive(Debug, Clone)]
@@ -19,122 +31,210 @@ impl Game {
         Game { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// A mapping of every game object's ID to the actual game object. Primarily used by the server
     /// and client to easily refer to the game objects via ID.
     pub fn game_objects(&self) -> Map<Str, GameObject> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .game_objects.clone()
     }
 
     /// List of all the players in the game.
     pub fn players(&self) -> List<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .players.clone()
     }
 
     /// A unique identifier for the game instance that is being played.
     pub fn session(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .session.clone()
     }
 
     /// The player whose turn it is currently. That player can send commands. Other players cannot.
     pub fn current_player(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .current_player.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The current turn number, starting at 0 for the first player's turn.
     pub fn current_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .current_turn.clone()
+        lock(&self.inner)
+            .current_turn
     }
 
     /// The maximum number of turns before the game will automatically end.
     pub fn max_turns(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_turns.clone()
+        lock(&self.inner)
+            .max_turns
     }
 
     /// The amount of time (in nano-seconds) added after each player performs a turn.
     pub fn time_added_per_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .time_added_per_turn.clone()
+        lock(&self.inner)
+            .time_added_per_turn
     }
 
     /// The number of Tiles in the map along the x (horizontal) axis.
     pub fn map_width(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_width.clone()
+        lock(&self.inner)
+            .map_width
     }
 
     /// The number of Tiles in the map along the y (vertical) axis.
     pub fn map_height(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_height.clone()
+        lock(&self.inner)
+            .map_height
     }
 
     /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
     /// correct index.
     pub fn tiles(&self) -> List<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .tiles.clone()
     }
 
     /// Every Unit in the game.
     pub fn units(&self) -> List<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .units.clone()
     }
 
     /// A list of every unit type / job.
     pub fn unit_jobs(&self) -> List<UnitJob> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .unit_jobs.clone()
     }
 
     /// Every Tower in the game.
     pub fn towers(&self) -> List<Tower> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .towers.clone()
     }
 
     /// A list of every tower type / job.
     pub fn tower_jobs(&self) -> List<TowerJob> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .tower_jobs.clone()
     }
 
     /// The amount of turns it takes between the river changing phases.
     pub fn river_phase(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .river_phase.clone()
+        lock(&self.inner)
+            .river_phase
     }
 
     /// The amount of gold income per turn per unit in a mine.
     pub fn gold_income_per_unit(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .gold_income_per_unit.clone()
+        lock(&self.inner)
+            .gold_income_per_unit
     }
 
     /// The amount of gold income per turn per unit in the island mine.
     pub fn island_income_per_unit(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .island_income_per_unit.clone()
+        lock(&self.inner)
+            .island_income_per_unit
     }
 
     /// The Amount of gold income per turn per unit fishing on the river side.
     pub fn mana_income_per_unit(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .mana_income_per_unit.clone()
+        lock(&self.inner)
+            .mana_income_per_unit
+    }
+
+    /// Looks up a game object by its id.
+    ///
+    /// Returns `None` if there is no such object, if it has been removed from the game, or if it
+    /// is not a `T`.
+    pub fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.inner).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// The settings given to the AI for this game, like `--aiSettings` on the command line.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn ai_settings(&self) -> Result<AiSettings, Error> {
+        self.with_context(|cx| Ok(cx.ai_settings().clone()))
+    }
+
+    /// Takes an immutable copy of the whole game state, which can be read without locking.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        self.with_context(|cx| Ok(cx.snapshot()))
+    }
+}
+
+/// Handles are equal when they refer to the same game, regardless of its state.
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Game {}
+
+/// Handles hash by the identity of the game they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+impl AnyGame for Game {
+    type GameObject = GameObject;
+    type Player = Player;
+
+    fn session(&self) -> Str {
+        self.session()
+    }
+
+    fn players(&self) -> List<Player> {
+        self.players()
+    }
+
+    fn game_objects(&self) -> Map<Str, GameObject> {
+        self.game_objects()
+    }
+}
+
+impl TurnBasedGame for Game {
+    fn current_turn(&self) -> i64 {
+        self.current_turn()
+    }
+
+    fn max_turns(&self) -> i64 {
+        self.max_turns()
+    }
+}
+
+impl GridGame for Game {
+    type Tile = Tile;
+
+    fn map_width(&self) -> i64 {
+        self.map_width()
+    }
+
+    fn map_height(&self) -> i64 {
+        self.map_height()
+    }
+
+    fn tiles(&self) -> List<Tile> {
+        self.tiles()
     }
 }
@@ -0,0 +1,1060 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{UnitTitle, TowerTitle};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tower`](super::Tower) at the time of this snapshot.
+    pub fn tower(&self, obj: &super::Tower) -> Option<Tower<'_>> {
+        Tower::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Unit`](super::Unit) at the time of this snapshot.
+    pub fn unit(&self, obj: &super::Unit) -> Option<Unit<'_>> {
+        Unit::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`UnitJob`](super::UnitJob) at the time of this snapshot.
+    pub fn unit_job(&self, obj: &super::UnitJob) -> Option<UnitJob<'_>> {
+        UnitJob::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`TowerJob`](super::TowerJob) at the time of this snapshot.
+    pub fn tower_job(&self, obj: &super::TowerJob) -> Option<TowerJob<'_>> {
+        TowerJob::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Unit in the game.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.snapshot.game.units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A list of every unit type / job.
+    pub fn unit_jobs(&self) -> Vec<UnitJob<'a>> {
+        self.snapshot.game.unit_jobs
+            .iter()
+            .filter_map(|obj| UnitJob::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Tower in the game.
+    pub fn towers(&self) -> Vec<Tower<'a>> {
+        self.snapshot.game.towers
+            .iter()
+            .filter_map(|obj| Tower::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A list of every tower type / job.
+    pub fn tower_jobs(&self) -> Vec<TowerJob<'a>> {
+        self.snapshot.game.tower_jobs
+            .iter()
+            .filter_map(|obj| TowerJob::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of turns it takes between the river changing phases.
+    pub fn river_phase(&self) -> i64 {
+        self.snapshot.game.river_phase
+    }
+
+    /// The amount of gold income per turn per unit in a mine.
+    pub fn gold_income_per_unit(&self) -> i64 {
+        self.snapshot.game.gold_income_per_unit
+    }
+
+    /// The amount of gold income per turn per unit in the island mine.
+    pub fn island_income_per_unit(&self) -> i64 {
+        self.snapshot.game.island_income_per_unit
+    }
+
+    /// The Amount of gold income per turn per unit fishing on the river side.
+    pub fn mana_income_per_unit(&self) -> i64 {
+        self.snapshot.game.mana_income_per_unit
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// Every Unit owned by this Player.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.state.as_player().units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Tower owned by this player.
+    pub fn towers(&self) -> Vec<Tower<'a>> {
+        self.state.as_player().towers
+            .iter()
+            .filter_map(|obj| Tower::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of gold this Player has.
+    pub fn gold(&self) -> i64 {
+        self.state.as_player().gold
+    }
+
+    /// The amount of mana this player has.
+    pub fn mana(&self) -> i64 {
+        self.state.as_player().mana
+    }
+
+    /// The amount of health remaining for this player's main unit.
+    pub fn health(&self) -> i64 {
+        self.state.as_player().health
+    }
+
+    /// All tiles that this player can build on and move workers on.
+    pub fn side(&self) -> Vec<Tile<'a>> {
+        self.state.as_player().side
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The tile that the home base is located on.
+    pub fn home_base(&self) -> Vec<Tile<'a>> {
+        self.state.as_player().home_base
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Unit on this Tile if present, otherwise None.
+    pub fn unit(&self) -> Option<Unit<'a>> {
+        self.state.as_tile().unit
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The Tower on this Tile if present, otherwise None.
+    pub fn tower(&self) -> Option<Tower<'a>> {
+        self.state.as_tile().tower
+            .as_ref()
+            .and_then(|obj| Tower::resolve(self.snapshot, obj))
+    }
+
+    /// Whether or not the tile can be moved on by workers.
+    pub fn is_wall(&self) -> bool {
+        self.state.as_tile().is_wall
+    }
+
+    /// Whether or not the tile is considered a path or not (Units can walk on paths).
+    pub fn is_path(&self) -> bool {
+        self.state.as_tile().is_path
+    }
+
+    /// Whether or not the tile is considered grass or not (Workers can walk on grass).
+    pub fn is_grass(&self) -> bool {
+        self.state.as_tile().is_grass
+    }
+
+    /// Whether or not the tile is considered a tower or not.
+    pub fn is_tower(&self) -> bool {
+        self.state.as_tile().is_tower
+    }
+
+    /// Whether or not the tile is considered to be a gold mine or not.
+    pub fn is_gold_mine(&self) -> bool {
+        self.state.as_tile().is_gold_mine
+    }
+
+    /// Whether or not the tile is considered to be the island gold mine or not.
+    pub fn is_island_gold_mine(&self) -> bool {
+        self.state.as_tile().is_island_gold_mine
+    }
+
+    /// Whether or not the tile is considered a river or not.
+    pub fn is_river(&self) -> bool {
+        self.state.as_tile().is_river
+    }
+
+    /// Whether or not the tile is a castle tile.
+    pub fn is_castle(&self) -> bool {
+        self.state.as_tile().is_castle
+    }
+
+    /// Whether or not the tile is the worker spawn.
+    pub fn is_worker_spawn(&self) -> bool {
+        self.state.as_tile().is_worker_spawn
+    }
+
+    /// Whether or not the tile is the unit spawn.
+    pub fn is_unit_spawn(&self) -> bool {
+        self.state.as_tile().is_unit_spawn
+    }
+
+    /// The amount of corpses on this tile.
+    pub fn corpses(&self) -> i64 {
+        self.state.as_tile().corpses
+    }
+
+    /// The amount of Zombies on this tile.
+    pub fn num_zombies(&self) -> i64 {
+        self.state.as_tile().num_zombies
+    }
+
+    /// The amount of Ghouls on this tile.
+    pub fn num_ghouls(&self) -> i64 {
+        self.state.as_tile().num_ghouls
+    }
+
+    /// The amount of Hounds on this tile.
+    pub fn num_hounds(&self) -> i64 {
+        self.state.as_tile().num_hounds
+    }
+
+    /// Which player owns this tile, only applies to grass tiles for workers, None otherwise.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_tile().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tower`](super::Tower) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tower<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tower<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tower<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tower()?;
+        Some(Tower { snapshot, state })
+    }
+
+    /// The player that built / owns this tower.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_tower().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this Tower is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_tower().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// What type of tower this is (it's job).
+    pub fn job(&self) -> Option<TowerJob<'a>> {
+        self.state.as_tower().job
+            .as_ref()
+            .and_then(|obj| TowerJob::resolve(self.snapshot, obj))
+    }
+
+    /// How much remaining health this tower has.
+    pub fn health(&self) -> i64 {
+        self.state.as_tower().health
+    }
+
+    /// Whether this tower has attacked this turn or not.
+    pub fn attacked(&self) -> bool {
+        self.state.as_tower().attacked
+    }
+
+    /// How many turns are left before it can fire again.
+    pub fn cooldown(&self) -> i64 {
+        self.state.as_tower().cooldown
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tower<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tower").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tower<'a>> for GameObject<'a> {
+    fn from(obj: Tower<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tower<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tower<'a>, GameObject<'a>> {
+        match obj.state.try_as_tower() {
+            Some(_) => Ok(Tower {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Unit`](super::Unit) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Unit<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Unit<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Unit<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit()?;
+        Some(Unit { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Unit.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_unit().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this Unit is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_unit().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The type of unit this is.
+    pub fn job(&self) -> Option<UnitJob<'a>> {
+        self.state.as_unit().job
+            .as_ref()
+            .and_then(|obj| UnitJob::resolve(self.snapshot, obj))
+    }
+
+    /// The remaining health of a unit.
+    pub fn health(&self) -> i64 {
+        self.state.as_unit().health
+    }
+
+    /// Whether or not this Unit has performed its action this turn (attack or build).
+    pub fn acted(&self) -> bool {
+        self.state.as_unit().acted
+    }
+
+    /// The number of moves this unit has left this turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_unit().moves
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Unit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unit").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Unit<'a>> for GameObject<'a> {
+    fn from(obj: Unit<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Unit<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Unit<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit() {
+            Some(_) => Ok(Unit {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`UnitJob`](super::UnitJob) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct UnitJob<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> UnitJob<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<UnitJob<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit_job()?;
+        Some(UnitJob { snapshot, state })
+    }
+
+    /// The type title. 'worker', 'zombie', 'ghoul', 'hound', 'abomination', 'wraith' or
+    /// 'horseman'.
+    pub fn title(&self) -> &'a UnitTitle {
+        &self.state.as_unit_job().title
+    }
+
+    /// How many of this type of unit can take up one tile.
+    pub fn per_tile(&self) -> i64 {
+        self.state.as_unit_job().per_tile
+    }
+
+    /// The amount of starting health this type has.
+    pub fn health(&self) -> i64 {
+        self.state.as_unit_job().health
+    }
+
+    /// The number of moves this type can make per turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_unit_job().moves
+    }
+
+    /// The amount of damage this type does per attack.
+    pub fn damage(&self) -> i64 {
+        self.state.as_unit_job().damage
+    }
+
+    /// How much does this type cost in gold.
+    pub fn gold_cost(&self) -> i64 {
+        self.state.as_unit_job().gold_cost
+    }
+
+    /// How much does this type cost in mana.
+    pub fn mana_cost(&self) -> i64 {
+        self.state.as_unit_job().mana_cost
+    }
+
+    /// Amount of tiles away this type has to be in order to be effective.
+    pub fn range(&self) -> i64 {
+        self.state.as_unit_job().range
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for UnitJob<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UnitJob").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<UnitJob<'a>> for GameObject<'a> {
+    fn from(obj: UnitJob<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for UnitJob<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<UnitJob<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit_job() {
+            Some(_) => Ok(UnitJob {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`TowerJob`](super::TowerJob) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct TowerJob<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> TowerJob<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<TowerJob<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tower_job()?;
+        Some(TowerJob { snapshot, state })
+    }
+
+    /// The type title. 'arrow', 'aoe', 'ballista', 'cleansing', or 'castle'.
+    pub fn title(&self) -> &'a TowerTitle {
+        &self.state.as_tower_job().title
+    }
+
+    /// The amount of starting health this type has.
+    pub fn health(&self) -> i64 {
+        self.state.as_tower_job().health
+    }
+
+    /// The number of tiles this type can attack from.
+    pub fn range(&self) -> i64 {
+        self.state.as_tower_job().range
+    }
+
+    /// Whether this tower type hits all of the units on a tile (true) or one at a time (false).
+    pub fn all_units(&self) -> bool {
+        self.state.as_tower_job().all_units
+    }
+
+    /// The amount of damage this type does per attack.
+    pub fn damage(&self) -> i64 {
+        self.state.as_tower_job().damage
+    }
+
+    /// How much does this type cost in gold.
+    pub fn gold_cost(&self) -> i64 {
+        self.state.as_tower_job().gold_cost
+    }
+
+    /// How many turns have to take place between this type's attacks.
+    pub fn turns_between_attacks(&self) -> i64 {
+        self.state.as_tower_job().turns_between_attacks
+    }
+
+    /// How much does this type cost in mana.
+    pub fn mana_cost(&self) -> i64 {
+        self.state.as_tower_job().mana_cost
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for TowerJob<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TowerJob").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<TowerJob<'a>> for GameObject<'a> {
+    fn from(obj: TowerJob<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for TowerJob<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<TowerJob<'a>, GameObject<'a>> {
+        match obj.state.try_as_tower_job() {
+            Some(_) => Ok(TowerJob {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
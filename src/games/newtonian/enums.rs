@@ -0,0 +1,398 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Tile::type_`](super::Tile::type_)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TileType {
+    /// `"normal"`
+    Normal,
+    /// `"generator"`
+    Generator,
+    /// `"conveyor"`
+    Conveyor,
+    /// `"spawn"`
+    Spawn,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl TileType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TileType::Normal => "normal",
+            TileType::Generator => "generator",
+            TileType::Conveyor => "conveyor",
+            TileType::Spawn => "spawn",
+            TileType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for TileType {
+    fn from(s: &str) -> TileType {
+        match s {
+            "normal" => TileType::Normal,
+            "generator" => TileType::Generator,
+            "conveyor" => TileType::Conveyor,
+            "spawn" => TileType::Spawn,
+            other => TileType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for TileType {
+    fn default() -> TileType {
+        TileType::from("")
+    }
+}
+
+impl fmt::Display for TileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TileType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| TileType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for TileType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Tile::direction`](super::Tile::direction)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// `"blank"`
+    Blank,
+    /// `"north"`
+    North,
+    /// `"east"`
+    East,
+    /// `"south"`
+    South,
+    /// `"west"`
+    West,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Direction::Blank => "blank",
+            Direction::North => "north",
+            Direction::East => "east",
+            Direction::South => "south",
+            Direction::West => "west",
+            Direction::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Direction {
+    fn from(s: &str) -> Direction {
+        match s {
+            "blank" => Direction::Blank,
+            "north" => Direction::North,
+            "east" => Direction::East,
+            "south" => Direction::South,
+            "west" => Direction::West,
+            other => Direction::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::from("")
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Direction::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Direction {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Machine::ore_type`](super::Machine::ore_type)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OreType {
+    /// `"redium"`
+    Redium,
+    /// `"blueium"`
+    Blueium,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl OreType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            OreType::Redium => "redium",
+            OreType::Blueium => "blueium",
+            OreType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for OreType {
+    fn from(s: &str) -> OreType {
+        match s {
+            "redium" => OreType::Redium,
+            "blueium" => OreType::Blueium,
+            other => OreType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for OreType {
+    fn default() -> OreType {
+        OreType::from("")
+    }
+}
+
+impl fmt::Display for OreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for OreType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OreType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| OreType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for OreType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `material` argument of [`Unit::drop`](super::Unit::drop)
+/// - the `material` argument of [`Unit::pickup`](super::Unit::pickup)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Material {
+    /// `"redium"`
+    Redium,
+    /// `"blueium"`
+    Blueium,
+    /// `"redium ore"`
+    RediumOre,
+    /// `"blueium ore"`
+    BlueiumOre,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Material {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Material::Redium => "redium",
+            Material::Blueium => "blueium",
+            Material::RediumOre => "redium ore",
+            Material::BlueiumOre => "blueium ore",
+            Material::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Material {
+    fn from(s: &str) -> Material {
+        match s {
+            "redium" => Material::Redium,
+            "blueium" => Material::Blueium,
+            "redium ore" => Material::RediumOre,
+            "blueium ore" => Material::BlueiumOre,
+            other => Material::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Material {
+    fn default() -> Material {
+        Material::from("")
+    }
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Material {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Material {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Material::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Material {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Job::title`](super::Job::title)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JobTitle {
+    /// `"intern"`
+    Intern,
+    /// `"manager"`
+    Manager,
+    /// `"physicist"`
+    Physicist,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl JobTitle {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JobTitle::Intern => "intern",
+            JobTitle::Manager => "manager",
+            JobTitle::Physicist => "physicist",
+            JobTitle::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for JobTitle {
+    fn from(s: &str) -> JobTitle {
+        match s {
+            "intern" => JobTitle::Intern,
+            "manager" => JobTitle::Manager,
+            "physicist" => JobTitle::Physicist,
+            other => JobTitle::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for JobTitle {
+    fn default() -> JobTitle {
+        JobTitle::from("")
+    }
+}
+
+impl fmt::Display for JobTitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for JobTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobTitle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| JobTitle::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for JobTitle {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,959 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{TileType, Direction, OreType, Material, JobTitle};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Machine`](super::Machine) at the time of this snapshot.
+    pub fn machine(&self, obj: &super::Machine) -> Option<Machine<'_>> {
+        Machine::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Unit`](super::Unit) at the time of this snapshot.
+    pub fn unit(&self, obj: &super::Unit) -> Option<Unit<'_>> {
+        Unit::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Job`](super::Job) at the time of this snapshot.
+    pub fn job(&self, obj: &super::Job) -> Option<Job<'_>> {
+        Job::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Unit in the game.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.snapshot.game.units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A list of all jobs. first item is intern, second is physicists, and third is manager.
+    pub fn jobs(&self) -> Vec<Job<'a>> {
+        self.snapshot.game.jobs
+            .iter()
+            .filter_map(|obj| Job::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Machine in the game.
+    pub fn machines(&self) -> Vec<Machine<'a>> {
+        self.snapshot.game.machines
+            .iter()
+            .filter_map(|obj| Machine::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of victory points added when a refined ore is consumed by the generator.
+    pub fn refined_value(&self) -> i64 {
+        self.snapshot.game.refined_value
+    }
+
+    /// The amount of turns it takes a unit to spawn.
+    pub fn spawn_time(&self) -> i64 {
+        self.snapshot.game.spawn_time
+    }
+
+    /// The maximum number of managers a player can have.
+    pub fn manager_cap(&self) -> i64 {
+        self.snapshot.game.manager_cap
+    }
+
+    /// The maximum number of interns a player can have.
+    pub fn intern_cap(&self) -> i64 {
+        self.snapshot.game.intern_cap
+    }
+
+    /// The maximum number of physicists a player can have.
+    pub fn physicist_cap(&self) -> i64 {
+        self.snapshot.game.physicist_cap
+    }
+
+    /// The amount of turns a unit cannot do anything when stunned.
+    pub fn stun_time(&self) -> i64 {
+        self.snapshot.game.stun_time
+    }
+
+    /// The number turns a unit is immune to being stunned.
+    pub fn time_immune(&self) -> i64 {
+        self.snapshot.game.time_immune
+    }
+
+    /// The number of materials that spawn per spawn cycle.
+    pub fn material_spawn(&self) -> i64 {
+        self.snapshot.game.material_spawn
+    }
+
+    /// The percent of max HP regained when a unit end their turn on a tile owned by their player.
+    pub fn regenerate_rate(&self) -> f64 {
+        self.snapshot.game.regenerate_rate
+    }
+
+    /// The amount of combined heat and pressure that you need to win.
+    pub fn victory_amount(&self) -> i64 {
+        self.snapshot.game.victory_amount
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// Every Unit owned by this Player.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.state.as_player().units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of heat this Player has.
+    pub fn heat(&self) -> i64 {
+        self.state.as_player().heat
+    }
+
+    /// The amount of pressure this Player has.
+    pub fn pressure(&self) -> i64 {
+        self.state.as_player().pressure
+    }
+
+    /// The time left till a intern spawns. (0 to spawnTime).
+    pub fn intern_spawn(&self) -> i64 {
+        self.state.as_player().intern_spawn
+    }
+
+    /// The time left till a physicist spawns. (0 to spawnTime).
+    pub fn physicist_spawn(&self) -> i64 {
+        self.state.as_player().physicist_spawn
+    }
+
+    /// The time left till a manager spawns. (0 to spawnTime).
+    pub fn manager_spawn(&self) -> i64 {
+        self.state.as_player().manager_spawn
+    }
+
+    /// All the tiles this Player's units can spawn on. (listed from the outer edges inward, from
+    /// top to bottom).
+    pub fn spawn_tiles(&self) -> Vec<Tile<'a>> {
+        self.state.as_player().spawn_tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every generator Tile owned by this Player. (listed from the outer edges inward, from top to
+    /// bottom).
+    pub fn generator_tiles(&self) -> Vec<Tile<'a>> {
+        self.state.as_player().generator_tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Unit on this Tile if present, otherwise None.
+    pub fn unit(&self) -> Option<Unit<'a>> {
+        self.state.as_tile().unit
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The Machine on this Tile if present, otherwise None.
+    pub fn machine(&self) -> Option<Machine<'a>> {
+        self.state.as_tile().machine
+            .as_ref()
+            .and_then(|obj| Machine::resolve(self.snapshot, obj))
+    }
+
+    /// Whether or not the tile is a wall.
+    pub fn is_wall(&self) -> bool {
+        self.state.as_tile().is_wall
+    }
+
+    /// The type of Tile this is ('normal', 'generator', 'conveyor', or 'spawn').
+    pub fn type_(&self) -> &'a TileType {
+        &self.state.as_tile().type_
+    }
+
+    /// The direction of a conveyor belt ('blank', 'north', 'east', 'south', or 'west'). blank
+    /// means conveyor doesn't move.
+    pub fn direction(&self) -> &'a Direction {
+        &self.state.as_tile().direction
+    }
+
+    /// The owner of this Tile, or None if owned by no-one. Only for generators and spawn areas.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_tile().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The amount of redium ore on this tile.
+    pub fn redium_ore(&self) -> i64 {
+        self.state.as_tile().redium_ore
+    }
+
+    /// The amount of redium on this tile.
+    pub fn redium(&self) -> i64 {
+        self.state.as_tile().redium
+    }
+
+    /// The amount of blueium ore on this tile.
+    pub fn blueium_ore(&self) -> i64 {
+        self.state.as_tile().blueium_ore
+    }
+
+    /// The amount of blueium on this tile.
+    pub fn blueium(&self) -> i64 {
+        self.state.as_tile().blueium
+    }
+
+    /// (Visualizer only) Different tile types, cracked, slightly dirty, etc. This has no effect on
+    /// gameplay, but feel free to use it if you want.
+    pub fn decoration(&self) -> i64 {
+        self.state.as_tile().decoration
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Machine`](super::Machine) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Machine<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Machine<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Machine<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_machine()?;
+        Some(Machine { snapshot, state })
+    }
+
+    /// The Tile this Machine is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_machine().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// Tracks how many times this machine has been worked. (0 to refineTime).
+    pub fn worked(&self) -> i64 {
+        self.state.as_machine().worked
+    }
+
+    /// What type of ore the machine takes it. Also determines the type of material it outputs.
+    /// (redium or blueium).
+    pub fn ore_type(&self) -> &'a OreType {
+        &self.state.as_machine().ore_type
+    }
+
+    /// The number of times this machine needs to be worked to refine ore.
+    pub fn refine_time(&self) -> i64 {
+        self.state.as_machine().refine_time
+    }
+
+    /// The amount of ore that needs to be inputted into the machine for it to be worked.
+    pub fn refine_input(&self) -> i64 {
+        self.state.as_machine().refine_input
+    }
+
+    /// The amount of refined ore that is returned after the machine has been fully worked.
+    pub fn refine_output(&self) -> i64 {
+        self.state.as_machine().refine_output
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Machine<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Machine").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Machine<'a>> for GameObject<'a> {
+    fn from(obj: Machine<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Machine<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Machine<'a>, GameObject<'a>> {
+        match obj.state.try_as_machine() {
+            Some(_) => Ok(Machine {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Unit`](super::Unit) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Unit<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Unit<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Unit<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit()?;
+        Some(Unit { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Unit.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_unit().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this Unit is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_unit().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Job this Unit has.
+    pub fn job(&self) -> Option<Job<'a>> {
+        self.state.as_unit().job
+            .as_ref()
+            .and_then(|obj| Job::resolve(self.snapshot, obj))
+    }
+
+    /// The remaining health of a unit.
+    pub fn health(&self) -> i64 {
+        self.state.as_unit().health
+    }
+
+    /// Whether or not this Unit has performed its action this turn.
+    pub fn acted(&self) -> bool {
+        self.state.as_unit().acted
+    }
+
+    /// The number of moves this unit has left this turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_unit().moves
+    }
+
+    /// The amount of redium ore carried by this unit. (0 to job carry capacity - other carried
+    /// items).
+    pub fn redium_ore(&self) -> i64 {
+        self.state.as_unit().redium_ore
+    }
+
+    /// The amount of redium carried by this unit. (0 to job carry capacity - other carried items).
+    pub fn redium(&self) -> i64 {
+        self.state.as_unit().redium
+    }
+
+    /// The amount of blueium ore carried by this unit. (0 to job carry capacity - other carried
+    /// items).
+    pub fn blueium_ore(&self) -> i64 {
+        self.state.as_unit().blueium_ore
+    }
+
+    /// The amount of blueium carried by this unit. (0 to job carry capacity - other carried
+    /// items).
+    pub fn blueium(&self) -> i64 {
+        self.state.as_unit().blueium
+    }
+
+    /// Duration the unit is stunned. (0 to the game constant stunTime).
+    pub fn stun_time(&self) -> i64 {
+        self.state.as_unit().stun_time
+    }
+
+    /// Duration of stun immunity. (0 to timeImmune).
+    pub fn stun_immune(&self) -> i64 {
+        self.state.as_unit().stun_immune
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Unit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unit").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Unit<'a>> for GameObject<'a> {
+    fn from(obj: Unit<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Unit<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Unit<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit() {
+            Some(_) => Ok(Unit {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Job`](super::Job) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Job<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Job<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_job()?;
+        Some(Job { snapshot, state })
+    }
+
+    /// The Job title. 'intern', 'manager', or 'physicist'.
+    pub fn title(&self) -> &'a JobTitle {
+        &self.state.as_job().title
+    }
+
+    /// The amount of starting health this Job has.
+    pub fn health(&self) -> i64 {
+        self.state.as_job().health
+    }
+
+    /// The number of moves this Job can make per turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_job().moves
+    }
+
+    /// The amount of damage this Job does per attack.
+    pub fn damage(&self) -> i64 {
+        self.state.as_job().damage
+    }
+
+    /// How many combined resources a unit with this Job can hold at once.
+    pub fn carry_limit(&self) -> i64 {
+        self.state.as_job().carry_limit
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Job<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Job").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Job<'a>> for GameObject<'a> {
+    fn from(obj: Job<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Job<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Job<'a>, GameObject<'a>> {
+        match obj.state.try_as_job() {
+            Some(_) => Ok(Job {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A Tile in the game that makes up the 2D map grid.
 #[derive(Debug, Clone)]
@@ -19,134 +31,136 @@ impl Tile {
         Tile { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The x (horizontal) position of this Tile.
     pub fn x(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .x.clone()
+            .x
     }
 
     /// The y (vertical) position of this Tile.
     pub fn y(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .y.clone()
+            .y
     }
 
     /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
     pub fn tile_north(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .tile_north.clone()
     }
 
     /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
     pub fn tile_east(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .tile_east.clone()
     }
 
     /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
     pub fn tile_south(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .tile_south.clone()
     }
 
     /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
     pub fn tile_west(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .tile_west.clone()
     }
 
     /// The Unit on this Tile if present, otherwise None.
     pub fn unit(&self) -> Option<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .unit.clone()
     }
 
     /// The Machine on this Tile if present, otherwise None.
     pub fn machine(&self) -> Option<Machine> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .machine.clone()
     }
 
     /// Whether or not the tile is a wall.
     pub fn is_wall(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .is_wall.clone()
+            .is_wall
     }
 
     /// The type of Tile this is ('normal', 'generator', 'conveyor', or 'spawn').
-    pub fn type_(&self) -> Str {
-        self.inner.lock().unwrap()
+    pub fn type_(&self) -> TileType {
+        lock(&self.inner)
             .as_tile()
             .type_.clone()
     }
 
     /// The direction of a conveyor belt ('blank', 'north', 'east', 'south', or 'west'). blank
     /// means conveyor doesn't move.
-    pub fn direction(&self) -> Str {
-        self.inner.lock().unwrap()
+    pub fn direction(&self) -> Direction {
+        lock(&self.inner)
             .as_tile()
             .direction.clone()
     }
 
     /// The owner of this Tile, or None if owned by no-one. Only for generators and spawn areas.
     pub fn owner(&self) -> Option<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
             .owner.clone()
     }
 
     /// The amount of redium ore on this tile.
     pub fn redium_ore(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .redium_ore.clone()
+            .redium_ore
     }
 
     /// The amount of redium on this tile.
     pub fn redium(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .redium.clone()
+            .redium
     }
 
     /// The amount of blueium ore on this tile.
     pub fn blueium_ore(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .blueium_ore.clone()
+            .blueium_ore
     }
 
     /// The amount of blueium on this tile.
     pub fn blueium(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .blueium.clone()
+            .blueium
     }
 
     /// (Visualizer only) Different tile types, cracked, slightly dirty, etc. This has no effect on
     /// gameplay, but feel free to use it if you want.
     pub fn decoration(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_tile()
-            .decoration.clone()
+            .decoration
     }
 
     /// _Inherited from [`GameObject`]_
@@ -154,7 +168,7 @@ impl Tile {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -165,7 +179,7 @@ impl Tile {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -174,7 +188,7 @@ impl Tile {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -193,8 +207,11 @@ impl Tile {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -204,21 +221,87 @@ impl Tile {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Tile {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Tile {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Tile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Tile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tile {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Tile {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_tile().is_some() {
             Some(Tile {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -227,3 +310,73 @@ impl inner::ObjectInner for Tile {
     }
 }
 impl Object for Tile {}
+
+impl Serialize for Tile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Tile {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Tile {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Tile {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
+
+impl GridTile for Tile {
+    fn x(&self) -> i64 {
+        self.x()
+    }
+
+    fn y(&self) -> i64 {
+        self.y()
+    }
+
+    fn tile_north(&self) -> Option<Tile> {
+        self.tile_north()
+    }
+
+    fn tile_east(&self) -> Option<Tile> {
+        self.tile_east()
+    }
+
+    fn tile_south(&self) -> Option<Tile> {
+        self.tile_south()
+    }
+
+    fn tile_west(&self) -> Option<Tile> {
+        self.tile_west()
+    }
+}
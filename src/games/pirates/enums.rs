@@ -0,0 +1,153 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Tile::type_`](super::Tile::type_)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TileType {
+    /// `"water"`
+    Water,
+    /// `"land"`
+    Land,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl TileType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TileType::Water => "water",
+            TileType::Land => "land",
+            TileType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for TileType {
+    fn from(s: &str) -> TileType {
+        match s {
+            "water" => TileType::Water,
+            "land" => TileType::Land,
+            other => TileType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for TileType {
+    fn default() -> TileType {
+        TileType::from("")
+    }
+}
+
+impl fmt::Display for TileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TileType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| TileType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for TileType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `type_` argument of [`Port::spawn`](super::Port::spawn)
+/// - the `target` argument of [`Unit::attack`](super::Unit::attack)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UnitType {
+    /// `"crew"`
+    Crew,
+    /// `"ship"`
+    Ship,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl UnitType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UnitType::Crew => "crew",
+            UnitType::Ship => "ship",
+            UnitType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for UnitType {
+    fn from(s: &str) -> UnitType {
+        match s {
+            "crew" => UnitType::Crew,
+            "ship" => UnitType::Ship,
+            other => UnitType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for UnitType {
+    fn default() -> UnitType {
+        UnitType::from("")
+    }
+}
+
+impl fmt::Display for UnitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UnitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| UnitType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for UnitType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,815 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{TileType, UnitType};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Port`](super::Port) at the time of this snapshot.
+    pub fn port(&self, obj: &super::Port) -> Option<Port<'_>> {
+        Port::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Unit`](super::Unit) at the time of this snapshot.
+    pub fn unit(&self, obj: &super::Unit) -> Option<Unit<'_>> {
+        Unit::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Unit in the game. Merchant units have targetPort set to a port.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.snapshot.game.units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Port in the game. Merchant ports have owner set to None.
+    pub fn ports(&self) -> Vec<Port<'a>> {
+        self.snapshot.game.ports
+            .iter()
+            .filter_map(|obj| Port::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// How much gold it costs to construct a single crew.
+    pub fn crew_cost(&self) -> i64 {
+        self.snapshot.game.crew_cost
+    }
+
+    /// How much gold it costs to construct a ship.
+    pub fn ship_cost(&self) -> i64 {
+        self.snapshot.game.ship_cost
+    }
+
+    /// How much damage crew deal to each other.
+    pub fn crew_damage(&self) -> i64 {
+        self.snapshot.game.crew_damage
+    }
+
+    /// How much damage ships deal to ships and ports.
+    pub fn ship_damage(&self) -> i64 {
+        self.snapshot.game.ship_damage
+    }
+
+    /// The maximum amount of health a crew member can have.
+    pub fn crew_health(&self) -> i64 {
+        self.snapshot.game.crew_health
+    }
+
+    /// The maximum amount of health a ship can have.
+    pub fn ship_health(&self) -> i64 {
+        self.snapshot.game.ship_health
+    }
+
+    /// A crew's attack range. Range is circular.
+    pub fn crew_range(&self) -> f64 {
+        self.snapshot.game.crew_range
+    }
+
+    /// A ship's attack range. Range is circular.
+    pub fn ship_range(&self) -> f64 {
+        self.snapshot.game.ship_range
+    }
+
+    /// The number of moves Units with only crew are given each turn.
+    pub fn crew_moves(&self) -> i64 {
+        self.snapshot.game.crew_moves
+    }
+
+    /// The number of moves Units with ships are given each turn.
+    pub fn ship_moves(&self) -> i64 {
+        self.snapshot.game.ship_moves
+    }
+
+    /// How far a Unit can be from a Port to rest. Range is circular.
+    pub fn rest_range(&self) -> f64 {
+        self.snapshot.game.rest_range
+    }
+
+    /// How much health a Unit recovers when they rest.
+    pub fn heal_factor(&self) -> f64 {
+        self.snapshot.game.heal_factor
+    }
+
+    /// The rate buried gold increases each turn.
+    pub fn bury_interest_rate(&self) -> f64 {
+        self.snapshot.game.bury_interest_rate
+    }
+
+    /// When a merchant ship spawns, the amount of additional gold it has relative to the Port's
+    /// investment.
+    pub fn merchant_interest_rate(&self) -> f64 {
+        self.snapshot.game.merchant_interest_rate
+    }
+
+    /// The Euclidean distance buried gold must be from the Player's Port to accumulate interest.
+    pub fn min_interest_distance(&self) -> f64 {
+        self.snapshot.game.min_interest_distance
+    }
+
+    /// How much gold merchant Ports get each turn.
+    pub fn merchant_gold_rate(&self) -> f64 {
+        self.snapshot.game.merchant_gold_rate
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// Every Unit owned by this Player.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.state.as_player().units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of infamy this Player has.
+    pub fn infamy(&self) -> i64 {
+        self.state.as_player().infamy
+    }
+
+    /// The amount of gold this Player has in reserve.
+    pub fn gold(&self) -> i64 {
+        self.state.as_player().gold
+    }
+
+    /// The Port owned by this Player.
+    pub fn port(&self) -> Option<Port<'a>> {
+        self.state.as_player().port
+            .as_ref()
+            .and_then(|obj| Port::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Unit on this Tile if present, otherwise None.
+    pub fn unit(&self) -> Option<Unit<'a>> {
+        self.state.as_tile().unit
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The Port on this Tile if present, otherwise None.
+    pub fn port(&self) -> Option<Port<'a>> {
+        self.state.as_tile().port
+            .as_ref()
+            .and_then(|obj| Port::resolve(self.snapshot, obj))
+    }
+
+    /// The type of Tile this is ('water' or 'land').
+    pub fn type_(&self) -> &'a TileType {
+        &self.state.as_tile().type_
+    }
+
+    /// The amount of gold buried on this tile.
+    pub fn gold(&self) -> i64 {
+        self.state.as_tile().gold
+    }
+
+    /// (Visualizer only) Whether this tile is deep sea or grassy. This has no effect on gameplay,
+    /// but feel free to use it if you want.
+    pub fn decoration(&self) -> bool {
+        self.state.as_tile().decoration
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Port`](super::Port) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Port<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Port<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Port<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_port()?;
+        Some(Port { snapshot, state })
+    }
+
+    /// The Tile this Port is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_port().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The owner of this Port, or None if owned by merchants.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_port().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// For players, how much more gold this Port can spend this turn. For merchants, how much gold
+    /// this Port has accumulated (it will spawn a ship when the Port can afford one).
+    pub fn gold(&self) -> i64 {
+        self.state.as_port().gold
+    }
+
+    /// (Merchants only) How much gold was invested into this Port. Investment determines the
+    /// strength and value of the next ship.
+    pub fn investment(&self) -> i64 {
+        self.state.as_port().investment
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Port<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Port").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Port<'a>> for GameObject<'a> {
+    fn from(obj: Port<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Port<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Port<'a>, GameObject<'a>> {
+        match obj.state.try_as_port() {
+            Some(_) => Ok(Port {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Unit`](super::Unit) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Unit<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Unit<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Unit<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit()?;
+        Some(Unit { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Unit, or None if the Unit is neutral.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_unit().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this Unit is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_unit().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// If a ship is on this Tile, how much health it has remaining. 0 for no ship.
+    pub fn ship_health(&self) -> i64 {
+        self.state.as_unit().ship_health
+    }
+
+    /// How many crew are on this Tile. This number will always be <= crewHealth.
+    pub fn crew(&self) -> i64 {
+        self.state.as_unit().crew
+    }
+
+    /// How much total health the crew on this Tile have.
+    pub fn crew_health(&self) -> i64 {
+        self.state.as_unit().crew_health
+    }
+
+    /// How much gold this Unit is carrying.
+    pub fn gold(&self) -> i64 {
+        self.state.as_unit().gold
+    }
+
+    /// Whether this Unit has performed its action this turn.
+    pub fn acted(&self) -> bool {
+        self.state.as_unit().acted
+    }
+
+    /// How many more times this Unit may move this turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_unit().moves
+    }
+
+    /// (Merchants only) The path this Unit will follow. The first element is the Tile this Unit
+    /// will move to next.
+    pub fn path(&self) -> Vec<Tile<'a>> {
+        self.state.as_unit().path
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// (Merchants only) The Port this Unit is moving to.
+    pub fn target_port(&self) -> Option<Port<'a>> {
+        self.state.as_unit().target_port
+            .as_ref()
+            .and_then(|obj| Port::resolve(self.snapshot, obj))
+    }
+
+    /// (Merchants only) The number of turns this merchant ship won't be able to move. They will
+    /// still attack. Merchant ships are stunned when they're attacked.
+    pub fn stun_turns(&self) -> i64 {
+        self.state.as_unit().stun_turns
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Unit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unit").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Unit<'a>> for GameObject<'a> {
+    fn from(obj: Unit<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Unit<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Unit<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit() {
+            Some(_) => Ok(Unit {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
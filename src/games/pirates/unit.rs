@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A unit group in the game. This may consist of a ship and any number of crew.
 #[derive(Debug, Clone)]
@@ -19,82 +31,84 @@ impl Unit {
         Unit { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The Player that owns and can control this Unit, or None if the Unit is neutral.
     pub fn owner(&self) -> Option<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .owner.clone()
     }
 
     /// The Tile this Unit is on.
     pub fn tile(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .tile.clone()
     }
 
     /// If a ship is on this Tile, how much health it has remaining. 0 for no ship.
     pub fn ship_health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .ship_health.clone()
+            .ship_health
     }
 
     /// How many crew are on this Tile. This number will always be <= crewHealth.
     pub fn crew(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .crew.clone()
+            .crew
     }
 
     /// How much total health the crew on this Tile have.
     pub fn crew_health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .crew_health.clone()
+            .crew_health
     }
 
     /// How much gold this Unit is carrying.
     pub fn gold(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .gold.clone()
+            .gold
     }
 
     /// Whether this Unit has performed its action this turn.
     pub fn acted(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .acted.clone()
+            .acted
     }
 
     /// How many more times this Unit may move this turn.
     pub fn moves(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .moves.clone()
+            .moves
     }
 
     /// (Merchants only) The path this Unit will follow. The first element is the Tile this Unit
     /// will move to next.
     pub fn path(&self) -> List<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .path.clone()
     }
 
     /// (Merchants only) The Port this Unit is moving to.
     pub fn target_port(&self) -> Option<Port> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .target_port.clone()
     }
@@ -102,9 +116,9 @@ impl Unit {
     /// (Merchants only) The number of turns this merchant ship won't be able to move. They will
     /// still attack. Merchant ships are stunned when they're attacked.
     pub fn stun_turns(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .stun_turns.clone()
+            .stun_turns
     }
 
     /// _Inherited from [`GameObject`]_
@@ -112,7 +126,7 @@ impl Unit {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -123,7 +137,7 @@ impl Unit {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -132,7 +146,7 @@ impl Unit {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -154,8 +168,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -172,7 +189,7 @@ impl Unit {
     /// - _tile_ - The Tile to attack.
     ///
     /// - _target_ - Whether to attack 'crew' or 'ship'. Crew deal damage to crew and ships deal
-    /// damage to ships. Consumes any remaining moves.
+    ///   damage to ships. Consumes any remaining moves.
     ///
     /// # Returns
     ///
@@ -180,13 +197,16 @@ impl Unit {
     pub fn attack(
         &self,
         tile: &Tile,
-        target: &str,
+        target: UnitType,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            target: &'a str,
+            target: UnitType,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -203,7 +223,7 @@ impl Unit {
     /// # Arguments
     ///
     /// - _amount_ - How much gold this Unit should bury. Amounts <= 0 will bury as much as
-    /// possible.
+    ///   possible.
     ///
     /// # Returns
     ///
@@ -214,8 +234,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -230,7 +253,7 @@ impl Unit {
     /// # Arguments
     ///
     /// - _amount_ - How much gold this Unit should take. Amounts <= 0 will dig up as much as
-    /// possible.
+    ///   possible.
     ///
     /// # Returns
     ///
@@ -241,8 +264,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -258,7 +284,7 @@ impl Unit {
     /// # Arguments
     ///
     /// - _amount_ - The amount of gold to deposit. Amounts <= 0 will deposit all the gold on this
-    /// Unit.
+    ///   Unit.
     ///
     /// # Returns
     ///
@@ -269,8 +295,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -295,8 +324,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -314,10 +346,10 @@ impl Unit {
     /// - _tile_ - The Tile to move the crew to.
     ///
     /// - _amount_ - The number of crew to move onto that Tile. Amount <= 0 will move all the crew
-    /// to that Tile.
+    ///   to that Tile.
     ///
     /// - _gold_ - The amount of gold the crew should take with them. Gold < 0 will move all the
-    /// gold to that Tile.
+    ///   gold to that Tile.
     ///
     /// # Returns
     ///
@@ -330,10 +362,13 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
             amount: i64,
             gold: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -355,7 +390,10 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -378,8 +416,11 @@ impl Unit {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -389,21 +430,87 @@ impl Unit {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Unit {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Unit {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Unit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Unit {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_unit().is_some() {
             Some(Unit {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -412,3 +519,47 @@ impl inner::ObjectInner for Unit {
     }
 }
 impl Object for Unit {}
+
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Unit {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Unit {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Unit {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -0,0 +1,42 @@
+//! Printing what changes in any game, for spectators that only want to watch.
+
+use crate::client::delta::Changes;
+
+use super::{AnyGame, GameObjectHandle};
+
+/// Prints one line for the game and each game object that changed, like
+/// `Unit #5 changed: moves, tile`.
+///
+/// Objects are listed in order of their ids, and their fields in alphabetical order. Objects
+/// that were just created are only listed as created.
+pub fn print_changes<G: AnyGame>(game: &G, changes: &Changes) {
+    if !changes.game_fields().is_empty() {
+        let mut fields: Vec<_> = changes.game_fields().iter().copied().collect();
+        fields.sort_unstable();
+        println!("Game changed: {}", fields.join(", "));
+    }
+
+    let game_objects = game.game_objects();
+    let name = |id: &str| match game_objects.get(id) {
+        Some(obj) => format!("{} #{}", obj.game_object_name(), id),
+        None => format!("Game object #{}", id),
+    };
+    let mut ids: Vec<_> = changes
+        .created()
+        .chain(changes.changed().map(|(id, _)| id))
+        .collect();
+    ids.extend(changes.removed());
+    ids.sort_unstable_by_key(|id| (id.len(), *id));
+    ids.dedup();
+    for id in ids {
+        if changes.was_removed(id) {
+            println!("{} removed", name(id));
+        } else if changes.was_created(id) {
+            println!("{} created", name(id));
+        } else if let Some(fields) = changes.fields(id) {
+            let mut fields: Vec<_> = fields.iter().copied().collect();
+            fields.sort_unstable();
+            println!("{} changed: {}", name(id), fields.join(", "));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! The registry of games that the client can play.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::client::connection::Connection;
+use crate::client::delta;
+use crate::client::exit::Exit;
+use crate::client::gamelog::Gamelog;
+use crate::client::outcome::GameOutcome;
+use crate::client::settings::{AiSettings, GameSetting};
+use crate::error::Error;
+
+use super::FEATURES;
+
+use serde_json::Value;
+
+/// An AI that is ready to play its game, with the game's type erased.
+///
+/// Each game module provides one of these through its `register` function.
+pub trait Session {
+    /// The name of the game the AI plays, as resolved by the server.
+    fn game_name(&self) -> &str;
+
+    /// The settings the game accepts, besides the base settings every game accepts.
+    fn settings(&self) -> &'static [GameSetting];
+
+    /// The name of the player the AI wants to play as.
+    fn player_name(&self) -> String;
+
+    /// Plays the game until it is over, starting from the first delta sent by the server.
+    ///
+    /// `player_id` is the id of the AI's player, as given in the "start" event. The AI can read
+    /// `ai_settings` throughout the game.
+    fn play(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+        player_id: &str,
+        ai_settings: AiSettings,
+    ) -> Result<GameOutcome, Error>;
+
+    /// Replays a gamelog offline, showing the AI the game from the view of the player with the
+    /// given index.
+    fn replay(self: Box<Self>, gamelog: &Gamelog, player_index: usize) -> Result<(), Error>;
+}
+
+/// A spectator that is ready to watch its game, with the game's type erased.
+///
+/// Each game module provides one of these through its `printer` function, and its `spectate`
+/// function watches a game with one.
+pub trait Spectator {
+    /// The name of the game being watched, as resolved by the server.
+    fn game_name(&self) -> &str;
+
+    /// Watches the game until it is over, starting from the first delta sent by the server.
+    fn watch(
+        self: Box<Self>,
+        connection: Arc<Mutex<Connection>>,
+        delta: delta::Context,
+        initial_delta: HashMap<String, Value>,
+    ) -> Result<(), Error>;
+
+    /// Replays a gamelog offline, showing the spectator each change in state.
+    fn replay(self: Box<Self>, gamelog: &Gamelog) -> Result<(), Error>;
+}
+
+/// Maps the names of games on the server to the AIs that play them.
+#[derive(Default)]
+pub struct Registry {
+    games: HashMap<String, Box<dyn Fn() -> Box<dyn Session>>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds a game, replacing any other AI registered for it.
+    ///
+    /// `new_session` is called once each time the game is played.
+    pub fn insert<F>(&mut self, game_name: &str, new_session: F)
+    where
+        F: Fn() -> Box<dyn Session> + 'static,
+    {
+        self.games
+            .insert(game_name.to_string(), Box::new(new_session));
+    }
+
+    pub fn contains(&self, game_name: &str) -> bool {
+        self.games.contains_key(game_name)
+    }
+
+    pub fn game_names(&self) -> impl Iterator<Item = &str> {
+        self.games.keys().map(String::as_str)
+    }
+
+    /// Creates a session for the game with the given name, as resolved by the server.
+    ///
+    /// Fails with [`Exit::GameNotFound`] if no AI has been registered for it, including when its
+    /// module was left out of the build.
+    pub fn session(&self, game_name: &str) -> Result<Box<dyn Session>, Error> {
+        match self.games.get(game_name) {
+            Some(new_session) => Ok(new_session()),
+            None => Err(not_found(game_name, "no AI is registered for")),
+        }
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.game_names()).finish()
+    }
+}
+
+/// Reports a game that can't be played or watched as [`Exit::GameNotFound`], pointing out the
+/// feature to enable if its module was left out of the build, and giving `reason` otherwise.
+pub(crate) fn not_found(game_name: &str, reason: &str) -> Error {
+    let disabled = FEATURES
+        .iter()
+        .find(|(name, _, enabled)| *name == game_name && !enabled);
+    let message = match disabled {
+        Some((_, feature, _)) => format!(
+            "game {:?} is not compiled in, enable the {:?} feature of joueur to play it",
+            game_name, feature,
+        ),
+        None => format!("{} game {:?}", reason, game_name),
+    };
+    (Exit::GameNotFound, message).into()
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A person on the map that can move around and interact within the saloon.
 #[derive(Debug, Clone)]
@@ -19,53 +31,56 @@ impl Cowboy {
         Cowboy { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// How much health this Cowboy currently has.
     pub fn health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .health.clone()
+            .health
     }
 
     /// The Player that owns and can control this Cowboy.
     pub fn owner(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
             .owner.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// If this Cowboy is dead and has been removed from the game.
     pub fn is_dead(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .is_dead.clone()
+            .is_dead
     }
 
     /// The job that this Cowboy does, and dictates how they fight and interact within the Saloon.
-    pub fn job(&self) -> Str {
-        self.inner.lock().unwrap()
+    pub fn job(&self) -> Job {
+        lock(&self.inner)
             .as_cowboy()
             .job.clone()
     }
 
     /// If the Cowboy can be moved this turn via its owner.
     pub fn can_move(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .can_move.clone()
+            .can_move
     }
 
     /// The Tile that this Cowboy is located on.
     pub fn tile(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
             .tile.clone()
     }
@@ -73,39 +88,39 @@ impl Cowboy {
     /// How much focus this Cowboy has. Different Jobs do different things with their Cowboy's
     /// focus.
     pub fn focus(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .focus.clone()
+            .focus
     }
 
     /// If this Cowboy is drunk, and will automatically walk.
     pub fn is_drunk(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .is_drunk.clone()
+            .is_drunk
     }
 
     /// The direction this Cowboy is moving while drunk. Will be 'North', 'East', 'South', or
     /// 'West' when drunk; or '' (empty string) when not drunk.
-    pub fn drunk_direction(&self) -> Str {
-        self.inner.lock().unwrap()
+    pub fn drunk_direction(&self) -> Direction {
+        lock(&self.inner)
             .as_cowboy()
             .drunk_direction.clone()
     }
 
     /// How many times this unit has been drunk before taking their siesta and reseting this to 0.
     pub fn tolerance(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .tolerance.clone()
+            .tolerance
     }
 
     /// How many turns this unit has remaining before it is no longer busy and can `act()` or
     /// `play()` again.
     pub fn turns_busy(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_cowboy()
-            .turns_busy.clone()
+            .turns_busy
     }
 
     /// _Inherited from [`GameObject`]_
@@ -113,7 +128,7 @@ impl Cowboy {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -124,7 +139,7 @@ impl Cowboy {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -133,7 +148,7 @@ impl Cowboy {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -153,8 +168,11 @@ impl Cowboy {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -179,8 +197,11 @@ impl Cowboy {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             piano: &'a Furnishing,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -197,7 +218,7 @@ impl Cowboy {
     /// - _tile_ - The Tile you want this Cowboy to act on.
     ///
     /// - _drunk_direction_ - The direction the bottle will cause drunk cowboys to be in, can be
-    /// 'North', 'East', 'South', or 'West'.
+    ///   'North', 'East', 'South', or 'West'.
     ///
     /// # Returns
     ///
@@ -205,13 +226,16 @@ impl Cowboy {
     pub fn act(
         &self,
         tile: &Tile,
-        drunk_direction: &str,
+        drunk_direction: Direction,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            drunk_direction: &'a str,
+            drunk_direction: Direction,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -236,8 +260,11 @@ impl Cowboy {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -247,21 +274,87 @@ impl Cowboy {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Cowboy {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Cowboy {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Cowboy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Cowboy {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cowboy {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Cowboy {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_cowboy().is_some() {
             Some(Cowboy {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -270,3 +363,47 @@ impl inner::ObjectInner for Cowboy {
     }
 }
 impl Object for Cowboy {}
+
+impl Serialize for Cowboy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Cowboy {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Cowboy {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Cowboy {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -0,0 +1,173 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - the elements of [`Game::jobs`](super::Game::jobs)
+/// - [`Cowboy::job`](super::Cowboy::job)
+/// - the `job` argument of [`YoungGun::call_in`](super::YoungGun::call_in)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Job {
+    /// `"Bartender"`
+    Bartender,
+    /// `"Brawler"`
+    Brawler,
+    /// `"Sharpshooter"`
+    Sharpshooter,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Job {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Job::Bartender => "Bartender",
+            Job::Brawler => "Brawler",
+            Job::Sharpshooter => "Sharpshooter",
+            Job::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Job {
+    fn from(s: &str) -> Job {
+        match s {
+            "Bartender" => Job::Bartender,
+            "Brawler" => Job::Brawler,
+            "Sharpshooter" => Job::Sharpshooter,
+            other => Job::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Job {
+    fn default() -> Job {
+        Job::from("")
+    }
+}
+
+impl fmt::Display for Job {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Job {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Job {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Job::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Job {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Cowboy::drunk_direction`](super::Cowboy::drunk_direction)
+/// - the `drunk_direction` argument of [`Cowboy::act`](super::Cowboy::act)
+/// - [`Bottle::direction`](super::Bottle::direction)
+/// - [`Bottle::drunk_direction`](super::Bottle::drunk_direction)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// `""`
+    Empty,
+    /// `"North"`
+    North,
+    /// `"East"`
+    East,
+    /// `"South"`
+    South,
+    /// `"West"`
+    West,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Direction::Empty => "",
+            Direction::North => "North",
+            Direction::East => "East",
+            Direction::South => "South",
+            Direction::West => "West",
+            Direction::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Direction {
+    fn from(s: &str) -> Direction {
+        match s {
+            "" => Direction::Empty,
+            "North" => Direction::North,
+            "East" => Direction::East,
+            "South" => Direction::South,
+            "West" => Direction::West,
+            other => Direction::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::from("")
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Direction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Direction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Direction::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Direction {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
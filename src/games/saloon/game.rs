@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// Holds top-level game state and settings for the current game.
 #[derive(Debug, Clone)]
@@ -19,141 +31,229 @@ impl Game {
         Game { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// A mapping of every game object's ID to the actual game object. Primarily used by the server
     /// and client to easily refer to the game objects via ID.
     pub fn game_objects(&self) -> Map<Str, GameObject> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .game_objects.clone()
     }
 
     /// List of all the players in the game.
     pub fn players(&self) -> List<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .players.clone()
     }
 
     /// A unique identifier for the game instance that is being played.
     pub fn session(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .session.clone()
     }
 
     /// The player whose turn it is currently. That player can send commands. Other players cannot.
     pub fn current_player(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .current_player.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The current turn number, starting at 0 for the first player's turn.
     pub fn current_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .current_turn.clone()
+        lock(&self.inner)
+            .current_turn
     }
 
     /// The maximum number of turns before the game will automatically end.
     pub fn max_turns(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_turns.clone()
+        lock(&self.inner)
+            .max_turns
     }
 
     /// The amount of time (in nano-seconds) added after each player performs a turn.
     pub fn time_added_per_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .time_added_per_turn.clone()
+        lock(&self.inner)
+            .time_added_per_turn
     }
 
     /// The number of Tiles in the map along the x (horizontal) axis.
     pub fn map_width(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_width.clone()
+        lock(&self.inner)
+            .map_width
     }
 
     /// The number of Tiles in the map along the y (vertical) axis.
     pub fn map_height(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .map_height.clone()
+        lock(&self.inner)
+            .map_height
     }
 
     /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
     /// correct index.
     pub fn tiles(&self) -> List<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .tiles.clone()
     }
 
     /// Every Cowboy in the game.
     pub fn cowboys(&self) -> List<Cowboy> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .cowboys.clone()
     }
 
     /// Every furnishing in the game.
     pub fn furnishings(&self) -> List<Furnishing> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .furnishings.clone()
     }
 
     /// All the jobs that Cowboys can be called in with.
-    pub fn jobs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+    pub fn jobs(&self) -> List<Job> {
+        lock(&self.inner)
             .jobs.clone()
     }
 
     /// All the beer Bottles currently flying across the saloon in the game.
     pub fn bottles(&self) -> List<Bottle> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .bottles.clone()
     }
 
     /// When a player's rowdiness reaches or exceeds this number their Cowboys take a collective
     /// siesta.
     pub fn rowdiness_to_siesta(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .rowdiness_to_siesta.clone()
+        lock(&self.inner)
+            .rowdiness_to_siesta
     }
 
     /// How long siestas are for a player's team.
     pub fn siesta_length(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .siesta_length.clone()
+        lock(&self.inner)
+            .siesta_length
     }
 
     /// The maximum number of Cowboys a Player can bring into the saloon of each specific job.
     pub fn max_cowboys_per_job(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_cowboys_per_job.clone()
+        lock(&self.inner)
+            .max_cowboys_per_job
     }
 
     /// How much damage is applied to things hit by Sharpshooters when they act.
     pub fn sharpshooter_damage(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .sharpshooter_damage.clone()
+        lock(&self.inner)
+            .sharpshooter_damage
     }
 
     /// How much damage is applied to neighboring things bit by the Sharpshooter between turns.
     pub fn brawler_damage(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .brawler_damage.clone()
+        lock(&self.inner)
+            .brawler_damage
     }
 
     /// How many turns a Cowboy will be drunk for if a bottle breaks on it.
     pub fn turns_drunk(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .turns_drunk.clone()
+        lock(&self.inner)
+            .turns_drunk
     }
 
     /// How many turns a Bartender will be busy for after throwing a Bottle.
     pub fn bartender_cooldown(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .bartender_cooldown.clone()
+        lock(&self.inner)
+            .bartender_cooldown
+    }
+
+    /// Looks up a game object by its id.
+    ///
+    /// Returns `None` if there is no such object, if it has been removed from the game, or if it
+    /// is not a `T`.
+    pub fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.inner).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// The settings given to the AI for this game, like `--aiSettings` on the command line.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn ai_settings(&self) -> Result<AiSettings, Error> {
+        self.with_context(|cx| Ok(cx.ai_settings().clone()))
+    }
+
+    /// Takes an immutable copy of the whole game state, which can be read without locking.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        self.with_context(|cx| Ok(cx.snapshot()))
+    }
+}
+
+/// Handles are equal when they refer to the same game, regardless of its state.
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Game {}
+
+/// Handles hash by the identity of the game they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+impl AnyGame for Game {
+    type GameObject = GameObject;
+    type Player = Player;
+
+    fn session(&self) -> Str {
+        self.session()
+    }
+
+    fn players(&self) -> List<Player> {
+        self.players()
+    }
+
+    fn game_objects(&self) -> Map<Str, GameObject> {
+        self.game_objects()
+    }
+}
+
+impl TurnBasedGame for Game {
+    fn current_turn(&self) -> i64 {
+        self.current_turn()
+    }
+
+    fn max_turns(&self) -> i64 {
+        self.max_turns()
+    }
+}
+
+impl GridGame for Game {
+    type Tile = Tile;
+
+    fn map_width(&self) -> i64 {
+        self.map_width()
+    }
+
+    fn map_height(&self) -> i64 {
+        self.map_height()
+    }
+
+    fn tiles(&self) -> List<Tile> {
+        self.tiles()
     }
 }
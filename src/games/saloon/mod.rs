@@ -3,6 +3,9 @@
 
 mod inner;
 
+mod ai;
+pub mod snapshot;
+mod enums;
 mod bottle;
 mod cowboy;
 mod furnishing;
@@ -21,4 +24,125 @@ pub use tile::Tile;
 pub use young_gun::YoungGun;
 pub use game::Game;
 
+pub use enums::{Job, Direction};
+
+pub use ai::AI;
+
+pub use snapshot::Snapshot;
+
 pub use inner::Object;
+
+pub use crate::client::delta::Changes;
+
+use crate::client::gamelog::Gamelog;
+use crate::client::settings::{GameSetting, SettingKind};
+use crate::client::{Client, GameOutcome};
+use crate::error::Error;
+use crate::games::{print_changes, Registry, Spectator};
+
+/// The name of this game on the server.
+pub const GAME_NAME: &str = "Saloon";
+
+/// The settings the server accepts for this game, besides the
+/// [`BASE_SETTINGS`](crate::client::settings::BASE_SETTINGS) every game accepts.
+///
+/// Each sets the initial value of the [`Game`] attribute of the same name.
+pub const SETTINGS: &[GameSetting] = &[
+    GameSetting {
+        name: "maxTurns",
+        kind: SettingKind::Int,
+        description: "The maximum number of turns before the game will automatically end.",
+    },
+    GameSetting {
+        name: "mapWidth",
+        kind: SettingKind::Int,
+        description: "The number of Tiles in the map along the x (horizontal) axis.",
+    },
+    GameSetting {
+        name: "mapHeight",
+        kind: SettingKind::Int,
+        description: "The number of Tiles in the map along the y (vertical) axis.",
+    },
+    GameSetting {
+        name: "rowdinessToSiesta",
+        kind: SettingKind::Int,
+        description: "When a player's rowdiness reaches or exceeds this number their Cowboys take a collective siesta.",
+    },
+    GameSetting {
+        name: "siestaLength",
+        kind: SettingKind::Int,
+        description: "How long siestas are for a player's team.",
+    },
+    GameSetting {
+        name: "maxCowboysPerJob",
+        kind: SettingKind::Int,
+        description: "The maximum number of Cowboys a Player can bring into the saloon of each specific job.",
+    },
+    GameSetting {
+        name: "sharpshooterDamage",
+        kind: SettingKind::Int,
+        description: "How much damage is applied to things hit by Sharpshooters when they act.",
+    },
+    GameSetting {
+        name: "brawlerDamage",
+        kind: SettingKind::Int,
+        description: "How much damage is applied to neighboring things bit by the Sharpshooter between turns.",
+    },
+    GameSetting {
+        name: "turnsDrunk",
+        kind: SettingKind::Int,
+        description: "How many turns a Cowboy will be drunk for if a bottle breaks on it.",
+    },
+    GameSetting {
+        name: "bartenderCooldown",
+        kind: SettingKind::Int,
+        description: "How many turns a Bartender will be busy for after throwing a Bottle.",
+    },
+];
+
+/// Registers an AI for this game. `new_ai` is called to create a fresh AI for each game played.
+pub fn register<A, F>(registry: &mut Registry, new_ai: F)
+where
+    A: AI + 'static,
+    F: Fn() -> A + 'static,
+{
+    registry.insert(GAME_NAME, move || Box::new(inner::Pending::new(new_ai())));
+}
+
+/// Plays one game of this game on the server the client is configured for, with the given AI.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the server
+/// resolves the client's game to a different game.
+pub fn play<A: AI + 'static>(client: &Client, ai: A) -> Result<GameOutcome, Error> {
+    client.play_session(Box::new(inner::Pending::new(ai)))
+}
+
+/// Watches one game of this game on the server the client is configured for, without playing.
+///
+/// `on_update` is called with the whole initial state as created, and then each time the state
+/// changes, until the game is over. Fails like [`play`] does if the server resolves the
+/// client's game to a different game.
+pub fn spectate<F>(client: &Client, on_update: F) -> Result<(), Error>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error> + 'static,
+{
+    client.spectate_session(Box::new(inner::Watching::new(on_update)))
+}
+
+/// A spectator that prints what changes in the game with [`print_changes`], as used by
+/// `joueur --spectate`.
+pub fn printer() -> Box<dyn Spectator> {
+    Box::new(inner::Watching::new(|game: &Game, changes: &Changes| {
+        print_changes(game, changes);
+        Ok(())
+    }))
+}
+
+/// Replays a gamelog of this game offline, calling the AI's hooks as the state changes.
+///
+/// The AI sees the game from the view of the player with the given index. No orders are given,
+/// and game object functions fail since there is no server to run them. The AI is returned
+/// afterwards, so that anything it recorded along the way can be inspected.
+pub fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, ai: A) -> Result<A, Error> {
+    inner::replay(gamelog, player_index, ai)
+}
@@ -0,0 +1,997 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{Job, Direction};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Cowboy`](super::Cowboy) at the time of this snapshot.
+    pub fn cowboy(&self, obj: &super::Cowboy) -> Option<Cowboy<'_>> {
+        Cowboy::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Furnishing`](super::Furnishing) at the time of this snapshot.
+    pub fn furnishing(&self, obj: &super::Furnishing) -> Option<Furnishing<'_>> {
+        Furnishing::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Bottle`](super::Bottle) at the time of this snapshot.
+    pub fn bottle(&self, obj: &super::Bottle) -> Option<Bottle<'_>> {
+        Bottle::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`YoungGun`](super::YoungGun) at the time of this snapshot.
+    pub fn young_gun(&self, obj: &super::YoungGun) -> Option<YoungGun<'_>> {
+        YoungGun::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Cowboy in the game.
+    pub fn cowboys(&self) -> Vec<Cowboy<'a>> {
+        self.snapshot.game.cowboys
+            .iter()
+            .filter_map(|obj| Cowboy::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every furnishing in the game.
+    pub fn furnishings(&self) -> Vec<Furnishing<'a>> {
+        self.snapshot.game.furnishings
+            .iter()
+            .filter_map(|obj| Furnishing::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the jobs that Cowboys can be called in with.
+    pub fn jobs(&self) -> &'a List<Job> {
+        &self.snapshot.game.jobs
+    }
+
+    /// All the beer Bottles currently flying across the saloon in the game.
+    pub fn bottles(&self) -> Vec<Bottle<'a>> {
+        self.snapshot.game.bottles
+            .iter()
+            .filter_map(|obj| Bottle::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// When a player's rowdiness reaches or exceeds this number their Cowboys take a collective
+    /// siesta.
+    pub fn rowdiness_to_siesta(&self) -> i64 {
+        self.snapshot.game.rowdiness_to_siesta
+    }
+
+    /// How long siestas are for a player's team.
+    pub fn siesta_length(&self) -> i64 {
+        self.snapshot.game.siesta_length
+    }
+
+    /// The maximum number of Cowboys a Player can bring into the saloon of each specific job.
+    pub fn max_cowboys_per_job(&self) -> i64 {
+        self.snapshot.game.max_cowboys_per_job
+    }
+
+    /// How much damage is applied to things hit by Sharpshooters when they act.
+    pub fn sharpshooter_damage(&self) -> i64 {
+        self.snapshot.game.sharpshooter_damage
+    }
+
+    /// How much damage is applied to neighboring things bit by the Sharpshooter between turns.
+    pub fn brawler_damage(&self) -> i64 {
+        self.snapshot.game.brawler_damage
+    }
+
+    /// How many turns a Cowboy will be drunk for if a bottle breaks on it.
+    pub fn turns_drunk(&self) -> i64 {
+        self.snapshot.game.turns_drunk
+    }
+
+    /// How many turns a Bartender will be busy for after throwing a Bottle.
+    pub fn bartender_cooldown(&self) -> i64 {
+        self.snapshot.game.bartender_cooldown
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// How many times their team has played a piano.
+    pub fn score(&self) -> i64 {
+        self.state.as_player().score
+    }
+
+    /// How rowdy their team is. When it gets too high their team takes a collective siesta.
+    pub fn rowdiness(&self) -> i64 {
+        self.state.as_player().rowdiness
+    }
+
+    /// How many enemy Cowboys this player's team has killed.
+    pub fn kills(&self) -> i64 {
+        self.state.as_player().kills
+    }
+
+    /// Every Cowboy owned by this Player.
+    pub fn cowboys(&self) -> Vec<Cowboy<'a>> {
+        self.state.as_player().cowboys
+            .iter()
+            .filter_map(|obj| Cowboy::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The YoungGun this Player uses to call in new Cowboys.
+    pub fn young_gun(&self) -> Option<YoungGun<'a>> {
+        self.state.as_player().young_gun
+            .as_ref()
+            .and_then(|obj| YoungGun::resolve(self.snapshot, obj))
+    }
+
+    /// 0 when not having a team siesta. When greater than 0 represents how many turns left for the
+    /// team siesta to complete.
+    pub fn siesta(&self) -> i64 {
+        self.state.as_player().siesta
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Cowboy that is on this Tile, None otherwise.
+    pub fn cowboy(&self) -> Option<Cowboy<'a>> {
+        self.state.as_tile().cowboy
+            .as_ref()
+            .and_then(|obj| Cowboy::resolve(self.snapshot, obj))
+    }
+
+    /// The furnishing that is on this Tile, None otherwise.
+    pub fn furnishing(&self) -> Option<Furnishing<'a>> {
+        self.state.as_tile().furnishing
+            .as_ref()
+            .and_then(|obj| Furnishing::resolve(self.snapshot, obj))
+    }
+
+    /// If this Tile is a balcony of the Saloon that YoungGuns walk around on, and can never be
+    /// pathed through by Cowboys.
+    pub fn is_balcony(&self) -> bool {
+        self.state.as_tile().is_balcony
+    }
+
+    /// If this Tile is pathable, but has a hazard that damages Cowboys that path through it.
+    pub fn has_hazard(&self) -> bool {
+        self.state.as_tile().has_hazard
+    }
+
+    /// The beer Bottle currently flying over this Tile, None otherwise.
+    pub fn bottle(&self) -> Option<Bottle<'a>> {
+        self.state.as_tile().bottle
+            .as_ref()
+            .and_then(|obj| Bottle::resolve(self.snapshot, obj))
+    }
+
+    /// The YoungGun on this tile, None otherwise.
+    pub fn young_gun(&self) -> Option<YoungGun<'a>> {
+        self.state.as_tile().young_gun
+            .as_ref()
+            .and_then(|obj| YoungGun::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Cowboy`](super::Cowboy) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Cowboy<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Cowboy<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Cowboy<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_cowboy()?;
+        Some(Cowboy { snapshot, state })
+    }
+
+    /// How much health this Cowboy currently has.
+    pub fn health(&self) -> i64 {
+        self.state.as_cowboy().health
+    }
+
+    /// The Player that owns and can control this Cowboy.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_cowboy().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// If this Cowboy is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_cowboy().is_dead
+    }
+
+    /// The job that this Cowboy does, and dictates how they fight and interact within the Saloon.
+    pub fn job(&self) -> &'a Job {
+        &self.state.as_cowboy().job
+    }
+
+    /// If the Cowboy can be moved this turn via its owner.
+    pub fn can_move(&self) -> bool {
+        self.state.as_cowboy().can_move
+    }
+
+    /// The Tile that this Cowboy is located on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_cowboy().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// How much focus this Cowboy has. Different Jobs do different things with their Cowboy's
+    /// focus.
+    pub fn focus(&self) -> i64 {
+        self.state.as_cowboy().focus
+    }
+
+    /// If this Cowboy is drunk, and will automatically walk.
+    pub fn is_drunk(&self) -> bool {
+        self.state.as_cowboy().is_drunk
+    }
+
+    /// The direction this Cowboy is moving while drunk. Will be 'North', 'East', 'South', or
+    /// 'West' when drunk; or '' (empty string) when not drunk.
+    pub fn drunk_direction(&self) -> &'a Direction {
+        &self.state.as_cowboy().drunk_direction
+    }
+
+    /// How many times this unit has been drunk before taking their siesta and reseting this to 0.
+    pub fn tolerance(&self) -> i64 {
+        self.state.as_cowboy().tolerance
+    }
+
+    /// How many turns this unit has remaining before it is no longer busy and can `act()` or
+    /// `play()` again.
+    pub fn turns_busy(&self) -> i64 {
+        self.state.as_cowboy().turns_busy
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Cowboy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cowboy").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Cowboy<'a>> for GameObject<'a> {
+    fn from(obj: Cowboy<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Cowboy<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Cowboy<'a>, GameObject<'a>> {
+        match obj.state.try_as_cowboy() {
+            Some(_) => Ok(Cowboy {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Furnishing`](super::Furnishing) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Furnishing<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Furnishing<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Furnishing<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_furnishing()?;
+        Some(Furnishing { snapshot, state })
+    }
+
+    /// True if this Furnishing is a piano and can be played, False otherwise.
+    pub fn is_piano(&self) -> bool {
+        self.state.as_furnishing().is_piano
+    }
+
+    /// The Tile that this Furnishing is located on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_furnishing().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// How much health this Furnishing currently has.
+    pub fn health(&self) -> i64 {
+        self.state.as_furnishing().health
+    }
+
+    /// If this Furnishing has been destroyed, and has been removed from the game.
+    pub fn is_destroyed(&self) -> bool {
+        self.state.as_furnishing().is_destroyed
+    }
+
+    /// If this is a piano and a Cowboy is playing it this turn.
+    pub fn is_playing(&self) -> bool {
+        self.state.as_furnishing().is_playing
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Furnishing<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Furnishing").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Furnishing<'a>> for GameObject<'a> {
+    fn from(obj: Furnishing<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Furnishing<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Furnishing<'a>, GameObject<'a>> {
+        match obj.state.try_as_furnishing() {
+            Some(_) => Ok(Furnishing {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Bottle`](super::Bottle) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Bottle<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Bottle<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Bottle<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_bottle()?;
+        Some(Bottle { snapshot, state })
+    }
+
+    /// The Tile this bottle is currently flying over.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_bottle().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Direction this Bottle is flying and will move to between turns, can be 'North', 'East',
+    /// 'South', or 'West'.
+    pub fn direction(&self) -> &'a Direction {
+        &self.state.as_bottle().direction
+    }
+
+    /// True if this Bottle has impacted and has been destroyed (removed from the Game). False if
+    /// still in the game flying through the saloon.
+    pub fn is_destroyed(&self) -> bool {
+        self.state.as_bottle().is_destroyed
+    }
+
+    /// The direction any Cowboys hit by this will move, can be 'North', 'East', 'South', or
+    /// 'West'.
+    pub fn drunk_direction(&self) -> &'a Direction {
+        &self.state.as_bottle().drunk_direction
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Bottle<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Bottle").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Bottle<'a>> for GameObject<'a> {
+    fn from(obj: Bottle<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Bottle<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Bottle<'a>, GameObject<'a>> {
+        match obj.state.try_as_bottle() {
+            Some(_) => Ok(Bottle {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`YoungGun`](super::YoungGun) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct YoungGun<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> YoungGun<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<YoungGun<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_young_gun()?;
+        Some(YoungGun { snapshot, state })
+    }
+
+    /// The Player that owns and can control this YoungGun.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_young_gun().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile this YoungGun is currently on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_young_gun().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// True if the YoungGun can call in a Cowboy, false otherwise.
+    pub fn can_call_in(&self) -> bool {
+        self.state.as_young_gun().can_call_in
+    }
+
+    /// The Tile that a Cowboy will be called in on if this YoungGun calls in a Cowboy.
+    pub fn call_in_tile(&self) -> Option<Tile<'a>> {
+        self.state.as_young_gun().call_in_tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for YoungGun<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("YoungGun").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<YoungGun<'a>> for GameObject<'a> {
+    fn from(obj: YoungGun<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for YoungGun<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<YoungGun<'a>, GameObject<'a>> {
+        match obj.state.try_as_young_gun() {
+            Some(_) => Ok(YoungGun {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -0,0 +1,85 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `spiderling_type` argument of [`BroodMother::spawn`](super::BroodMother::spawn)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpiderlingType {
+    /// `"Spitter"`
+    Spitter,
+    /// `"Weaver"`
+    Weaver,
+    /// `"Cutter"`
+    Cutter,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl SpiderlingType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SpiderlingType::Spitter => "Spitter",
+            SpiderlingType::Weaver => "Weaver",
+            SpiderlingType::Cutter => "Cutter",
+            SpiderlingType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for SpiderlingType {
+    fn from(s: &str) -> SpiderlingType {
+        match s {
+            "Spitter" => SpiderlingType::Spitter,
+            "Weaver" => SpiderlingType::Weaver,
+            "Cutter" => SpiderlingType::Cutter,
+            other => SpiderlingType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for SpiderlingType {
+    fn default() -> SpiderlingType {
+        SpiderlingType::from("")
+    }
+}
+
+impl fmt::Display for SpiderlingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SpiderlingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SpiderlingType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| SpiderlingType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for SpiderlingType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
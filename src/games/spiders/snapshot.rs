@@ -0,0 +1,1445 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::SpiderlingType;
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Nest`](super::Nest) at the time of this snapshot.
+    pub fn nest(&self, obj: &super::Nest) -> Option<Nest<'_>> {
+        Nest::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Web`](super::Web) at the time of this snapshot.
+    pub fn web(&self, obj: &super::Web) -> Option<Web<'_>> {
+        Web::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Spider`](super::Spider) at the time of this snapshot.
+    pub fn spider(&self, obj: &super::Spider) -> Option<Spider<'_>> {
+        Spider::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`BroodMother`](super::BroodMother) at the time of this snapshot.
+    pub fn brood_mother(&self, obj: &super::BroodMother) -> Option<BroodMother<'_>> {
+        BroodMother::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Spiderling`](super::Spiderling) at the time of this snapshot.
+    pub fn spiderling(&self, obj: &super::Spiderling) -> Option<Spiderling<'_>> {
+        Spiderling::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Spitter`](super::Spitter) at the time of this snapshot.
+    pub fn spitter(&self, obj: &super::Spitter) -> Option<Spitter<'_>> {
+        Spitter::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Weaver`](super::Weaver) at the time of this snapshot.
+    pub fn weaver(&self, obj: &super::Weaver) -> Option<Weaver<'_>> {
+        Weaver::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Cutter`](super::Cutter) at the time of this snapshot.
+    pub fn cutter(&self, obj: &super::Cutter) -> Option<Cutter<'_>> {
+        Cutter::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// Every Nest in the game.
+    pub fn nests(&self) -> Vec<Nest<'a>> {
+        self.snapshot.game.nests
+            .iter()
+            .filter_map(|obj| Nest::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Web in the game.
+    pub fn webs(&self) -> Vec<Web<'a>> {
+        self.snapshot.game.webs
+            .iter()
+            .filter_map(|obj| Web::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The speed at which Spiderlings move on Webs.
+    pub fn movement_speed(&self) -> i64 {
+        self.snapshot.game.movement_speed
+    }
+
+    /// The speed at which Weavers work to do strengthens and weakens on Webs.
+    pub fn weave_speed(&self) -> i64 {
+        self.snapshot.game.weave_speed
+    }
+
+    /// The speed at which Cutters work to do cut Webs.
+    pub fn cut_speed(&self) -> i64 {
+        self.snapshot.game.cut_speed
+    }
+
+    /// The speed at which Spitters work to spit new Webs.
+    pub fn spit_speed(&self) -> i64 {
+        self.snapshot.game.spit_speed
+    }
+
+    /// How much web strength is added or removed from Webs when they are weaved.
+    pub fn weave_power(&self) -> i64 {
+        self.snapshot.game.weave_power
+    }
+
+    /// The starting strength for Webs.
+    pub fn initial_web_strength(&self) -> i64 {
+        self.snapshot.game.initial_web_strength
+    }
+
+    /// The maximum strength a web can be strengthened to.
+    pub fn max_web_strength(&self) -> i64 {
+        self.snapshot.game.max_web_strength
+    }
+
+    /// Constant used to calculate how many eggs BroodMothers get on their owner's turns.
+    pub fn eggs_scalar(&self) -> f64 {
+        self.snapshot.game.eggs_scalar
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// All the Spiders owned by this player.
+    pub fn spiders(&self) -> Vec<Spider<'a>> {
+        self.state.as_player().spiders
+            .iter()
+            .filter_map(|obj| Spider::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// This player's BroodMother. If it dies they lose the game.
+    pub fn brood_mother(&self) -> Option<BroodMother<'a>> {
+        self.state.as_player().brood_mother
+            .as_ref()
+            .and_then(|obj| BroodMother::resolve(self.snapshot, obj))
+    }
+
+    /// The max number of Spiderlings players can spawn.
+    pub fn max_spiderlings(&self) -> i64 {
+        self.state.as_player().max_spiderlings
+    }
+
+    /// The number of nests this player controls.
+    pub fn number_of_nests_controlled(&self) -> i64 {
+        self.state.as_player().number_of_nests_controlled
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Nest`](super::Nest) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Nest<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Nest<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Nest<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_nest()?;
+        Some(Nest { snapshot, state })
+    }
+
+    /// Webs that connect to this Nest.
+    pub fn webs(&self) -> Vec<Web<'a>> {
+        self.state.as_nest().webs
+            .iter()
+            .filter_map(|obj| Web::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the Spiders currently located on this Nest.
+    pub fn spiders(&self) -> Vec<Spider<'a>> {
+        self.state.as_nest().spiders
+            .iter()
+            .filter_map(|obj| Spider::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The Player that 'controls' this Nest as they have the most Spiders on this nest.
+    pub fn controlling_player(&self) -> Option<Player<'a>> {
+        self.state.as_nest().controlling_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The X coordinate of the Nest. Used for distance calculations.
+    pub fn x(&self) -> i64 {
+        self.state.as_nest().x
+    }
+
+    /// The Y coordinate of the Nest. Used for distance calculations.
+    pub fn y(&self) -> i64 {
+        self.state.as_nest().y
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Nest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Nest").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Nest<'a>> for GameObject<'a> {
+    fn from(obj: Nest<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Nest<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Nest<'a>, GameObject<'a>> {
+        match obj.state.try_as_nest() {
+            Some(_) => Ok(Nest {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Web`](super::Web) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Web<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Web<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Web<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_web()?;
+        Some(Web { snapshot, state })
+    }
+
+    /// The first Nest this Web is connected to.
+    pub fn nest_a(&self) -> Option<Nest<'a>> {
+        self.state.as_web().nest_a
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// The second Nest this Web is connected to.
+    pub fn nest_b(&self) -> Option<Nest<'a>> {
+        self.state.as_web().nest_b
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// All the Spiderlings currently moving along this Web.
+    pub fn spiderlings(&self) -> Vec<Spiderling<'a>> {
+        self.state.as_web().spiderlings
+            .iter()
+            .filter_map(|obj| Spiderling::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// How much weight this Web can take before snapping and destroying itself and all the Spiders
+    /// on it.
+    pub fn strength(&self) -> i64 {
+        self.state.as_web().strength
+    }
+
+    /// How much weight this Web currently has on it, which is the sum of all its Spiderlings
+    /// weight.
+    pub fn load(&self) -> i64 {
+        self.state.as_web().load
+    }
+
+    /// How long this Web is, i.e., the distance between its nestA and nestB.
+    pub fn length(&self) -> f64 {
+        self.state.as_web().length
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Web<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Web").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Web<'a>> for GameObject<'a> {
+    fn from(obj: Web<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Web<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Web<'a>, GameObject<'a>> {
+        match obj.state.try_as_web() {
+            Some(_) => Ok(Web {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Spider`](super::Spider) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Spider<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Spider<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Spider<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_spider()?;
+        Some(Spider { snapshot, state })
+    }
+
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Spider<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Spider").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Spider<'a>> for GameObject<'a> {
+    fn from(obj: Spider<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Spider<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Spider<'a>, GameObject<'a>> {
+        match obj.state.try_as_spider() {
+            Some(_) => Ok(Spider {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`BroodMother`](super::BroodMother) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct BroodMother<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> BroodMother<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<BroodMother<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_brood_mother()?;
+        Some(BroodMother { snapshot, state })
+    }
+
+    /// How much health this BroodMother has left. When it reaches 0, she dies and her owner loses.
+    pub fn health(&self) -> i64 {
+        self.state.as_brood_mother().health
+    }
+
+    /// How many eggs the BroodMother has to spawn Spiderlings this turn.
+    pub fn eggs(&self) -> i64 {
+        self.state.as_brood_mother().eggs
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for BroodMother<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BroodMother").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<BroodMother<'a>> for Spider<'a> {
+    fn from(obj: BroodMother<'a>) -> Spider<'a> {
+        Spider {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<BroodMother<'a>> for GameObject<'a> {
+    fn from(obj: BroodMother<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for BroodMother<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<BroodMother<'a>, GameObject<'a>> {
+        match obj.state.try_as_brood_mother() {
+            Some(_) => Ok(BroodMother {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Spiderling`](super::Spiderling) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Spiderling<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Spiderling<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Spiderling<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_spiderling()?;
+        Some(Spiderling { snapshot, state })
+    }
+
+    /// When empty string this Spiderling is not busy, and can act. Otherwise a string representing
+    /// what it is busy with, e.g. 'Moving', 'Attacking'.
+    pub fn busy(&self) -> &'a Str {
+        &self.state.as_spiderling().busy
+    }
+
+    /// How much work needs to be done for this Spiderling to finish being busy. See docs for the
+    /// Work forumla.
+    pub fn work_remaining(&self) -> f64 {
+        self.state.as_spiderling().work_remaining
+    }
+
+    /// The number of Spiderlings busy with the same work this Spiderling is doing, speeding up the
+    /// task.
+    pub fn number_of_coworkers(&self) -> i64 {
+        self.state.as_spiderling().number_of_coworkers
+    }
+
+    /// The Web this Spiderling is using to move. None if it is not moving.
+    pub fn moving_on_web(&self) -> Option<Web<'a>> {
+        self.state.as_spiderling().moving_on_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// The Nest this Spiderling is moving to. None if it is not moving.
+    pub fn moving_to_nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spiderling().moving_to_nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Spiderling<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Spiderling").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Spiderling<'a>> for Spider<'a> {
+    fn from(obj: Spiderling<'a>) -> Spider<'a> {
+        Spider {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Spiderling<'a>> for GameObject<'a> {
+    fn from(obj: Spiderling<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Spiderling<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Spiderling<'a>, GameObject<'a>> {
+        match obj.state.try_as_spiderling() {
+            Some(_) => Ok(Spiderling {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Spitter`](super::Spitter) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Spitter<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Spitter<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Spitter<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_spitter()?;
+        Some(Spitter { snapshot, state })
+    }
+
+    /// The Nest that this Spitter is creating a Web to spit at, thus connecting them. None if not
+    /// spitting.
+    pub fn spitting_web_to_nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spitter().spitting_web_to_nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// When empty string this Spiderling is not busy, and can act. Otherwise a string representing
+    /// what it is busy with, e.g. 'Moving', 'Attacking'.
+    pub fn busy(&self) -> &'a Str {
+        &self.state.as_spiderling().busy
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// How much work needs to be done for this Spiderling to finish being busy. See docs for the
+    /// Work forumla.
+    pub fn work_remaining(&self) -> f64 {
+        self.state.as_spiderling().work_remaining
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The number of Spiderlings busy with the same work this Spiderling is doing, speeding up the
+    /// task.
+    pub fn number_of_coworkers(&self) -> i64 {
+        self.state.as_spiderling().number_of_coworkers
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Web this Spiderling is using to move. None if it is not moving.
+    pub fn moving_on_web(&self) -> Option<Web<'a>> {
+        self.state.as_spiderling().moving_on_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Nest this Spiderling is moving to. None if it is not moving.
+    pub fn moving_to_nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spiderling().moving_to_nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Spitter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Spitter").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Spitter<'a>> for Spiderling<'a> {
+    fn from(obj: Spitter<'a>) -> Spiderling<'a> {
+        Spiderling {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Spitter<'a>> for Spider<'a> {
+    fn from(obj: Spitter<'a>) -> Spider<'a> {
+        Spider {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Spitter<'a>> for GameObject<'a> {
+    fn from(obj: Spitter<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Spitter<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Spitter<'a>, GameObject<'a>> {
+        match obj.state.try_as_spitter() {
+            Some(_) => Ok(Spitter {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Weaver`](super::Weaver) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Weaver<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Weaver<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Weaver<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_weaver()?;
+        Some(Weaver { snapshot, state })
+    }
+
+    /// The Web that this Weaver is strengthening. None if not strengthening.
+    pub fn strengthening_web(&self) -> Option<Web<'a>> {
+        self.state.as_weaver().strengthening_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// The Web that this Weaver is weakening. None if not weakening.
+    pub fn weakening_web(&self) -> Option<Web<'a>> {
+        self.state.as_weaver().weakening_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// When empty string this Spiderling is not busy, and can act. Otherwise a string representing
+    /// what it is busy with, e.g. 'Moving', 'Attacking'.
+    pub fn busy(&self) -> &'a Str {
+        &self.state.as_spiderling().busy
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// How much work needs to be done for this Spiderling to finish being busy. See docs for the
+    /// Work forumla.
+    pub fn work_remaining(&self) -> f64 {
+        self.state.as_spiderling().work_remaining
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The number of Spiderlings busy with the same work this Spiderling is doing, speeding up the
+    /// task.
+    pub fn number_of_coworkers(&self) -> i64 {
+        self.state.as_spiderling().number_of_coworkers
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Web this Spiderling is using to move. None if it is not moving.
+    pub fn moving_on_web(&self) -> Option<Web<'a>> {
+        self.state.as_spiderling().moving_on_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Nest this Spiderling is moving to. None if it is not moving.
+    pub fn moving_to_nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spiderling().moving_to_nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Weaver<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Weaver").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Weaver<'a>> for Spiderling<'a> {
+    fn from(obj: Weaver<'a>) -> Spiderling<'a> {
+        Spiderling {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Weaver<'a>> for Spider<'a> {
+    fn from(obj: Weaver<'a>) -> Spider<'a> {
+        Spider {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Weaver<'a>> for GameObject<'a> {
+    fn from(obj: Weaver<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Weaver<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Weaver<'a>, GameObject<'a>> {
+        match obj.state.try_as_weaver() {
+            Some(_) => Ok(Weaver {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Cutter`](super::Cutter) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Cutter<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Cutter<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Cutter<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_cutter()?;
+        Some(Cutter { snapshot, state })
+    }
+
+    /// The Web that this Cutter is trying to cut. None if not cutting.
+    pub fn cutting_web(&self) -> Option<Web<'a>> {
+        self.state.as_cutter().cutting_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// When empty string this Spiderling is not busy, and can act. Otherwise a string representing
+    /// what it is busy with, e.g. 'Moving', 'Attacking'.
+    pub fn busy(&self) -> &'a Str {
+        &self.state.as_spiderling().busy
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// How much work needs to be done for this Spiderling to finish being busy. See docs for the
+    /// Work forumla.
+    pub fn work_remaining(&self) -> f64 {
+        self.state.as_spiderling().work_remaining
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The number of Spiderlings busy with the same work this Spiderling is doing, speeding up the
+    /// task.
+    pub fn number_of_coworkers(&self) -> i64 {
+        self.state.as_spiderling().number_of_coworkers
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Web this Spiderling is using to move. None if it is not moving.
+    pub fn moving_on_web(&self) -> Option<Web<'a>> {
+        self.state.as_spiderling().moving_on_web
+            .as_ref()
+            .and_then(|obj| Web::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spiderling`]_
+    ///
+    /// The Nest this Spiderling is moving to. None if it is not moving.
+    pub fn moving_to_nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spiderling().moving_to_nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Player that owns this Spider, and can command it.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_spider().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// The Nest that this Spider is currently on. None when moving on a Web.
+    pub fn nest(&self) -> Option<Nest<'a>> {
+        self.state.as_spider().nest
+            .as_ref()
+            .and_then(|obj| Nest::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`Spider`]_
+    ///
+    /// If this Spider is dead and has been removed from the game.
+    pub fn is_dead(&self) -> bool {
+        self.state.as_spider().is_dead
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Cutter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cutter").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Cutter<'a>> for Spiderling<'a> {
+    fn from(obj: Cutter<'a>) -> Spiderling<'a> {
+        Spiderling {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Cutter<'a>> for Spider<'a> {
+    fn from(obj: Cutter<'a>) -> Spider<'a> {
+        Spider {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> From<Cutter<'a>> for GameObject<'a> {
+    fn from(obj: Cutter<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Cutter<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Cutter<'a>, GameObject<'a>> {
+        match obj.state.try_as_cutter() {
+            Some(_) => Ok(Cutter {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -0,0 +1,253 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Body::body_type`](super::Body::body_type)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BodyType {
+    /// `"planet"`
+    Planet,
+    /// `"asteroid"`
+    Asteroid,
+    /// `"sun"`
+    Sun,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl BodyType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BodyType::Planet => "planet",
+            BodyType::Asteroid => "asteroid",
+            BodyType::Sun => "sun",
+            BodyType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for BodyType {
+    fn from(s: &str) -> BodyType {
+        match s {
+            "planet" => BodyType::Planet,
+            "asteroid" => BodyType::Asteroid,
+            "sun" => BodyType::Sun,
+            other => BodyType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for BodyType {
+    fn default() -> BodyType {
+        BodyType::from("")
+    }
+}
+
+impl fmt::Display for BodyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for BodyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BodyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| BodyType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for BodyType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Body::material_type`](super::Body::material_type)
+/// - the `material` argument of [`Unit::transfer`](super::Unit::transfer)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MaterialType {
+    /// `"none"`
+    None,
+    /// `"genarium"`
+    Genarium,
+    /// `"rarium"`
+    Rarium,
+    /// `"legendarium"`
+    Legendarium,
+    /// `"mythicite"`
+    Mythicite,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl MaterialType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaterialType::None => "none",
+            MaterialType::Genarium => "genarium",
+            MaterialType::Rarium => "rarium",
+            MaterialType::Legendarium => "legendarium",
+            MaterialType::Mythicite => "mythicite",
+            MaterialType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for MaterialType {
+    fn from(s: &str) -> MaterialType {
+        match s {
+            "none" => MaterialType::None,
+            "genarium" => MaterialType::Genarium,
+            "rarium" => MaterialType::Rarium,
+            "legendarium" => MaterialType::Legendarium,
+            "mythicite" => MaterialType::Mythicite,
+            other => MaterialType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for MaterialType {
+    fn default() -> MaterialType {
+        MaterialType::from("")
+    }
+}
+
+impl fmt::Display for MaterialType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for MaterialType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaterialType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| MaterialType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for MaterialType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - the `title` argument of [`Body::spawn`](super::Body::spawn)
+/// - [`Job::title`](super::Job::title)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JobTitle {
+    /// `"corvette"`
+    Corvette,
+    /// `"missileboat"`
+    Missileboat,
+    /// `"martyr"`
+    Martyr,
+    /// `"transport"`
+    Transport,
+    /// `"miner"`
+    Miner,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl JobTitle {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JobTitle::Corvette => "corvette",
+            JobTitle::Missileboat => "missileboat",
+            JobTitle::Martyr => "martyr",
+            JobTitle::Transport => "transport",
+            JobTitle::Miner => "miner",
+            JobTitle::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for JobTitle {
+    fn from(s: &str) -> JobTitle {
+        match s {
+            "corvette" => JobTitle::Corvette,
+            "missileboat" => JobTitle::Missileboat,
+            "martyr" => JobTitle::Martyr,
+            "transport" => JobTitle::Transport,
+            "miner" => JobTitle::Miner,
+            other => JobTitle::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for JobTitle {
+    fn default() -> JobTitle {
+        JobTitle::from("")
+    }
+}
+
+impl fmt::Display for JobTitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for JobTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobTitle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| JobTitle::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for JobTitle {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
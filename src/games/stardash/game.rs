@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// Holds top-level game state and settings for the current game.
 #[derive(Debug, Clone)]
@@ -19,214 +31,286 @@ impl Game {
         Game { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// A mapping of every game object's ID to the actual game object. Primarily used by the server
     /// and client to easily refer to the game objects via ID.
     pub fn game_objects(&self) -> Map<Str, GameObject> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .game_objects.clone()
     }
 
     /// List of all the players in the game.
     pub fn players(&self) -> List<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .players.clone()
     }
 
     /// A unique identifier for the game instance that is being played.
     pub fn session(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .session.clone()
     }
 
     /// The player whose turn it is currently. That player can send commands. Other players cannot.
     pub fn current_player(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .current_player.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The current turn number, starting at 0 for the first player's turn.
     pub fn current_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .current_turn.clone()
+        lock(&self.inner)
+            .current_turn
     }
 
     /// The maximum number of turns before the game will automatically end.
     pub fn max_turns(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_turns.clone()
+        lock(&self.inner)
+            .max_turns
     }
 
     /// The amount of time (in nano-seconds) added after each player performs a turn.
     pub fn time_added_per_turn(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .time_added_per_turn.clone()
+        lock(&self.inner)
+            .time_added_per_turn
     }
 
     /// Every Unit in the game.
     pub fn units(&self) -> List<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .units.clone()
     }
 
     /// Every projectile in the game.
     pub fn projectiles(&self) -> List<Projectile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .projectiles.clone()
     }
 
     /// A list of all jobs. first item is corvette, second is missileboat, third is martyr, fourth
     /// is transport, and fifth is miner.
     pub fn jobs(&self) -> List<Job> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .jobs.clone()
     }
 
     /// All the celestial bodies in the game. The first two are planets and the third is the sun.
     /// The fourth is the VP asteroid. Everything else is normal asteroids.
     pub fn bodies(&self) -> List<Body> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .bodies.clone()
     }
 
     /// The size of the map in the X direction.
     pub fn size_x(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .size_x.clone()
+        lock(&self.inner)
+            .size_x
     }
 
     /// The size of the map in the Y direction.
     pub fn size_y(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .size_y.clone()
+        lock(&self.inner)
+            .size_y
     }
 
     /// The distance traveled each turn by dashing.
     pub fn dash_distance(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .dash_distance.clone()
+        lock(&self.inner)
+            .dash_distance
     }
 
     /// The cost of dashing.
     pub fn dash_cost(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .dash_cost.clone()
+        lock(&self.inner)
+            .dash_cost
     }
 
     /// The highest amount of material, that can be in a asteroid.
     pub fn max_asteroid(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .max_asteroid.clone()
+        lock(&self.inner)
+            .max_asteroid
     }
 
     /// The smallest amount of material, that can be in a asteroid.
     pub fn min_asteroid(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .min_asteroid.clone()
+        lock(&self.inner)
+            .min_asteroid
     }
 
     /// The rarity modifier of the most common ore. This controls how much spawns.
     pub fn ore_rarity_genarium(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .ore_rarity_genarium.clone()
+        lock(&self.inner)
+            .ore_rarity_genarium
     }
 
     /// The rarity modifier of the second rarest ore. This controls how much spawns.
     pub fn ore_rarity_rarium(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .ore_rarity_rarium.clone()
+        lock(&self.inner)
+            .ore_rarity_rarium
     }
 
     /// The rarity modifier of the rarest ore. This controls how much spawns.
     pub fn ore_rarity_legendarium(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .ore_rarity_legendarium.clone()
+        lock(&self.inner)
+            .ore_rarity_legendarium
     }
 
     /// The value of every unit of genarium.
     pub fn genarium_value(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .genarium_value.clone()
+        lock(&self.inner)
+            .genarium_value
     }
 
     /// The value of every unit of rarium.
     pub fn rarium_value(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .rarium_value.clone()
+        lock(&self.inner)
+            .rarium_value
     }
 
     /// The value of every unit of legendarium.
     pub fn legendarium_value(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .legendarium_value.clone()
+        lock(&self.inner)
+            .legendarium_value
     }
 
     /// The amount of mythicite that spawns at the start of the game.
     pub fn mythicite_amount(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .mythicite_amount.clone()
+        lock(&self.inner)
+            .mythicite_amount
     }
 
     /// The regeneration rate of asteroids.
     pub fn regenerate_rate(&self) -> f64 {
-        self.inner.lock().unwrap()
-            .regenerate_rate.clone()
+        lock(&self.inner)
+            .regenerate_rate
     }
 
     /// The amount of energy the planets restore each round.
     pub fn planet_recharge_rate(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .planet_recharge_rate.clone()
+        lock(&self.inner)
+            .planet_recharge_rate
     }
 
     /// The amount of energy a planet can hold at once.
     pub fn planet_energy_cap(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .planet_energy_cap.clone()
+        lock(&self.inner)
+            .planet_energy_cap
     }
 
     /// The rate at which miners grab minerals from asteroids.
     pub fn mining_speed(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .mining_speed.clone()
+        lock(&self.inner)
+            .mining_speed
     }
 
     /// The amount of distance missiles travel through space.
     pub fn projectile_speed(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .projectile_speed.clone()
+        lock(&self.inner)
+            .projectile_speed
     }
 
     /// The standard size of ships.
     pub fn projectile_radius(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .projectile_radius.clone()
+        lock(&self.inner)
+            .projectile_radius
     }
 
     /// The standard size of ships.
     pub fn ship_radius(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .ship_radius.clone()
+        lock(&self.inner)
+            .ship_radius
     }
 
     /// The number of turns it takes for a asteroid to orbit the sun. (Asteroids move after each
     /// players turn).
     pub fn turns_to_orbit(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .turns_to_orbit.clone()
+        lock(&self.inner)
+            .turns_to_orbit
     }
 
     /// The number of orbit updates you cannot mine the mithicite asteroid.
     pub fn orbits_protected(&self) -> i64 {
-        self.inner.lock().unwrap()
-            .orbits_protected.clone()
+        lock(&self.inner)
+            .orbits_protected
+    }
+
+    /// Looks up a game object by its id.
+    ///
+    /// Returns `None` if there is no such object, if it has been removed from the game, or if it
+    /// is not a `T`.
+    pub fn get_object<T: Object>(&self, id: &str) -> Option<T> {
+        let obj = lock(&self.inner).game_objects.get(id).cloned();
+        obj.and_then(|obj| obj.try_cast())
+    }
+
+    /// The settings given to the AI for this game, like `--aiSettings` on the command line.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn ai_settings(&self) -> Result<AiSettings, Error> {
+        self.with_context(|cx| Ok(cx.ai_settings().clone()))
+    }
+
+    /// Takes an immutable copy of the whole game state, which can be read without locking.
+    ///
+    /// Fails with [`Exit::AiErrored`] if the game has already ended.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        self.with_context(|cx| Ok(cx.snapshot()))
+    }
+}
+
+/// Handles are equal when they refer to the same game, regardless of its state.
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Game {}
+
+/// Handles hash by the identity of the game they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+impl AnyGame for Game {
+    type GameObject = GameObject;
+    type Player = Player;
+
+    fn session(&self) -> Str {
+        self.session()
+    }
+
+    fn players(&self) -> List<Player> {
+        self.players()
+    }
+
+    fn game_objects(&self) -> Map<Str, GameObject> {
+        self.game_objects()
+    }
+}
+
+impl TurnBasedGame for Game {
+    fn current_turn(&self) -> i64 {
+        self.current_turn()
+    }
+
+    fn max_turns(&self) -> i64 {
+        self.max_turns()
     }
 }
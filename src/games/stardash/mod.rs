@@ -3,6 +3,9 @@
 
 mod inner;
 
+mod ai;
+pub mod snapshot;
+mod enums;
 mod body;
 mod game_object;
 mod job;
@@ -19,4 +22,190 @@ pub use projectile::Projectile;
 pub use unit::Unit;
 pub use game::Game;
 
+pub use enums::{BodyType, MaterialType, JobTitle};
+
+pub use ai::AI;
+
+pub use snapshot::Snapshot;
+
 pub use inner::Object;
+
+pub use crate::client::delta::Changes;
+
+use crate::client::gamelog::Gamelog;
+use crate::client::settings::{GameSetting, SettingKind};
+use crate::client::{Client, GameOutcome};
+use crate::error::Error;
+use crate::games::{print_changes, Registry, Spectator};
+
+/// The name of this game on the server.
+pub const GAME_NAME: &str = "Stardash";
+
+/// The settings the server accepts for this game, besides the
+/// [`BASE_SETTINGS`](crate::client::settings::BASE_SETTINGS) every game accepts.
+///
+/// Each sets the initial value of the [`Game`] attribute of the same name.
+pub const SETTINGS: &[GameSetting] = &[
+    GameSetting {
+        name: "maxTurns",
+        kind: SettingKind::Int,
+        description: "The maximum number of turns before the game will automatically end.",
+    },
+    GameSetting {
+        name: "sizeX",
+        kind: SettingKind::Int,
+        description: "The size of the map in the X direction.",
+    },
+    GameSetting {
+        name: "sizeY",
+        kind: SettingKind::Int,
+        description: "The size of the map in the Y direction.",
+    },
+    GameSetting {
+        name: "dashDistance",
+        kind: SettingKind::Int,
+        description: "The distance traveled each turn by dashing.",
+    },
+    GameSetting {
+        name: "dashCost",
+        kind: SettingKind::Int,
+        description: "The cost of dashing.",
+    },
+    GameSetting {
+        name: "maxAsteroid",
+        kind: SettingKind::Int,
+        description: "The highest amount of material, that can be in a asteroid.",
+    },
+    GameSetting {
+        name: "minAsteroid",
+        kind: SettingKind::Int,
+        description: "The smallest amount of material, that can be in a asteroid.",
+    },
+    GameSetting {
+        name: "oreRarityGenarium",
+        kind: SettingKind::Float,
+        description: "The rarity modifier of the most common ore. This controls how much spawns.",
+    },
+    GameSetting {
+        name: "oreRarityRarium",
+        kind: SettingKind::Float,
+        description: "The rarity modifier of the second rarest ore. This controls how much spawns.",
+    },
+    GameSetting {
+        name: "oreRarityLegendarium",
+        kind: SettingKind::Float,
+        description: "The rarity modifier of the rarest ore. This controls how much spawns.",
+    },
+    GameSetting {
+        name: "genariumValue",
+        kind: SettingKind::Float,
+        description: "The value of every unit of genarium.",
+    },
+    GameSetting {
+        name: "rariumValue",
+        kind: SettingKind::Float,
+        description: "The value of every unit of rarium.",
+    },
+    GameSetting {
+        name: "legendariumValue",
+        kind: SettingKind::Float,
+        description: "The value of every unit of legendarium.",
+    },
+    GameSetting {
+        name: "mythiciteAmount",
+        kind: SettingKind::Float,
+        description: "The amount of mythicite that spawns at the start of the game.",
+    },
+    GameSetting {
+        name: "regenerateRate",
+        kind: SettingKind::Float,
+        description: "The regeneration rate of asteroids.",
+    },
+    GameSetting {
+        name: "planetRechargeRate",
+        kind: SettingKind::Int,
+        description: "The amount of energy the planets restore each round.",
+    },
+    GameSetting {
+        name: "planetEnergyCap",
+        kind: SettingKind::Int,
+        description: "The amount of energy a planet can hold at once.",
+    },
+    GameSetting {
+        name: "miningSpeed",
+        kind: SettingKind::Int,
+        description: "The rate at which miners grab minerals from asteroids.",
+    },
+    GameSetting {
+        name: "projectileSpeed",
+        kind: SettingKind::Int,
+        description: "The amount of distance missiles travel through space.",
+    },
+    GameSetting {
+        name: "projectileRadius",
+        kind: SettingKind::Int,
+        description: "The standard size of ships.",
+    },
+    GameSetting {
+        name: "shipRadius",
+        kind: SettingKind::Int,
+        description: "The standard size of ships.",
+    },
+    GameSetting {
+        name: "turnsToOrbit",
+        kind: SettingKind::Int,
+        description: "The number of turns it takes for a asteroid to orbit the sun. (Asteroids move after each players turn).",
+    },
+    GameSetting {
+        name: "orbitsProtected",
+        kind: SettingKind::Int,
+        description: "The number of orbit updates you cannot mine the mithicite asteroid.",
+    },
+];
+
+/// Registers an AI for this game. `new_ai` is called to create a fresh AI for each game played.
+pub fn register<A, F>(registry: &mut Registry, new_ai: F)
+where
+    A: AI + 'static,
+    F: Fn() -> A + 'static,
+{
+    registry.insert(GAME_NAME, move || Box::new(inner::Pending::new(new_ai())));
+}
+
+/// Plays one game of this game on the server the client is configured for, with the given AI.
+///
+/// Fails with [`Exit::GameNotFound`](crate::client::exit::Exit::GameNotFound) if the server
+/// resolves the client's game to a different game.
+pub fn play<A: AI + 'static>(client: &Client, ai: A) -> Result<GameOutcome, Error> {
+    client.play_session(Box::new(inner::Pending::new(ai)))
+}
+
+/// Watches one game of this game on the server the client is configured for, without playing.
+///
+/// `on_update` is called with the whole initial state as created, and then each time the state
+/// changes, until the game is over. Fails like [`play`] does if the server resolves the
+/// client's game to a different game.
+pub fn spectate<F>(client: &Client, on_update: F) -> Result<(), Error>
+where
+    F: FnMut(&Game, &Changes) -> Result<(), Error> + 'static,
+{
+    client.spectate_session(Box::new(inner::Watching::new(on_update)))
+}
+
+/// A spectator that prints what changes in the game with [`print_changes`], as used by
+/// `joueur --spectate`.
+pub fn printer() -> Box<dyn Spectator> {
+    Box::new(inner::Watching::new(|game: &Game, changes: &Changes| {
+        print_changes(game, changes);
+        Ok(())
+    }))
+}
+
+/// Replays a gamelog of this game offline, calling the AI's hooks as the state changes.
+///
+/// The AI sees the game from the view of the player with the given index. No orders are given,
+/// and game object functions fail since there is no server to run them. The AI is returned
+/// afterwards, so that anything it recorded along the way can be inspected.
+pub fn replay<A: AI>(gamelog: &Gamelog, player_index: usize, ai: A) -> Result<A, Error> {
+    inner::replay(gamelog, player_index, ai)
+}
@@ -0,0 +1,967 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{BodyType, MaterialType, JobTitle};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Body`](super::Body) at the time of this snapshot.
+    pub fn body(&self, obj: &super::Body) -> Option<Body<'_>> {
+        Body::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Projectile`](super::Projectile) at the time of this snapshot.
+    pub fn projectile(&self, obj: &super::Projectile) -> Option<Projectile<'_>> {
+        Projectile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Unit`](super::Unit) at the time of this snapshot.
+    pub fn unit(&self, obj: &super::Unit) -> Option<Unit<'_>> {
+        Unit::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Job`](super::Job) at the time of this snapshot.
+    pub fn job(&self, obj: &super::Job) -> Option<Job<'_>> {
+        Job::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// Every Unit in the game.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.snapshot.game.units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every projectile in the game.
+    pub fn projectiles(&self) -> Vec<Projectile<'a>> {
+        self.snapshot.game.projectiles
+            .iter()
+            .filter_map(|obj| Projectile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A list of all jobs. first item is corvette, second is missileboat, third is martyr, fourth
+    /// is transport, and fifth is miner.
+    pub fn jobs(&self) -> Vec<Job<'a>> {
+        self.snapshot.game.jobs
+            .iter()
+            .filter_map(|obj| Job::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the celestial bodies in the game. The first two are planets and the third is the sun.
+    /// The fourth is the VP asteroid. Everything else is normal asteroids.
+    pub fn bodies(&self) -> Vec<Body<'a>> {
+        self.snapshot.game.bodies
+            .iter()
+            .filter_map(|obj| Body::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The size of the map in the X direction.
+    pub fn size_x(&self) -> i64 {
+        self.snapshot.game.size_x
+    }
+
+    /// The size of the map in the Y direction.
+    pub fn size_y(&self) -> i64 {
+        self.snapshot.game.size_y
+    }
+
+    /// The distance traveled each turn by dashing.
+    pub fn dash_distance(&self) -> i64 {
+        self.snapshot.game.dash_distance
+    }
+
+    /// The cost of dashing.
+    pub fn dash_cost(&self) -> i64 {
+        self.snapshot.game.dash_cost
+    }
+
+    /// The highest amount of material, that can be in a asteroid.
+    pub fn max_asteroid(&self) -> i64 {
+        self.snapshot.game.max_asteroid
+    }
+
+    /// The smallest amount of material, that can be in a asteroid.
+    pub fn min_asteroid(&self) -> i64 {
+        self.snapshot.game.min_asteroid
+    }
+
+    /// The rarity modifier of the most common ore. This controls how much spawns.
+    pub fn ore_rarity_genarium(&self) -> f64 {
+        self.snapshot.game.ore_rarity_genarium
+    }
+
+    /// The rarity modifier of the second rarest ore. This controls how much spawns.
+    pub fn ore_rarity_rarium(&self) -> f64 {
+        self.snapshot.game.ore_rarity_rarium
+    }
+
+    /// The rarity modifier of the rarest ore. This controls how much spawns.
+    pub fn ore_rarity_legendarium(&self) -> f64 {
+        self.snapshot.game.ore_rarity_legendarium
+    }
+
+    /// The value of every unit of genarium.
+    pub fn genarium_value(&self) -> f64 {
+        self.snapshot.game.genarium_value
+    }
+
+    /// The value of every unit of rarium.
+    pub fn rarium_value(&self) -> f64 {
+        self.snapshot.game.rarium_value
+    }
+
+    /// The value of every unit of legendarium.
+    pub fn legendarium_value(&self) -> f64 {
+        self.snapshot.game.legendarium_value
+    }
+
+    /// The amount of mythicite that spawns at the start of the game.
+    pub fn mythicite_amount(&self) -> f64 {
+        self.snapshot.game.mythicite_amount
+    }
+
+    /// The regeneration rate of asteroids.
+    pub fn regenerate_rate(&self) -> f64 {
+        self.snapshot.game.regenerate_rate
+    }
+
+    /// The amount of energy the planets restore each round.
+    pub fn planet_recharge_rate(&self) -> i64 {
+        self.snapshot.game.planet_recharge_rate
+    }
+
+    /// The amount of energy a planet can hold at once.
+    pub fn planet_energy_cap(&self) -> i64 {
+        self.snapshot.game.planet_energy_cap
+    }
+
+    /// The rate at which miners grab minerals from asteroids.
+    pub fn mining_speed(&self) -> i64 {
+        self.snapshot.game.mining_speed
+    }
+
+    /// The amount of distance missiles travel through space.
+    pub fn projectile_speed(&self) -> i64 {
+        self.snapshot.game.projectile_speed
+    }
+
+    /// The standard size of ships.
+    pub fn projectile_radius(&self) -> i64 {
+        self.snapshot.game.projectile_radius
+    }
+
+    /// The standard size of ships.
+    pub fn ship_radius(&self) -> i64 {
+        self.snapshot.game.ship_radius
+    }
+
+    /// The number of turns it takes for a asteroid to orbit the sun. (Asteroids move after each
+    /// players turn).
+    pub fn turns_to_orbit(&self) -> i64 {
+        self.snapshot.game.turns_to_orbit
+    }
+
+    /// The number of orbit updates you cannot mine the mithicite asteroid.
+    pub fn orbits_protected(&self) -> i64 {
+        self.snapshot.game.orbits_protected
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// Every Unit owned by this Player. The earlier in the list the older they are.
+    pub fn units(&self) -> Vec<Unit<'a>> {
+        self.state.as_player().units
+            .iter()
+            .filter_map(|obj| Unit::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Projectile owned by this Player. The earlier in the list the older they are.
+    pub fn projectiles(&self) -> Vec<Projectile<'a>> {
+        self.state.as_player().projectiles
+            .iter()
+            .filter_map(|obj| Projectile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// The amount of money this Player has.
+    pub fn money(&self) -> i64 {
+        self.state.as_player().money
+    }
+
+    /// The home base of the player.
+    pub fn home_base(&self) -> Option<Body<'a>> {
+        self.state.as_player().home_base
+            .as_ref()
+            .and_then(|obj| Body::resolve(self.snapshot, obj))
+    }
+
+    /// The number of victory points the player has.
+    pub fn victory_points(&self) -> i64 {
+        self.state.as_player().victory_points
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Body`](super::Body) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Body<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Body<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Body<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_body()?;
+        Some(Body { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Body.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_body().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The x value this celestial body is on.
+    pub fn x(&self) -> f64 {
+        self.state.as_body().x
+    }
+
+    /// The y value this celestial body is on.
+    pub fn y(&self) -> f64 {
+        self.state.as_body().y
+    }
+
+    /// The radius of the circle that this body takes up.
+    pub fn radius(&self) -> f64 {
+        self.state.as_body().radius
+    }
+
+    /// The type of celestial body it is. Either 'planet', 'asteroid', or 'sun'.
+    pub fn body_type(&self) -> &'a BodyType {
+        &self.state.as_body().body_type
+    }
+
+    /// The type of material the celestial body has. Either 'none', 'genarium', 'rarium',
+    /// 'legendarium', or 'mythicite'.
+    pub fn material_type(&self) -> &'a MaterialType {
+        &self.state.as_body().material_type
+    }
+
+    /// The amount of material the object has, or energy if it is a planet.
+    pub fn amount(&self) -> i64 {
+        self.state.as_body().amount
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Body<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Body").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Body<'a>> for GameObject<'a> {
+    fn from(obj: Body<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Body<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Body<'a>, GameObject<'a>> {
+        match obj.state.try_as_body() {
+            Some(_) => Ok(Body {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Projectile`](super::Projectile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Projectile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Projectile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Projectile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_projectile()?;
+        Some(Projectile { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Projectile.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_projectile().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The x value this projectile is on.
+    pub fn x(&self) -> f64 {
+        self.state.as_projectile().x
+    }
+
+    /// The y value this projectile is on.
+    pub fn y(&self) -> f64 {
+        self.state.as_projectile().y
+    }
+
+    /// The unit that is being attacked by this projectile.
+    pub fn target(&self) -> Option<Unit<'a>> {
+        self.state.as_projectile().target
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// The amount of remaining distance the projectile can move.
+    pub fn fuel(&self) -> i64 {
+        self.state.as_projectile().fuel
+    }
+
+    /// The remaining health of the projectile.
+    pub fn energy(&self) -> i64 {
+        self.state.as_projectile().energy
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Projectile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Projectile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Projectile<'a>> for GameObject<'a> {
+    fn from(obj: Projectile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Projectile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Projectile<'a>, GameObject<'a>> {
+        match obj.state.try_as_projectile() {
+            Some(_) => Ok(Projectile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Unit`](super::Unit) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Unit<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Unit<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Unit<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_unit()?;
+        Some(Unit { snapshot, state })
+    }
+
+    /// The Player that owns and can control this Unit.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_unit().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The x value this unit is on.
+    pub fn x(&self) -> f64 {
+        self.state.as_unit().x
+    }
+
+    /// The y value this unit is on.
+    pub fn y(&self) -> f64 {
+        self.state.as_unit().y
+    }
+
+    /// The x value this unit is dashing to.
+    pub fn dash_x(&self) -> f64 {
+        self.state.as_unit().dash_x
+    }
+
+    /// The y value this unit is dashing to.
+    pub fn dash_y(&self) -> f64 {
+        self.state.as_unit().dash_y
+    }
+
+    /// The Job this Unit has.
+    pub fn job(&self) -> Option<Job<'a>> {
+        self.state.as_unit().job
+            .as_ref()
+            .and_then(|obj| Job::resolve(self.snapshot, obj))
+    }
+
+    /// The remaining health of the unit.
+    pub fn energy(&self) -> i64 {
+        self.state.as_unit().energy
+    }
+
+    /// The sheild that a martyr ship has.
+    pub fn shield(&self) -> i64 {
+        self.state.as_unit().shield
+    }
+
+    /// Whether or not this Unit has performed its action this turn.
+    pub fn acted(&self) -> bool {
+        self.state.as_unit().acted
+    }
+
+    /// The distance this unit can still move.
+    pub fn moves(&self) -> f64 {
+        self.state.as_unit().moves
+    }
+
+    /// The amount of Genarium ore carried by this unit. (0 to job carry capacity - other carried
+    /// items).
+    pub fn genarium(&self) -> i64 {
+        self.state.as_unit().genarium
+    }
+
+    /// The amount of Rarium carried by this unit. (0 to job carry capacity - other carried items).
+    pub fn rarium(&self) -> i64 {
+        self.state.as_unit().rarium
+    }
+
+    /// The amount of Legendarium ore carried by this unit. (0 to job carry capacity - other
+    /// carried items).
+    pub fn legendarium(&self) -> i64 {
+        self.state.as_unit().legendarium
+    }
+
+    /// The amount of Mythicite carried by this unit. (0 to job carry capacity - other carried
+    /// items).
+    pub fn mythicite(&self) -> i64 {
+        self.state.as_unit().mythicite
+    }
+
+    /// Tracks wheither or not the ship is dashing or Mining. If true, it cannot do anything else.
+    pub fn is_busy(&self) -> bool {
+        self.state.as_unit().is_busy
+    }
+
+    /// The martyr ship that is currently shielding this ship if any.
+    pub fn protector(&self) -> Option<Unit<'a>> {
+        self.state.as_unit().protector
+            .as_ref()
+            .and_then(|obj| Unit::resolve(self.snapshot, obj))
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Unit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unit").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Unit<'a>> for GameObject<'a> {
+    fn from(obj: Unit<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Unit<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Unit<'a>, GameObject<'a>> {
+        match obj.state.try_as_unit() {
+            Some(_) => Ok(Unit {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Job`](super::Job) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Job<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Job<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_job()?;
+        Some(Job { snapshot, state })
+    }
+
+    /// The Job title. 'corvette', 'missileboat', 'martyr', 'transport', or 'miner'. (in this order
+    /// from 0-4).
+    pub fn title(&self) -> &'a JobTitle {
+        &self.state.as_job().title
+    }
+
+    /// The amount of starting health this Job has.
+    pub fn energy(&self) -> i64 {
+        self.state.as_job().energy
+    }
+
+    /// The reserve the martyr use to protect allies.
+    pub fn shield(&self) -> i64 {
+        self.state.as_job().shield
+    }
+
+    /// The distance this job can move per turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_job().moves
+    }
+
+    /// The amount of damage this Job does per attack.
+    pub fn damage(&self) -> i64 {
+        self.state.as_job().damage
+    }
+
+    /// How many combined resources a unit with this Job can hold at once.
+    pub fn carry_limit(&self) -> i64 {
+        self.state.as_job().carry_limit
+    }
+
+    /// How much money it costs to spawn a unit.
+    pub fn unit_cost(&self) -> i64 {
+        self.state.as_job().unit_cost
+    }
+
+    /// The distance at which this job can effect things.
+    pub fn range(&self) -> i64 {
+        self.state.as_job().range
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Job<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Job").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Job<'a>> for GameObject<'a> {
+    fn from(obj: Job<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Job<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Job<'a>, GameObject<'a>> {
+        match obj.state.try_as_job() {
+            Some(_) => Ok(Job {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A unit in the game. May be a corvette, missleboat, martyr, transport, miner.
 #[derive(Debug, Clone)]
@@ -19,126 +31,129 @@ impl Unit {
         Unit { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// The Player that owns and can control this Unit.
     pub fn owner(&self) -> Option<Player> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .owner.clone()
     }
 
     /// The x value this unit is on.
     pub fn x(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .x.clone()
+            .x
     }
 
     /// The y value this unit is on.
     pub fn y(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .y.clone()
+            .y
     }
 
     /// The x value this unit is dashing to.
     pub fn dash_x(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .dash_x.clone()
+            .dash_x
     }
 
     /// The y value this unit is dashing to.
     pub fn dash_y(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .dash_y.clone()
+            .dash_y
     }
 
     /// The Job this Unit has.
     pub fn job(&self) -> Job {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .job.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The remaining health of the unit.
     pub fn energy(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .energy.clone()
+            .energy
     }
 
     /// The sheild that a martyr ship has.
     pub fn shield(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .shield.clone()
+            .shield
     }
 
     /// Whether or not this Unit has performed its action this turn.
     pub fn acted(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .acted.clone()
+            .acted
     }
 
     /// The distance this unit can still move.
     pub fn moves(&self) -> f64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .moves.clone()
+            .moves
     }
 
     /// The amount of Genarium ore carried by this unit. (0 to job carry capacity - other carried
     /// items).
     pub fn genarium(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .genarium.clone()
+            .genarium
     }
 
     /// The amount of Rarium carried by this unit. (0 to job carry capacity - other carried items).
     pub fn rarium(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .rarium.clone()
+            .rarium
     }
 
     /// The amount of Legendarium ore carried by this unit. (0 to job carry capacity - other
     /// carried items).
     pub fn legendarium(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .legendarium.clone()
+            .legendarium
     }
 
     /// The amount of Mythicite carried by this unit. (0 to job carry capacity - other carried
     /// items).
     pub fn mythicite(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .mythicite.clone()
+            .mythicite
     }
 
     /// Tracks wheither or not the ship is dashing or Mining. If true, it cannot do anything else.
     pub fn is_busy(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
-            .is_busy.clone()
+            .is_busy
     }
 
     /// The martyr ship that is currently shielding this ship if any.
     pub fn protector(&self) -> Option<Unit> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_unit()
             .protector.clone()
     }
@@ -148,7 +163,7 @@ impl Unit {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -159,7 +174,7 @@ impl Unit {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -168,7 +183,7 @@ impl Unit {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -180,10 +195,10 @@ impl Unit {
     /// - _unit_ - The unit you are grabbing the resources from.
     ///
     /// - _amount_ - The amount of materials to you with to grab. Amounts <= 0 will pick up all the
-    /// materials that the unit can.
+    ///   materials that the unit can.
     ///
     /// - _material_ - The material the unit will pick up. 'genarium', 'rarium', 'legendarium', or
-    /// 'mythicite'.
+    ///   'mythicite'.
     ///
     /// # Returns
     ///
@@ -192,14 +207,17 @@ impl Unit {
         &self,
         unit: &Unit,
         amount: i64,
-        material: &str,
+        material: MaterialType,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             unit: &'a Unit,
             amount: i64,
-            material: &'a str,
+            material: MaterialType,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -229,9 +247,12 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             x: f64,
             y: f64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -257,8 +278,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             enemy: &'a Unit,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -283,8 +307,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             missile: &'a Projectile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -309,8 +336,11 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             body: &'a Body,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -338,9 +368,12 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             x: f64,
             y: f64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -369,9 +402,12 @@ impl Unit {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             x: f64,
             y: f64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -396,8 +432,11 @@ impl Unit {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -407,21 +446,87 @@ impl Unit {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Unit {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Unit {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Unit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Unit {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_unit().is_some() {
             Some(Unit {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -430,3 +535,47 @@ impl inner::ObjectInner for Unit {
     }
 }
 impl Object for Unit {}
+
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Unit {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Unit {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Unit {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(dead_code, unused_imports)]
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
+use serde::ser::{Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::Value;
+
 use super::*;
+use crate::client::base::ObjRef;
+use crate::client::delta::{DeltaMergeable, FromDelta};
+use crate::client::exit::Exit;
+use crate::client::settings::AiSettings;
+use crate::client::util::lock;
 use crate::types::*;
 use crate::error::Error;
+use crate::games::{AnyGame, AnyPlayer, GameObjectHandle, GridGame, GridTile, TurnBasedGame};
 
 /// A beaver in the game.
 #[derive(Debug, Clone)]
@@ -19,83 +31,87 @@ impl Beaver {
         Beaver { inner, context }
     }
 
-    fn with_context<F, R>(&self, f: F) -> R
+    fn with_context<F, R>(&self, f: F) -> Result<R, Error>
     where
-        F: FnOnce(&mut inner::Context) -> R,
+        F: FnOnce(&mut inner::Context) -> Result<R, Error>,
     {
-        let context = self.context.upgrade().expect("context dropped before end of game");
-        let mut handle = context.lock().unwrap();
+        let context = self.context.upgrade().ok_or_else(|| {
+            Error::from((Exit::AiErrored, "game object used after the end of its game"))
+        })?;
+        let mut handle = lock(&context);
         f(&mut handle)
     }
 
     /// How many moves this Beaver has left this turn.
     pub fn moves(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .moves.clone()
+            .moves
     }
 
     /// The Player that owns and can control this Beaver.
     pub fn owner(&self) -> Player {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
             .owner.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// The number of actions remaining for the Beaver this turn.
     pub fn actions(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .actions.clone()
+            .actions
     }
 
     /// The Tile this Beaver is on.
     pub fn tile(&self) -> Option<Tile> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
             .tile.clone()
     }
 
     /// How much health this Beaver has left.
     pub fn health(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .health.clone()
+            .health
     }
 
     /// Number of turns this Beaver is distracted for (0 means not distracted).
     pub fn turns_distracted(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .turns_distracted.clone()
+            .turns_distracted
     }
 
     /// The amount of branches this Beaver is holding.
     pub fn branches(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .branches.clone()
+            .branches
     }
 
     /// The amount of food this Beaver is holding.
     pub fn food(&self) -> i64 {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .food.clone()
+            .food
     }
 
     /// The Job this Beaver was recruited to do.
     pub fn job(&self) -> Job {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
             .job.clone()
+            .expect("unreachable: required attributes are checked when merged")
     }
 
     /// True if the Beaver has finished being recruited and can do things, False otherwise.
     pub fn recruited(&self) -> bool {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_beaver()
-            .recruited.clone()
+            .recruited
     }
 
     /// _Inherited from [`GameObject`]_
@@ -103,7 +119,7 @@ impl Beaver {
     /// A unique id for each instance of a GameObject or a sub class. Used for client and server
     /// communication. Should never change value after being set.
     pub fn id(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .id.clone()
     }
@@ -114,7 +130,7 @@ impl Beaver {
     /// reflection to create new instances on clients, but exposed for convenience should AIs want
     /// this data.
     pub fn game_object_name(&self) -> Str {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .game_object_name.clone()
     }
@@ -123,7 +139,7 @@ impl Beaver {
     ///
     /// Any strings logged will be stored here. Intended for debugging.
     pub fn logs(&self) -> List<Str> {
-        self.inner.lock().unwrap()
+        lock(&self.inner)
             .as_game_object()
             .logs.clone()
     }
@@ -143,8 +159,11 @@ impl Beaver {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -169,8 +188,11 @@ impl Beaver {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             spawner: &'a Spawner,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -195,8 +217,11 @@ impl Beaver {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             beaver: &'a Beaver,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -216,7 +241,10 @@ impl Beaver {
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -230,12 +258,12 @@ impl Beaver {
     /// # Arguments
     ///
     /// - _tile_ - The Tile to drop branches/food on. Must be the same Tile that the Beaver is on,
-    /// or an adjacent one.
+    ///   or an adjacent one.
     ///
     /// - _resource_ - The type of resource to drop ('branches' or 'food').
     ///
     /// - _amount_ - The amount of the resource to drop, numbers <= 0 will drop all the resource
-    /// type.
+    ///   type.
     ///
     /// # Returns
     ///
@@ -243,15 +271,18 @@ impl Beaver {
     pub fn drop(
         &self,
         tile: &Tile,
-        resource: &str,
+        resource: Resource,
         amount: i64,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            resource: &'a str,
+            resource: Resource,
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -268,12 +299,12 @@ impl Beaver {
     /// # Arguments
     ///
     /// - _tile_ - The Tile to pickup branches/food from. Must be the same Tile that the Beaver is
-    /// on, or an adjacent one.
+    ///   on, or an adjacent one.
     ///
     /// - _resource_ - The type of resource to pickup ('branches' or 'food').
     ///
     /// - _amount_ - The amount of the resource to drop, numbers <= 0 will pickup all of the
-    /// resource type.
+    ///   resource type.
     ///
     /// # Returns
     ///
@@ -281,15 +312,18 @@ impl Beaver {
     pub fn pickup(
         &self,
         tile: &Tile,
-        resource: &str,
+        resource: Resource,
         amount: i64,
     )
         -> Result<bool, Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             tile: &'a Tile,
-            resource: &'a str,
+            resource: Resource,
             amount: i64,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -315,8 +349,11 @@ impl Beaver {
     )
         -> Result<(), Error>
     {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Args<'a> {
             message: &'a str,
+            #[serde(skip)]
             _a: PhantomData< &'a () >,
         }
         let args = Args {
@@ -326,21 +363,87 @@ impl Beaver {
         self.with_context(|cx| cx.run(&self.id(), "log", args))
     }
 
+    /// Checks whether this object is still part of the game.
+    ///
+    /// Objects stop being alive when the server removes them, and once the game has ended. The
+    /// attributes of a dead object keep the last values they were sent.
+    pub fn is_alive(&self) -> bool {
+        self.with_context(|cx| Ok(cx.contains(&self.inner)))
+            .unwrap_or(false)
+    }
+
     pub fn try_cast<T: Object>(&self) -> Option<T> {
         T::from_game_object(&self.inner, &self.context)
     }
 
-    pub fn cast<T: Object>(&self) -> T {
-        self.try_cast().unwrap()
+    /// Converts this object to another type in its hierarchy.
+    ///
+    /// Fails with [`Exit::ReflectionFailed`] if it is not an instance of that type.
+    pub fn cast<T: Object>(&self) -> Result<T, Error> {
+        self.try_cast().ok_or_else(|| {
+            let message = format!(
+                "{} {:?} is not a {}",
+                self.game_object_name().as_slice(),
+                self.id().as_slice(),
+                std::any::type_name::<T>(),
+            );
+            (Exit::ReflectionFailed, message).into()
+        })
+    }
+}
+
+/// Handles are equal when they refer to the same game object, regardless of its state.
+impl PartialEq for Beaver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Beaver {}
+
+/// Handles hash by the identity of the game object they refer to, so they can be used as keys in
+/// hash maps and sets even though its state changes. Clippy's `mutable_key_type` lint can't tell
+/// that apart from hashing the state, so it needs `#[allow(clippy::mutable_key_type)]` where
+/// handles are used as keys.
+impl Hash for Beaver {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.inner).hash(state)
+    }
+}
+
+/// Handles are ordered by the id of the game object they refer to, shorter ids first, so that
+/// the numeric ids the server hands out sort as numbers, like `9` before `10`.
+///
+/// Ids are only reused after an object has been removed from the game, in which case the old and
+/// new objects are ordered arbitrarily but consistently.
+impl PartialOrd for Beaver {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Beaver {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (id, other_id) = (self.id(), other.id());
+        (id.len(), &id)
+            .cmp(&(other_id.len(), &other_id))
+            .then_with(|| Arc::as_ptr(&self.inner).cmp(&Arc::as_ptr(&other.inner)))
     }
 }
 
 impl inner::ObjectInner for Beaver {
+    fn game_object(&self) -> &Arc<Mutex<inner::AnyGameObject>> {
+        &self.inner
+    }
+
     fn from_game_object(game_obj: &Arc<Mutex<inner::AnyGameObject>>, context: &Weak<Mutex<inner::Context>>) -> Option<Self> {
-        let handle = game_obj.lock().unwrap();
+        let handle = lock(game_obj);
         if handle.try_as_beaver().is_some() {
             Some(Beaver {
-                inner: Arc::clone(&game_obj),
+                inner: Arc::clone(game_obj),
                 context: context.clone(),
             })
         } else {
@@ -349,3 +452,47 @@ impl inner::ObjectInner for Beaver {
     }
 }
 impl Object for Beaver {}
+
+impl Serialize for Beaver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ObjRef::new(self.id().as_slice()).serialize(serializer)
+    }
+}
+
+impl DeltaMergeable<inner::Context> for Beaver {
+    fn merge_delta(&mut self, delta: Value, cx: &inner::Context) -> Result<(), Error> {
+        *self = cx.resolve(delta)?;
+        Ok(())
+    }
+}
+
+impl FromDelta<inner::Context> for Beaver {
+    fn from_delta(delta: Value, cx: &inner::Context) -> Result<Self, Error> {
+        cx.resolve(delta)
+    }
+}
+
+impl GameObjectHandle for Beaver {
+    fn id(&self) -> Str {
+        self.id()
+    }
+
+    fn game_object_name(&self) -> Str {
+        self.game_object_name()
+    }
+
+    fn logs(&self) -> List<Str> {
+        self.logs()
+    }
+
+    fn log(&self, message: &str) -> Result<(), Error> {
+        self.log(message)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_alive()
+    }
+}
@@ -0,0 +1,238 @@
+#![allow(dead_code, unused_imports)]
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::client::delta::DeltaMergeable;
+use crate::error::Error;
+
+/// One of a fixed set of strings, used by:
+///
+/// - the elements of [`Game::spawner_types`](super::Game::spawner_types)
+/// - [`Spawner::type_`](super::Spawner::type_)
+/// - the `resource` argument of [`Beaver::drop`](super::Beaver::drop)
+/// - the `resource` argument of [`Beaver::pickup`](super::Beaver::pickup)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// `"food"`
+    Food,
+    /// `"branches"`
+    Branches,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl Resource {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Resource::Food => "food",
+            Resource::Branches => "branches",
+            Resource::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Resource {
+    fn from(s: &str) -> Resource {
+        match s {
+            "food" => Resource::Food,
+            "branches" => Resource::Branches,
+            other => Resource::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for Resource {
+    fn default() -> Resource {
+        Resource::from("")
+    }
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Resource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Resource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| Resource::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for Resource {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Tile::type_`](super::Tile::type_)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TileType {
+    /// `"water"`
+    Water,
+    /// `"land"`
+    Land,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl TileType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TileType::Water => "water",
+            TileType::Land => "land",
+            TileType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for TileType {
+    fn from(s: &str) -> TileType {
+        match s {
+            "water" => TileType::Water,
+            "land" => TileType::Land,
+            other => TileType::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for TileType {
+    fn default() -> TileType {
+        TileType::from("")
+    }
+}
+
+impl fmt::Display for TileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TileType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| TileType::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for TileType {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
+
+/// One of a fixed set of strings, used by:
+///
+/// - [`Tile::flow_direction`](super::Tile::flow_direction)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlowDirection {
+    /// `""`
+    Empty,
+    /// `"North"`
+    North,
+    /// `"East"`
+    East,
+    /// `"South"`
+    South,
+    /// `"West"`
+    West,
+    /// Any other value, which this version of the game doesn't know about.
+    Other(String),
+}
+
+impl FlowDirection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FlowDirection::Empty => "",
+            FlowDirection::North => "North",
+            FlowDirection::East => "East",
+            FlowDirection::South => "South",
+            FlowDirection::West => "West",
+            FlowDirection::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for FlowDirection {
+    fn from(s: &str) -> FlowDirection {
+        match s {
+            "" => FlowDirection::Empty,
+            "North" => FlowDirection::North,
+            "East" => FlowDirection::East,
+            "South" => FlowDirection::South,
+            "West" => FlowDirection::West,
+            other => FlowDirection::Other(other.to_string()),
+        }
+    }
+}
+
+/// The value attributes have before the server sets them.
+impl Default for FlowDirection {
+    fn default() -> FlowDirection {
+        FlowDirection::from("")
+    }
+}
+
+impl fmt::Display for FlowDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for FlowDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FlowDirection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| FlowDirection::from(s.as_str()))
+    }
+}
+
+impl<C: ?Sized> DeltaMergeable<C> for FlowDirection {
+    fn merge_delta(&mut self, delta: Value, _cx: &C) -> Result<(), Error> {
+        *self = serde_json::from_value(delta)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,885 @@
+//! Immutable copies of the game state, which can be read without locking.
+#![allow(dead_code, unused_imports)]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::inner::{self, AnyGameObject, ObjectInner};
+use crate::client::util::lock;
+use crate::types::*;
+use super::{Resource, TileType, FlowDirection};
+
+/// A copy of the whole game state, as of the delta it was taken at.
+///
+/// Snapshots never change and never lock, so they can be cloned cheaply and read from any number
+/// of threads while the game carries on. Objects in a snapshot are read through views like
+/// [`GameObject`], which borrow from it and refer to each other the same way the live objects do.
+#[derive(Clone)]
+pub struct Snapshot {
+    state: Arc<State>,
+}
+
+struct State {
+    game: inner::GameBase,
+    // Keyed by the address of each live object, which the references in `game` and `objects`
+    // keep alive for as long as the snapshot exists.
+    objects: HashMap<usize, AnyGameObject>,
+}
+
+fn key(obj: &Arc<Mutex<AnyGameObject>>) -> usize {
+    Arc::as_ptr(obj) as usize
+}
+
+impl Snapshot {
+    /// Copies the state of every object in the game. The caller must make sure no delta is merged
+    /// in the meantime.
+    pub(crate) fn new(game: inner::GameBase) -> Snapshot {
+        let objects = game.game_objects
+            .iter()
+            .map(|(_, obj)| {
+                let obj = obj.game_object();
+                (key(obj), lock(obj).clone())
+            })
+            .collect();
+        Snapshot {
+            state: Arc::new(State { game, objects }),
+        }
+    }
+
+    /// The game itself.
+    pub fn game(&self) -> Game<'_> {
+        Game {
+            snapshot: &self.state,
+        }
+    }
+
+    /// Looks up an object by its id, returning `None` if it was not in the game at the time.
+    pub fn get_object(&self, id: &str) -> Option<GameObject<'_>> {
+        let obj = self.state.game.game_objects.get(id)?;
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`GameObject`](super::GameObject) at the time of this snapshot.
+    pub fn game_object(&self, obj: &super::GameObject) -> Option<GameObject<'_>> {
+        GameObject::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Player`](super::Player) at the time of this snapshot.
+    pub fn player(&self, obj: &super::Player) -> Option<Player<'_>> {
+        Player::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Tile`](super::Tile) at the time of this snapshot.
+    pub fn tile(&self, obj: &super::Tile) -> Option<Tile<'_>> {
+        Tile::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Spawner`](super::Spawner) at the time of this snapshot.
+    pub fn spawner(&self, obj: &super::Spawner) -> Option<Spawner<'_>> {
+        Spawner::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Beaver`](super::Beaver) at the time of this snapshot.
+    pub fn beaver(&self, obj: &super::Beaver) -> Option<Beaver<'_>> {
+        Beaver::resolve(&self.state, obj)
+    }
+
+    /// The state of a live [`Job`](super::Job) at the time of this snapshot.
+    pub fn job(&self, obj: &super::Job) -> Option<Job<'_>> {
+        Job::resolve(&self.state, obj)
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("objects", &self.state.objects.len())
+            .finish()
+    }
+}
+
+/// A read-only view of the [`Game`](super::Game) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Game<'a> {
+    snapshot: &'a State,
+}
+
+impl<'a> Game<'a> {
+    /// A mapping of every game object's ID to the actual game object. Primarily used by the server
+    /// and client to easily refer to the game objects via ID.
+    pub fn game_objects(&self) -> HashMap<&'a str, GameObject<'a>> {
+        self.snapshot.game.game_objects
+            .iter()
+            .filter_map(|(key, obj)| Some((key.as_slice(), GameObject::resolve(self.snapshot, obj)?)))
+            .collect()
+    }
+
+    /// List of all the players in the game.
+    pub fn players(&self) -> Vec<Player<'a>> {
+        self.snapshot.game.players
+            .iter()
+            .filter_map(|obj| Player::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A unique identifier for the game instance that is being played.
+    pub fn session(&self) -> &'a Str {
+        &self.snapshot.game.session
+    }
+
+    /// The player whose turn it is currently. That player can send commands. Other players cannot.
+    pub fn current_player(&self) -> Option<Player<'a>> {
+        self.snapshot.game.current_player
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The current turn number, starting at 0 for the first player's turn.
+    pub fn current_turn(&self) -> i64 {
+        self.snapshot.game.current_turn
+    }
+
+    /// The maximum number of turns before the game will automatically end.
+    pub fn max_turns(&self) -> i64 {
+        self.snapshot.game.max_turns
+    }
+
+    /// The amount of time (in nano-seconds) added after each player performs a turn.
+    pub fn time_added_per_turn(&self) -> i64 {
+        self.snapshot.game.time_added_per_turn
+    }
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    pub fn map_width(&self) -> i64 {
+        self.snapshot.game.map_width
+    }
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    pub fn map_height(&self) -> i64 {
+        self.snapshot.game.map_height
+    }
+
+    /// All the tiles in the map, stored in Row-major order. Use `x + y * mapWidth` to access the
+    /// correct index.
+    pub fn tiles(&self) -> Vec<Tile<'a>> {
+        self.snapshot.game.tiles
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Beaver in the game.
+    pub fn beavers(&self) -> Vec<Beaver<'a>> {
+        self.snapshot.game.beavers
+            .iter()
+            .filter_map(|obj| Beaver::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// Every Spawner in the game.
+    pub fn spawner(&self) -> Vec<Spawner<'a>> {
+        self.snapshot.game.spawner
+            .iter()
+            .filter_map(|obj| Spawner::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// All the types of Spawners in the game.
+    pub fn spawner_types(&self) -> &'a List<Resource> {
+        &self.snapshot.game.spawner_types
+    }
+
+    /// All the Jobs that Beavers can have in the game.
+    pub fn jobs(&self) -> Vec<Job<'a>> {
+        self.snapshot.game.jobs
+            .iter()
+            .filter_map(|obj| Job::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// When a Player has less Beavers than this number, then recruiting other Beavers is free.
+    pub fn free_beavers_count(&self) -> i64 {
+        self.snapshot.game.free_beavers_count
+    }
+
+    /// How many lodges must be owned by a Player at once to win the game.
+    pub fn lodges_to_win(&self) -> i64 {
+        self.snapshot.game.lodges_to_win
+    }
+
+    /// Constant number used to calculate what it costs to spawn a new lodge.
+    pub fn lodge_cost_constant(&self) -> f64 {
+        self.snapshot.game.lodge_cost_constant
+    }
+
+    /// Constant number used to calculate how many branches/food Beavers harvest from Spawners.
+    pub fn spawner_harvest_constant(&self) -> f64 {
+        self.snapshot.game.spawner_harvest_constant
+    }
+}
+
+impl<'a> fmt::Debug for Game<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Game").finish()
+    }
+}
+
+/// A read-only view of a [`GameObject`](super::GameObject) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct GameObject<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> GameObject<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<GameObject<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_game_object()?;
+        Some(GameObject { snapshot, state })
+    }
+
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for GameObject<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GameObject").field("id", &self.state.id()).finish()
+    }
+}
+
+/// A read-only view of a [`Player`](super::Player) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Player<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Player<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Player<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_player()?;
+        Some(Player { snapshot, state })
+    }
+
+    /// The name of the player.
+    pub fn name(&self) -> &'a Str {
+        &self.state.as_player().name
+    }
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    pub fn client_type(&self) -> &'a Str {
+        &self.state.as_player().client_type
+    }
+
+    /// If the player won the game or not.
+    pub fn won(&self) -> bool {
+        self.state.as_player().won
+    }
+
+    /// If the player lost the game or not.
+    pub fn lost(&self) -> bool {
+        self.state.as_player().lost
+    }
+
+    /// The reason why the player won the game.
+    pub fn reason_won(&self) -> &'a Str {
+        &self.state.as_player().reason_won
+    }
+
+    /// The reason why the player lost the game.
+    pub fn reason_lost(&self) -> &'a Str {
+        &self.state.as_player().reason_lost
+    }
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    pub fn time_remaining(&self) -> f64 {
+        self.state.as_player().time_remaining
+    }
+
+    /// This player's opponent in the game.
+    pub fn opponent(&self) -> Option<Player<'a>> {
+        self.state.as_player().opponent
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The list of Beavers owned by this Player.
+    pub fn beavers(&self) -> Vec<Beaver<'a>> {
+        self.state.as_player().beavers
+            .iter()
+            .filter_map(|obj| Beaver::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// A list of Tiles that contain lodges owned by this player.
+    pub fn lodges(&self) -> Vec<Tile<'a>> {
+        self.state.as_player().lodges
+            .iter()
+            .filter_map(|obj| Tile::resolve(self.snapshot, obj))
+            .collect()
+    }
+
+    /// How many branches are required to build a lodge for this Player.
+    pub fn branches_to_build_lodge(&self) -> i64 {
+        self.state.as_player().branches_to_build_lodge
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Player<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Player").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Player<'a>> for GameObject<'a> {
+    fn from(obj: Player<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Player<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Player<'a>, GameObject<'a>> {
+        match obj.state.try_as_player() {
+            Some(_) => Ok(Player {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Tile`](super::Tile) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Tile<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Tile<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Tile<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_tile()?;
+        Some(Tile { snapshot, state })
+    }
+
+    /// The x (horizontal) position of this Tile.
+    pub fn x(&self) -> i64 {
+        self.state.as_tile().x
+    }
+
+    /// The y (vertical) position of this Tile.
+    pub fn y(&self) -> i64 {
+        self.state.as_tile().y
+    }
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    pub fn tile_north(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_north
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    pub fn tile_east(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_east
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    pub fn tile_south(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_south
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    pub fn tile_west(&self) -> Option<Tile<'a>> {
+        self.state.as_tile().tile_west
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// What type of Tile this is, either 'water' or 'land'.
+    pub fn type_(&self) -> &'a TileType {
+        &self.state.as_tile().type_
+    }
+
+    /// The cardinal direction water is flowing on this Tile ('North', 'East', 'South', 'West').
+    pub fn flow_direction(&self) -> &'a FlowDirection {
+        &self.state.as_tile().flow_direction
+    }
+
+    /// The Beaver on this Tile if present, otherwise None.
+    pub fn beaver(&self) -> Option<Beaver<'a>> {
+        self.state.as_tile().beaver
+            .as_ref()
+            .and_then(|obj| Beaver::resolve(self.snapshot, obj))
+    }
+
+    /// The resource Spawner on this Tile if present, otherwise None.
+    pub fn spawner(&self) -> Option<Spawner<'a>> {
+        self.state.as_tile().spawner
+            .as_ref()
+            .and_then(|obj| Spawner::resolve(self.snapshot, obj))
+    }
+
+    /// The owner of the Beaver lodge on this Tile, if present, otherwise None.
+    pub fn lodge_owner(&self) -> Option<Player<'a>> {
+        self.state.as_tile().lodge_owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The number of branches dropped on this Tile.
+    pub fn branches(&self) -> i64 {
+        self.state.as_tile().branches
+    }
+
+    /// The number of food dropped on this Tile.
+    pub fn food(&self) -> i64 {
+        self.state.as_tile().food
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Tile<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tile").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Tile<'a>> for GameObject<'a> {
+    fn from(obj: Tile<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Tile<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Tile<'a>, GameObject<'a>> {
+        match obj.state.try_as_tile() {
+            Some(_) => Ok(Tile {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Spawner`](super::Spawner) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Spawner<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Spawner<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Spawner<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_spawner()?;
+        Some(Spawner { snapshot, state })
+    }
+
+    /// What type of resource this is ('food' or 'branches').
+    pub fn type_(&self) -> &'a Resource {
+        &self.state.as_spawner().type_
+    }
+
+    /// How much health this Spawner has, which is used to calculate how much of its resource can
+    /// be harvested.
+    pub fn health(&self) -> i64 {
+        self.state.as_spawner().health
+    }
+
+    /// The Tile this Spawner is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_spawner().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// True if this Spawner has been harvested this turn, and it will not heal at the end of the
+    /// turn, false otherwise.
+    pub fn has_been_harvested(&self) -> bool {
+        self.state.as_spawner().has_been_harvested
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Spawner<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Spawner").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Spawner<'a>> for GameObject<'a> {
+    fn from(obj: Spawner<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Spawner<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Spawner<'a>, GameObject<'a>> {
+        match obj.state.try_as_spawner() {
+            Some(_) => Ok(Spawner {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Beaver`](super::Beaver) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Beaver<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Beaver<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Beaver<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_beaver()?;
+        Some(Beaver { snapshot, state })
+    }
+
+    /// How many moves this Beaver has left this turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_beaver().moves
+    }
+
+    /// The Player that owns and can control this Beaver.
+    pub fn owner(&self) -> Option<Player<'a>> {
+        self.state.as_beaver().owner
+            .as_ref()
+            .and_then(|obj| Player::resolve(self.snapshot, obj))
+    }
+
+    /// The number of actions remaining for the Beaver this turn.
+    pub fn actions(&self) -> i64 {
+        self.state.as_beaver().actions
+    }
+
+    /// The Tile this Beaver is on.
+    pub fn tile(&self) -> Option<Tile<'a>> {
+        self.state.as_beaver().tile
+            .as_ref()
+            .and_then(|obj| Tile::resolve(self.snapshot, obj))
+    }
+
+    /// How much health this Beaver has left.
+    pub fn health(&self) -> i64 {
+        self.state.as_beaver().health
+    }
+
+    /// Number of turns this Beaver is distracted for (0 means not distracted).
+    pub fn turns_distracted(&self) -> i64 {
+        self.state.as_beaver().turns_distracted
+    }
+
+    /// The amount of branches this Beaver is holding.
+    pub fn branches(&self) -> i64 {
+        self.state.as_beaver().branches
+    }
+
+    /// The amount of food this Beaver is holding.
+    pub fn food(&self) -> i64 {
+        self.state.as_beaver().food
+    }
+
+    /// The Job this Beaver was recruited to do.
+    pub fn job(&self) -> Option<Job<'a>> {
+        self.state.as_beaver().job
+            .as_ref()
+            .and_then(|obj| Job::resolve(self.snapshot, obj))
+    }
+
+    /// True if the Beaver has finished being recruited and can do things, False otherwise.
+    pub fn recruited(&self) -> bool {
+        self.state.as_beaver().recruited
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Beaver<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Beaver").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Beaver<'a>> for GameObject<'a> {
+    fn from(obj: Beaver<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Beaver<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Beaver<'a>, GameObject<'a>> {
+        match obj.state.try_as_beaver() {
+            Some(_) => Ok(Beaver {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+/// A read-only view of a [`Job`](super::Job) in a [`Snapshot`].
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    snapshot: &'a State,
+    state: &'a AnyGameObject,
+}
+
+impl<'a> Job<'a> {
+    fn resolve(snapshot: &'a State, obj: &impl ObjectInner) -> Option<Job<'a>> {
+        let state = snapshot.objects.get(&key(obj.game_object()))?;
+        state.try_as_job()?;
+        Some(Job { snapshot, state })
+    }
+
+    /// The Job title.
+    pub fn title(&self) -> &'a Str {
+        &self.state.as_job().title
+    }
+
+    /// The amount of starting health this Job has.
+    pub fn health(&self) -> i64 {
+        self.state.as_job().health
+    }
+
+    /// The number of moves this Job can make per turn.
+    pub fn moves(&self) -> i64 {
+        self.state.as_job().moves
+    }
+
+    /// The number of actions this Job can make per turn.
+    pub fn actions(&self) -> i64 {
+        self.state.as_job().actions
+    }
+
+    /// The amount of damage this Job does per attack.
+    pub fn damage(&self) -> i64 {
+        self.state.as_job().damage
+    }
+
+    /// Scalar for how many branches this Job harvests at once.
+    pub fn chopping(&self) -> i64 {
+        self.state.as_job().chopping
+    }
+
+    /// Scalar for how much food this Job harvests at once.
+    pub fn munching(&self) -> i64 {
+        self.state.as_job().munching
+    }
+
+    /// How many turns a beaver attacked by this Job is distracted by.
+    pub fn distraction_power(&self) -> i64 {
+        self.state.as_job().distraction_power
+    }
+
+    /// How many combined resources a beaver with this Job can hold at once.
+    pub fn carry_limit(&self) -> i64 {
+        self.state.as_job().carry_limit
+    }
+
+    /// How much food this Job costs to recruit.
+    pub fn cost(&self) -> i64 {
+        self.state.as_job().cost
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    pub fn id(&self) -> &'a Str {
+        &self.state.as_game_object().id
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    pub fn game_object_name(&self) -> &'a Str {
+        &self.state.as_game_object().game_object_name
+    }
+
+    /// _Inherited from [`GameObject`]_
+    ///
+    /// Any strings logged will be stored here. Intended for debugging.
+    pub fn logs(&self) -> &'a List<Str> {
+        &self.state.as_game_object().logs
+    }
+}
+
+impl<'a> fmt::Debug for Job<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Job").field("id", &self.state.id()).finish()
+    }
+}
+
+impl<'a> From<Job<'a>> for GameObject<'a> {
+    fn from(obj: Job<'a>) -> GameObject<'a> {
+        GameObject {
+            snapshot: obj.snapshot,
+            state: obj.state,
+        }
+    }
+}
+
+impl<'a> TryFrom<GameObject<'a>> for Job<'a> {
+    type Error = GameObject<'a>;
+
+    fn try_from(obj: GameObject<'a>) -> Result<Job<'a>, GameObject<'a>> {
+        match obj.state.try_as_job() {
+            Some(_) => Ok(Job {
+                snapshot: obj.snapshot,
+                state: obj.state,
+            }),
+            None => Err(obj),
+        }
+    }
+}
+
+#[allow(unused)]
+fn assert_send_sync() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Snapshot>();
+}
@@ -0,0 +1,146 @@
+//! Traits shared by the games, so that helpers can be written once for all of them.
+//!
+//! Every game module implements [`AnyGame`], [`GameObjectHandle`] and [`AnyPlayer`]. Games with
+//! turn limits also implement [`TurnBasedGame`], and games played on a grid of tiles implement
+//! [`GridGame`] and [`GridTile`].
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::error::Error;
+use crate::types::{List, Map, Str};
+
+/// The game itself, in any game.
+pub trait AnyGame: Clone + Eq + Hash + Debug {
+    /// The game's `GameObject` type, which every game object can be cast to.
+    type GameObject: GameObjectHandle;
+
+    /// The game's `Player` type.
+    type Player: AnyPlayer;
+
+    /// A unique identifier for the game instance that is being played.
+    fn session(&self) -> Str;
+
+    /// List of all the players in the game.
+    fn players(&self) -> List<Self::Player>;
+
+    /// A mapping of every game object's ID to the actual game object.
+    fn game_objects(&self) -> Map<Str, Self::GameObject>;
+}
+
+/// A game that ends after a set number of turns.
+pub trait TurnBasedGame: AnyGame {
+    /// The current turn number, starting at 0 for the first player's turn.
+    fn current_turn(&self) -> i64;
+
+    /// The maximum number of turns before the game will automatically end.
+    fn max_turns(&self) -> i64;
+}
+
+/// A game played on a rectangular grid of tiles.
+pub trait GridGame: AnyGame {
+    /// The game's `Tile` type.
+    type Tile: GridTile;
+
+    /// The number of Tiles in the map along the x (horizontal) axis.
+    fn map_width(&self) -> i64;
+
+    /// The number of Tiles in the map along the y (vertical) axis.
+    fn map_height(&self) -> i64;
+
+    /// All the tiles in the map, stored in Row-major order.
+    fn tiles(&self) -> List<Self::Tile>;
+
+    /// Gets the tile at the given position, or `None` if it is off the map.
+    fn get_tile_at(&self, x: i64, y: i64) -> Option<Self::Tile> {
+        let (width, height) = (self.map_width(), self.map_height());
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return None;
+        }
+        self.tiles().get((x + y * width) as usize).cloned()
+    }
+}
+
+/// A handle to a game object of any type, in any game.
+pub trait GameObjectHandle: Clone + Eq + Hash + Debug {
+    /// A unique id for each instance of a GameObject or a sub class. Used for client and server
+    /// communication. Should never change value after being set.
+    fn id(&self) -> Str;
+
+    /// String representing the top level Class that this game object is an instance of. Used for
+    /// reflection to create new instances on clients, but exposed for convenience should AIs want
+    /// this data.
+    fn game_object_name(&self) -> Str;
+
+    /// Any strings logged will be stored here. Intended for debugging.
+    fn logs(&self) -> List<Str>;
+
+    /// Adds a message to this GameObject's logs. Intended for your own debugging purposes, as
+    /// strings stored here are saved in the gamelog.
+    fn log(&self, message: &str) -> Result<(), Error>;
+
+    /// Checks whether this object is still part of the game.
+    fn is_alive(&self) -> bool;
+}
+
+/// A player in any game.
+pub trait AnyPlayer: GameObjectHandle {
+    /// The name of the player.
+    fn name(&self) -> Str;
+
+    /// What type of client this is, e.g. 'Python', 'JavaScript', or some other language. For
+    /// potential data mining purposes.
+    fn client_type(&self) -> Str;
+
+    /// This player's opponent in the game.
+    fn opponent(&self) -> Self;
+
+    /// If the player won the game or not.
+    fn won(&self) -> bool;
+
+    /// If the player lost the game or not.
+    fn lost(&self) -> bool;
+
+    /// The reason why the player won the game.
+    fn reason_won(&self) -> Str;
+
+    /// The reason why the player lost the game.
+    fn reason_lost(&self) -> Str;
+
+    /// The amount of time (in ns) remaining for this AI to send commands.
+    fn time_remaining(&self) -> f64;
+}
+
+/// A tile in a game played on a grid.
+pub trait GridTile: GameObjectHandle {
+    /// The x (horizontal) position of this Tile.
+    fn x(&self) -> i64;
+
+    /// The y (vertical) position of this Tile.
+    fn y(&self) -> i64;
+
+    /// The Tile to the 'North' of this one (x, y-1). None if out of bounds of the map.
+    fn tile_north(&self) -> Option<Self>;
+
+    /// The Tile to the 'East' of this one (x+1, y). None if out of bounds of the map.
+    fn tile_east(&self) -> Option<Self>;
+
+    /// The Tile to the 'South' of this one (x, y+1). None if out of bounds of the map.
+    fn tile_south(&self) -> Option<Self>;
+
+    /// The Tile to the 'West' of this one (x-1, y). None if out of bounds of the map.
+    fn tile_west(&self) -> Option<Self>;
+
+    /// The tiles next to this one, in the order north, east, south, west.
+    fn neighbors(&self) -> Vec<Self> {
+        vec![
+            self.tile_north(),
+            self.tile_east(),
+            self.tile_south(),
+            self.tile_west(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
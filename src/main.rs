@@ -1,13 +1,19 @@
 use std::env;
-use std::io::{self, BufReader, BufWriter, Read, Write};
-use std::net::TcpStream;
+use std::path::{Path, PathBuf};
 use std::process::exit;
 
 use joueur::client::exit::Exit;
-use joueur::client::proto::{ClientEvent, EventSink, EventStream, ServerEvent};
+use joueur::client::gamelog::Gamelog;
+use joueur::client::{AiSettings, Client, ClientConfig, GameSettings};
 use joueur::error::Error;
+use joueur::games::{self, Registry};
 use structopt::StructOpt;
 
+/// Plays a game on a Cadre server with one of the AIs registered in this binary.
+///
+/// No AIs are registered as shipped, so every game is reported as not found until yours is added
+/// to the `registry` function in src/main.rs. Games can still be watched with --spectate, and
+/// replayed with `joueur replay`.
 #[derive(StructOpt)]
 struct Args {
     /// the name of the game you want to play on the server
@@ -48,121 +54,112 @@ struct Args {
     /// (debugging) print IO through the TCP socket to the terminal
     #[structopt(long = "printIO")]
     print_io: bool,
-}
 
-fn main_sub() -> Result<(), Error> {
-    let args = Args::from_iter_safe(env::args_os())?;
-
-    let mut server_parts = args.server.split(":");
-    let server_name = server_parts.next().unwrap();
-    let server_port = match server_parts.next() {
-        Some(port_str) => port_str
-            .parse()
-            .map_err(|e| Error::from_error(e).with_exit(Exit::InvalidArgs))?,
-        None => args.port,
-    };
+    /// watch the game without playing, printing what changes in it
+    #[structopt(long = "spectate")]
+    spectate: bool,
 
-    let socket = TcpStream::connect((server_name, server_port))?;
-    let read_half = socket.try_clone()?;
-    let write_half = socket;
-
-    let mut stream: EventStream<_, ServerEvent> = EventStream::new(BufReader::new(PrintRead {
-        read: read_half,
-        print_io: args.print_io,
-    }));
-    let mut sink: EventSink<_, ClientEvent> = EventSink::new(BufWriter::new(PrintWrite {
-        write: write_half,
-        print_io: args.print_io,
-    }));
-
-    sink.send(ClientEvent::Alias(args.game))?;
-    let game_name = match stream.recv().ok_or(Exit::DisconnectedUnexpectedly)?? {
-        ServerEvent::Named(name) => name,
-        event => return Err(unexpected_event(event)),
-    };
+    /// write the outcome of the game as JSON to this file once it is over
+    #[structopt(long = "outcome", parse(from_os_str), conflicts_with = "spectate")]
+    outcome: Option<PathBuf>,
+}
 
-    sink.send(ClientEvent::Play {
-        game_name,
-        requested_session: args.session,
-        client_type: "Rust".into(),
-        player_name: args.name.expect("todo: get name from ai"),
-        player_index: args.index,
-        password: args.password,
-        game_settings: args.game_settings,
-        spectating: None,
-    })?;
-    let (game_name, game_session, constants) =
-        match stream.recv().ok_or(Exit::DisconnectedUnexpectedly)?? {
-            ServerEvent::Lobbied {
-                game_name,
-                game_session,
-                constants,
-            } => (game_name, game_session, constants),
-            event => return Err(unexpected_event(event)),
-        };
-
-    let initial_delta = match stream.recv().ok_or(Exit::DisconnectedUnexpectedly)?? {
-        ServerEvent::Delta(delta) => delta,
-        event => return Err(unexpected_event(event)),
-    };
-    let player_id = match stream.recv().ok_or(Exit::DisconnectedUnexpectedly)?? {
-        ServerEvent::Start { player_id } => player_id,
-        event => return Err(unexpected_event(event)),
-    };
+/// Replays a gamelog saved by the server through your AI, without connecting to a server.
+///
+/// Games with no AI registered are replayed like --spectate watches them, printing what changes.
+#[derive(StructOpt)]
+#[structopt(name = "joueur replay")]
+struct ReplayArgs {
+    /// the uncompressed gamelog file to replay
+    gamelog: PathBuf,
+
+    /// the index of the player the AI plays as, with 0 being the first player
+    #[structopt(short = "i", long = "index", default_value = "0")]
+    index: usize,
+}
 
-    //TODO hand off to game runtime
+fn main_sub() -> Result<(), Error> {
+    if env::args_os().nth(1).is_some_and(|arg| arg == "replay") {
+        return replay(ReplayArgs::from_iter_safe(env::args_os().skip(1))?);
+    }
 
-    Ok(())
-}
+    let args = Args::from_iter_safe(env::args_os())?;
 
-fn unexpected_event(event: ServerEvent) -> Error {
-    match event {
-        ServerEvent::Fatal { message } => {
-            let message = message.unwrap_or_else(|| "unknown cause".into());
-            (Exit::FatalEvent, message).into()
-        }
-        event => (Exit::UnknownEventFromServer, format!("{:?}", event)).into(),
+    let mut config = ClientConfig::new(&args.game)
+        .server(&args.server)
+        .port(args.port)
+        .session(&args.session)
+        .print_io(args.print_io);
+    if let Some(name) = &args.name {
+        config = config.name(name);
+    }
+    if let Some(index) = args.index {
+        config = config.index(index);
+    }
+    if let Some(password) = &args.password {
+        config = config.password(password);
+    }
+    if let Some(game_settings) = &args.game_settings {
+        config = config.game_settings(GameSettings::parse(game_settings)?);
+    }
+    if let Some(ai_settings) = &args.ai_settings {
+        config = config.ai_settings(AiSettings::parse(ai_settings)?);
     }
-}
 
-struct PrintRead<R> {
-    read: R,
-    print_io: bool,
-}
+    if let Some(path) = &args.outcome {
+        check_outcome_path(path)?;
+    }
 
-impl<R> Read for PrintRead<R>
-where
-    R: Read,
-{
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let n = self.read.read(buf)?;
-        if self.print_io && n > 0 {
-            eprintln!("FROM SERVER <-- {}", String::from_utf8_lossy(&buf[..n]));
-        }
-        Ok(n)
+    let client = Client::new(config);
+    if args.spectate {
+        return client.spectate();
     }
+    let outcome = client.play(&registry())?;
+    if let Some(path) = &args.outcome {
+        outcome.save(path)?;
+    }
+    Ok(())
 }
 
-struct PrintWrite<W> {
-    write: W,
-    print_io: bool,
+/// Checks that the outcome could be written once the game is over, so that a path into a
+/// directory that doesn't exist is reported as an invalid argument before playing.
+fn check_outcome_path(path: &Path) -> Result<(), Error> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    if dir.is_dir() {
+        Ok(())
+    } else {
+        let message = format!("{} is not a directory", dir.display());
+        Err((Exit::InvalidArgs, message).into())
+    }
 }
 
-impl<W> Write for PrintWrite<W>
-where
-    W: Write,
-{
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let n = self.write.write(buf)?;
-        if self.print_io && n > 0 {
-            eprintln!("TO SERVER --> {}", String::from_utf8_lossy(&buf[..n]));
-        }
-        Ok(n)
+fn replay(args: ReplayArgs) -> Result<(), Error> {
+    let gamelog = Gamelog::open(&args.gamelog)?;
+    println!(
+        "Replaying game '{}' from session '{}'",
+        gamelog.game_name, gamelog.game_session
+    );
+    let registry = registry();
+    if registry.contains(&gamelog.game_name) {
+        registry
+            .session(&gamelog.game_name)?
+            .replay(&gamelog, args.index)
+    } else {
+        games::printer(&gamelog.game_name)?.replay(&gamelog)
     }
+}
 
-    fn flush(&mut self) -> io::Result<()> {
-        self.write.flush()
-    }
+/// The AIs this client can play with.
+///
+/// Each game's module has a `register` function for adding an AI that implements its `AI` trait,
+/// for example `joueur::games::chess::register(&mut registry, MyChessAi::new)`. Games without an
+/// AI are reported as not found once the server has resolved the game name, and are replayed
+/// without an AI.
+fn registry() -> Registry {
+    Registry::new()
 }
 
 fn main() -> ! {
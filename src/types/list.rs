@@ -3,15 +3,18 @@
 use std::borrow::{Borrow, BorrowMut};
 use std::iter::FromIterator;
 use std::ops;
+use std::slice;
 use std::sync::Arc;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// An internally reference-counted list structure.
 ///
 /// This type also dereferences to [`[T]`][slice], meaning you can use this anywhere where a slice
 /// is accepted. This includes calling slice methods such as `get`, `sort`, and `iter`.
 ///
 /// [slice]: https://doc.rust-lang.org/stable/std/primitive.slice.html
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct List<T> {
     inner: Arc<Vec<T>>,
 }
@@ -27,6 +30,29 @@ impl<T> List<T> {
     {
         &mut *Arc::make_mut(&mut self.inner)
     }
+
+    /// Appends an element to the end of the list.
+    pub fn push(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        Arc::make_mut(&mut self.inner).push(value)
+    }
+
+    /// Removes the last element of the list and returns it, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        Arc::make_mut(&mut self.inner).pop()
+    }
+}
+
+// Derived `Default` would needlessly require `T: Default`.
+impl<T> Default for List<T> {
+    fn default() -> List<T> {
+        Vec::new().into()
+    }
 }
 
 impl<T> ops::Deref for List<T> {
@@ -102,12 +128,33 @@ impl<T> FromIterator<T> for List<T> {
     }
 }
 
+impl<T> Extend<T> for List<T>
+where
+    T: Clone,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Arc::make_mut(&mut self.inner).extend(iter)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T> PartialEq<[T]> for List<T>
 where
     T: PartialEq,
 {
     fn eq(&self, other: &[T]) -> bool {
-        &*self.inner == &other
+        *self.inner == other
     }
 }
 
@@ -119,3 +166,21 @@ where
         &*self.inner == other
     }
 }
+
+impl<T> Serialize for List<T>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for List<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(List::from)
+    }
+}
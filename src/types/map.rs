@@ -1,25 +1,91 @@
 //! Associated types for `Map`.
 
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::hash_map::{self, HashMap};
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::sync::Arc;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// An internally reference-counted map structure.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Map<K: Hash + Eq, V> {
     inner: Arc<HashMap<K, V>>,
 }
 
 impl<K: Hash + Eq, V> Map<K, V> {
-    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Hash + Eq + ?Sized,
     {
         self.inner.get(key)
     }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterates over the entries of the map in an arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        self.inner.iter()
+    }
+
+    /// Iterates over the keys of the map in an arbitrary order.
+    pub fn keys(&self) -> hash_map::Keys<'_, K, V> {
+        self.inner.keys()
+    }
+
+    /// Iterates over the values of the map in an arbitrary order.
+    pub fn values(&self) -> hash_map::Values<'_, K, V> {
+        self.inner.values()
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Clone,
+        V: Clone,
+        Q: Hash + Eq + ?Sized,
+    {
+        Arc::make_mut(&mut self.inner).get_mut(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        Arc::make_mut(&mut self.inner).insert(key, value)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Clone,
+        V: Clone,
+        Q: Hash + Eq + ?Sized,
+    {
+        Arc::make_mut(&mut self.inner).remove(key)
+    }
+}
+
+// Derived `Default` would needlessly require `K: Default` and `V: Default`.
+impl<K: Hash + Eq, V> Default for Map<K, V> {
+    fn default() -> Map<K, V> {
+        HashMap::new().into()
+    }
 }
 
 impl<K: Hash + Eq, V> From<HashMap<K, V>> for Map<K, V> {
@@ -30,6 +96,12 @@ impl<K: Hash + Eq, V> From<HashMap<K, V>> for Map<K, V> {
     }
 }
 
+impl<K: Hash + Eq, V> AsRef<HashMap<K, V>> for Map<K, V> {
+    fn as_ref(&self) -> &HashMap<K, V> {
+        &self.inner
+    }
+}
+
 impl<K: Hash + Eq, V> FromIterator<(K, V)> for Map<K, V> {
     fn from_iter<I>(iter: I) -> Map<K, V>
     where
@@ -48,3 +120,45 @@ where
         &*self.inner == other
     }
 }
+
+impl<K, V> Extend<(K, V)> for Map<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Arc::make_mut(&mut self.inner).extend(iter)
+    }
+}
+
+impl<'a, K: Hash + Eq, V> IntoIterator for &'a Map<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> Serialize for Map<K, V>
+where
+    K: Hash + Eq + Serialize,
+    V: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Map<K, V>
+where
+    K: Hash + Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HashMap::deserialize(deserializer).map(Map::from)
+    }
+}
@@ -1,10 +1,13 @@
 //! Associated types for `Str`.
 
 use std::borrow::{Borrow, BorrowMut};
+use std::fmt;
 use std::iter::FromIterator;
 use std::ops;
 use std::sync::Arc;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// An internally reference-counted Unicode string.
 ///
 /// This type also dereferences to [`str`][str], meaning you can use it anywhere where string
@@ -19,7 +22,7 @@ pub struct Str {
 
 impl Str {
     pub fn as_slice(&self) -> &str {
-        &*self.inner
+        &self.inner
     }
 
     pub fn as_mut_slice(&mut self) -> &mut str {
@@ -79,6 +82,12 @@ impl From<String> for Str {
     }
 }
 
+impl fmt::Display for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
 impl FromIterator<char> for Str {
     fn from_iter<I>(iter: I) -> Str
     where
@@ -96,7 +105,7 @@ impl Default for Str {
 
 impl PartialEq<str> for Str {
     fn eq(&self, other: &str) -> bool {
-        &*self.inner == other
+        *self.inner == other
     }
 }
 
@@ -105,3 +114,15 @@ impl PartialEq<String> for Str {
         &*self.inner == other
     }
 }
+
+impl Serialize for Str {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Str {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Str::from)
+    }
+}
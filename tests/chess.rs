@@ -0,0 +1,585 @@
+//! Plays scripted games of chess against the mock server.
+#![cfg(feature = "chess")]
+
+mod mock;
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use joueur::client::exit::Exit;
+use joueur::client::gamelog::Gamelog;
+use joueur::client::proto::{ClientEvent, ServerEvent};
+use joueur::client::settings::GameSettings;
+use joueur::client::{AiSettings, Client, ClientConfig, GameOutcome};
+use joueur::error::Error;
+use joueur::games::chess::{self, Changes, Game, Player, Snapshot, AI};
+use joueur::games::Registry;
+use serde_json::{json, Value};
+
+use mock::{MockClient, MockServer, Run};
+
+/// An AI that records everything that happens to it.
+struct Recorder {
+    events: Arc<Mutex<Vec<String>>>,
+    next_move: &'static str,
+}
+
+impl Recorder {
+    fn record(&self, event: String) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl AI for Recorder {
+    fn start(&mut self, game: &Game, player: &Player) -> Result<(), Error> {
+        self.record(format!(
+            "start {} vs {} with {} players",
+            player.color().as_str(),
+            player.opponent().color().as_str(),
+            game.players().len(),
+        ));
+        Ok(())
+    }
+
+    fn game_updated(
+        &mut self,
+        game: &Game,
+        _player: &Player,
+        changes: &Changes,
+    ) -> Result<(), Error> {
+        let sorted =
+            |fields: &HashSet<&'static str>| fields.iter().copied().collect::<BTreeSet<_>>();
+        let objects: BTreeMap<_, _> = changes
+            .changed()
+            .map(|(id, fields)| (id, sorted(fields)))
+            .collect();
+        self.record(format!(
+            "update {} {:?} {:?}",
+            game.fen(),
+            sorted(changes.game_fields()),
+            objects,
+        ));
+        Ok(())
+    }
+
+    fn invalid(&mut self, message: &str) {
+        self.record(format!("invalid {}", message));
+    }
+
+    fn end(&mut self, won: bool, reason: &str) {
+        self.record(format!("end {} {}", won, reason));
+    }
+
+    fn make_move(&mut self, _game: &Game, player: &Player) -> Result<String, Error> {
+        player.log("thinking")?;
+        let logs: Vec<_> = player.logs().iter().map(|log| log.to_string()).collect();
+        self.record(format!("move with logs {:?}", logs));
+        Ok(self.next_move.to_string())
+    }
+}
+
+fn initial_state() -> Value {
+    state_with_players("0", "1")
+}
+
+/// The state of a game that has just started, between players with the given ids.
+fn state_with_players(white: &str, black: &str) -> Value {
+    let player = |id: &str, opponent: &str, color: &str| {
+        json!({
+            "id": id,
+            "gameObjectName": "Player",
+            "logs": {"&LEN": 0},
+            "name": format!("Player {}", id),
+            "clientType": "Rust",
+            "won": false,
+            "lost": false,
+            "reasonWon": "",
+            "reasonLost": "",
+            "timeRemaining": 9e10,
+            "opponent": {"id": opponent},
+            "color": color,
+        })
+    };
+    json!({
+        "gameObjects": {
+            white: player(white, black, "white"),
+            black: player(black, white, "black"),
+        },
+        "players": {"&LEN": 2, "0": {"id": white}, "1": {"id": black}},
+        "session": "mock",
+        "history": {"&LEN": 0},
+        "fen": "start",
+    })
+}
+
+/// Plays as player "0" against a server running `script`, returning what the AI recorded.
+fn play<F>(script: F) -> (Result<GameOutcome, Error>, Vec<String>)
+where
+    F: FnOnce(&mut MockClient) + Send + 'static,
+{
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut registry = Registry::new();
+    let recorded = Arc::clone(&events);
+    chess::register(&mut registry, move || Recorder {
+        events: Arc::clone(&recorded),
+        next_move: "e2e4",
+    });
+
+    let result = mock::play(&registry, "Chess", initial_state(), script);
+    let events = events.lock().unwrap().clone();
+    (result, events)
+}
+
+fn answer_log(client: &mut MockClient, run: Run) {
+    assert_eq!(run.caller, "0");
+    assert_eq!(run.function_name, "log");
+    assert_eq!(run.args.get("message"), Some(&json!("thinking")));
+    client.delta(json!({
+        "gameObjects": {"0": {"logs": {"&LEN": 1, "0": "thinking"}}},
+    }));
+    client.ran(Value::Null);
+}
+
+#[test]
+fn plays_a_game() {
+    let (result, events) = play(|client| {
+        let returned = client.order_with(0, "makeMove", vec![], answer_log);
+        assert_eq!(returned, json!("e2e4"));
+
+        client.delta(json!({
+            "fen": "after e2e4",
+            "history": {"&LEN": 1, "0": "e2e4"},
+            "gameObjects": {
+                "0": {"won": true, "reasonWon": "checkmate"},
+                "1": {"lost": true, "reasonLost": "checkmate"},
+            },
+        }));
+        client.over();
+        client.expect_disconnect();
+    });
+
+    let outcome = result.unwrap();
+    assert_eq!(
+        outcome,
+        GameOutcome {
+            game_name: "Chess".to_string(),
+            game_session: "mock".to_string(),
+            won: true,
+            lost: false,
+            reason_won: "checkmate".to_string(),
+            reason_lost: String::new(),
+            final_turn: None,
+            gamelog_url: None,
+            visualizer_url: None,
+        }
+    );
+    assert_eq!(
+        events,
+        vec![
+            "start white vs black with 2 players",
+            "move with logs [\"thinking\"]",
+            "update after e2e4 {\"fen\", \"history\"} \
+             {\"0\": {\"logs\", \"reason_won\", \"won\"}, \"1\": {\"lost\", \"reason_lost\"}}",
+            "end true checkmate",
+        ]
+    );
+}
+
+#[test]
+fn reports_invalid_runs_after_the_order() {
+    let (result, events) = play(|client| {
+        client.order_with(3, "makeMove", vec![], |client, run| {
+            client.invalid("not your turn");
+            answer_log(client, run);
+        });
+        client.over();
+    });
+
+    result.unwrap();
+    assert_eq!(
+        events[1..],
+        [
+            "move with logs [\"thinking\"]",
+            "invalid not your turn",
+            "end false "
+        ]
+    );
+}
+
+#[test]
+fn fatal_events_end_the_game() {
+    let (result, events) = play(|client| {
+        client.send(ServerEvent::Fatal {
+            message: Some("server exploded".to_string()),
+        });
+    });
+
+    let err = result.unwrap_err();
+    assert_eq!(err.exit(), Some(Exit::FatalEvent));
+    assert!(err.to_string().contains("server exploded"));
+    assert_eq!(events, ["start white vs black with 2 players"]);
+}
+
+#[test]
+fn unknown_attributes_are_skipped() {
+    let (result, events) = play(|client| {
+        client.delta(json!({
+            "fen": "newer server",
+            "variant": "chess960",
+            "gameObjects": {"0": {"elo": 1500}},
+        }));
+        client.over();
+    });
+
+    result.unwrap();
+    assert_eq!(
+        events[1..],
+        ["update newer server {\"fen\"} {}", "end false "]
+    );
+}
+
+#[test]
+fn mistyped_attributes_fail_to_merge() {
+    let (result, _) = play(|client| {
+        client.delta(json!({"fen": 5}));
+        client.expect_disconnect();
+    });
+
+    assert_eq!(result.unwrap_err().exit(), Some(Exit::DeltaMergeFailure));
+}
+
+#[test]
+fn unknown_orders_are_rejected() {
+    let (result, _) = play(|client| {
+        client.send(ServerEvent::Order {
+            name: "dance".to_string(),
+            index: 0,
+            args: vec![],
+        });
+        client.expect_disconnect();
+    });
+
+    assert_eq!(
+        result.unwrap_err().exit(),
+        Some(Exit::UnknownEventFromServer)
+    );
+}
+
+#[test]
+fn disconnects_are_reported() {
+    let (result, _) = play(|_| {});
+
+    assert_eq!(
+        result.unwrap_err().exit(),
+        Some(Exit::DisconnectedUnexpectedly)
+    );
+}
+
+#[test]
+fn ais_only_play_their_own_game() {
+    let server = MockServer::bind();
+    let config = ClientConfig::new("checkers")
+        .server("127.0.0.1")
+        .port(server.port());
+    let server = server.spawn(|client| {
+        client.recv();
+        client.send(ServerEvent::Named("Checkers".to_string()));
+        client.expect_disconnect();
+    });
+
+    let ai = Recorder {
+        events: Arc::default(),
+        next_move: "e2e4",
+    };
+    let result = chess::play(&Client::new(config), ai);
+    server.join().expect("mock server failed");
+    assert_eq!(result.unwrap_err().exit(), Some(Exit::GameNotFound));
+}
+
+#[test]
+fn starting_boards_are_known_settings() {
+    let settings = GameSettings::new().set("fen", "8/8/8/8/8/8/8/K6k w - - 0 1");
+    assert_eq!(
+        settings.check(chess::SETTINGS).unwrap(),
+        Vec::<String>::new()
+    );
+}
+
+/// An AI that reads its search depth from its settings.
+struct Configured {
+    depth: Arc<Mutex<Option<u32>>>,
+}
+
+impl AI for Configured {
+    fn start(&mut self, game: &Game, _player: &Player) -> Result<(), Error> {
+        let settings = game.ai_settings()?;
+        *self.depth.lock().unwrap() = Some(settings.get_or("depth", 1)?);
+        Ok(())
+    }
+
+    fn make_move(&mut self, _game: &Game, _player: &Player) -> Result<String, Error> {
+        Ok("e2e4".to_string())
+    }
+}
+
+#[test]
+fn replays_a_gamelog() {
+    let gamelog = json!({
+        "gameName": "Chess",
+        "gameSession": "saved",
+        "constants": mock::constants(),
+        "deltas": [
+            {"type": "start", "game": initial_state()},
+            {
+                "type": "finished",
+                "data": {"returned": "e2e4"},
+                "game": {"fen": "after e2e4", "history": {"&LEN": 1, "0": "e2e4"}},
+            },
+            {
+                "type": "over",
+                "game": {
+                    "gameObjects": {
+                        "0": {"lost": true, "reasonLost": "resigned"},
+                        "1": {"won": true, "reasonWon": "opponent resigned"},
+                    },
+                },
+            },
+        ],
+    });
+    let gamelog = Gamelog::from_reader(gamelog.to_string().as_bytes()).unwrap();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let ai = Recorder {
+        events: Arc::clone(&events),
+        next_move: "e2e4",
+    };
+    chess::replay(&gamelog, 1, ai).unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "start black vs white with 2 players",
+            "update after e2e4 {\"fen\", \"history\"} {}",
+            "update after e2e4 {} \
+             {\"0\": {\"lost\", \"reason_lost\"}, \"1\": {\"reason_won\", \"won\"}}",
+            "end true opponent resigned",
+        ]
+    );
+
+    let ai = Recorder {
+        events: Arc::clone(&events),
+        next_move: "e2e4",
+    };
+    let result = chess::replay(&gamelog, 2, ai);
+    assert_eq!(
+        result.err().and_then(|err| err.exit()),
+        Some(Exit::InvalidArgs)
+    );
+}
+
+/// An AI that sorts the players when the game starts.
+#[derive(Default)]
+struct Sorter {
+    ids: Vec<String>,
+}
+
+impl AI for Sorter {
+    fn start(&mut self, game: &Game, _player: &Player) -> Result<(), Error> {
+        let mut players = game.players().to_vec();
+        players.sort();
+        self.ids = players
+            .iter()
+            .map(|player| player.id().to_string())
+            .collect();
+        Ok(())
+    }
+
+    fn make_move(&mut self, _game: &Game, _player: &Player) -> Result<String, Error> {
+        Ok("e2e4".to_string())
+    }
+}
+
+#[test]
+fn handles_are_ordered_by_numeric_id() {
+    let gamelog = json!({
+        "gameName": "Chess",
+        "gameSession": "saved",
+        "constants": mock::constants(),
+        "deltas": [{"type": "start", "game": state_with_players("10", "9")}],
+    });
+    let gamelog = Gamelog::from_reader(gamelog.to_string().as_bytes()).unwrap();
+
+    let sorter = chess::replay(&gamelog, 0, Sorter::default()).unwrap();
+    assert_eq!(sorter.ids, ["9", "10"]);
+}
+
+#[test]
+fn ais_can_read_their_settings() {
+    let server = MockServer::bind();
+    let config = ClientConfig::new("Chess")
+        .server("127.0.0.1")
+        .port(server.port())
+        .ai_settings(AiSettings::parse("depth=4&style=aggressive").unwrap());
+    let server = server.spawn(|client| {
+        client.lobby("Chess");
+        client.start(initial_state(), "0");
+        client.send(ServerEvent::Fatal { message: None });
+    });
+
+    let depth = Arc::new(Mutex::new(None));
+    let ai = Configured {
+        depth: Arc::clone(&depth),
+    };
+    let result = chess::play(&Client::new(config), ai);
+    server.join().expect("mock server failed");
+    assert_eq!(result.unwrap_err().exit(), Some(Exit::FatalEvent));
+    assert_eq!(*depth.lock().unwrap(), Some(4));
+}
+
+#[test]
+fn spectators_watch_without_playing() {
+    let server = MockServer::bind();
+    let config = ClientConfig::new("chess")
+        .server("127.0.0.1")
+        .port(server.port())
+        .index(0);
+    let server = server.spawn(|client| {
+        client.recv();
+        client.send(ServerEvent::Named("Chess".to_string()));
+        match client.recv() {
+            ClientEvent::Play {
+                spectating,
+                player_index,
+                ..
+            } => {
+                assert_eq!(spectating, Some(true));
+                assert_eq!(player_index, None);
+            }
+            event => panic!("expected play, got {:?}", event),
+        }
+        client.send(ServerEvent::Lobbied {
+            game_name: "Chess".to_string(),
+            game_session: "mock".to_string(),
+            constants: mock::constants(),
+        });
+        client.start(initial_state(), "");
+        client.delta(json!({"fen": "moved"}));
+        client.over();
+    });
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&updates);
+    let result = chess::spectate(&Client::new(config), move |game, changes| {
+        let created = changes.created().count();
+        recorded
+            .lock()
+            .unwrap()
+            .push(format!("{} with {} created", game.fen(), created));
+        Ok(())
+    });
+    server.join().expect("mock server failed");
+    result.unwrap();
+    assert_eq!(
+        *updates.lock().unwrap(),
+        ["start with 2 created", "moved with 0 created"]
+    );
+}
+
+/// An AI that takes a snapshot when the game starts, and compares it to the live game on each
+/// update from another thread.
+struct Snapshotter {
+    snapshot: Option<Snapshot>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl AI for Snapshotter {
+    fn start(&mut self, game: &Game, _player: &Player) -> Result<(), Error> {
+        self.snapshot = Some(game.snapshot()?);
+        Ok(())
+    }
+
+    fn game_updated(
+        &mut self,
+        game: &Game,
+        player: &Player,
+        _changes: &Changes,
+    ) -> Result<(), Error> {
+        let snapshot = self.snapshot.clone().expect("snapshot taken at start");
+        let old = thread::spawn(move || {
+            let game = snapshot.game();
+            let player = &game.players()[0];
+            format!("{} {} {}", game.fen(), game.history().len(), player.won())
+        })
+        .join()
+        .expect("snapshot reader panicked");
+        let new = format!("{} {} {}", game.fen(), game.history().len(), player.won());
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} -> {}", old, new));
+        Ok(())
+    }
+
+    fn make_move(&mut self, _game: &Game, _player: &Player) -> Result<String, Error> {
+        Ok("e2e4".to_string())
+    }
+}
+
+#[test]
+fn snapshots_keep_their_state_after_deltas() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    let mut registry = Registry::new();
+    chess::register(&mut registry, move || Snapshotter {
+        snapshot: None,
+        events: Arc::clone(&recorded),
+    });
+
+    let result = mock::play(&registry, "Chess", initial_state(), |client| {
+        client.delta(json!({
+            "fen": "after e2e4",
+            "history": {"&LEN": 1, "0": "e2e4"},
+            "gameObjects": {"0": {"won": true, "reasonWon": "checkmate"}},
+        }));
+        client.over();
+    });
+
+    result.unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["start 0 false -> after e2e4 1 true"]
+    );
+}
+
+/// An AI whose `make_move` fails with an error of its own.
+struct Failing(fn() -> Result<String, Error>);
+
+impl AI for Failing {
+    fn make_move(&mut self, _game: &Game, _player: &Player) -> Result<String, Error> {
+        (self.0)()
+    }
+}
+
+#[test]
+fn ai_errors_exit_as_ai_errored() {
+    let failures: [fn() -> Result<String, Error>; 3] = [
+        || Err("no moves left".into()),
+        || Ok("e2e4".parse::<u32>()?.to_string()),
+        || Err(io::Error::new(io::ErrorKind::NotFound, "opening book missing").into()),
+    ];
+    for failure in failures {
+        let mut registry = Registry::new();
+        chess::register(&mut registry, move || Failing(failure));
+        let result = mock::play(&registry, "Chess", initial_state(), |client| {
+            client.send(ServerEvent::Order {
+                name: "makeMove".to_string(),
+                index: 0,
+                args: vec![],
+            });
+            client.expect_disconnect();
+        });
+
+        assert_eq!(result.unwrap_err().exit(), Some(Exit::AiErrored));
+    }
+}
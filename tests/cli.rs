@@ -0,0 +1,182 @@
+//! Runs the `joueur` binary against the mock server.
+
+mod mock;
+
+use std::process::{Command, Output};
+
+use joueur::client::exit;
+use joueur::client::proto::{ClientEvent, ServerEvent};
+#[cfg(feature = "chess")]
+use serde_json::{json, Value};
+
+use mock::MockServer;
+
+fn joueur(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_joueur"))
+        .args(args)
+        .output()
+        .expect("failed to run joueur")
+}
+
+#[test]
+fn games_without_an_ai_are_not_found() {
+    let server = MockServer::bind();
+    let port = server.port().to_string();
+    let server = server.spawn(|client| {
+        match client.recv() {
+            ClientEvent::Alias(alias) => assert_eq!(alias, "chess"),
+            event => panic!("expected alias, got {:?}", event),
+        }
+        client.send(ServerEvent::Named("Chess".to_string()));
+        client.expect_disconnect();
+    });
+
+    let output = joueur(&["chess", "--server", "127.0.0.1", "--port", &port]);
+    server.join().expect("mock server failed");
+    assert_eq!(output.status.code(), Some(exit::GAME_NOT_FOUND));
+}
+
+#[test]
+fn fatal_events_set_the_exit_code() {
+    let server = MockServer::bind();
+    let address = format!("127.0.0.1:{}", server.port());
+    let server = server.spawn(|client| {
+        client.recv();
+        client.send(ServerEvent::Fatal { message: None });
+    });
+
+    let output = joueur(&["chess", "--server", &address]);
+    server.join().expect("mock server failed");
+    assert_eq!(output.status.code(), Some(exit::FATAL_EVENT));
+}
+
+#[test]
+fn missing_gamelogs_are_invalid_args() {
+    let output = joueur(&["replay", "no/such/gamelog.json"]);
+    assert_eq!(output.status.code(), Some(exit::INVALID_ARGS));
+}
+
+#[test]
+fn malformed_ai_settings_are_invalid_args() {
+    let output = joueur(&["chess", "--aiSettings", "depth"]);
+    assert_eq!(output.status.code(), Some(exit::INVALID_ARGS));
+}
+
+#[test]
+fn malformed_game_settings_are_invalid_args() {
+    let output = joueur(&["chess", "--gameSettings", "maxTurns=%zz"]);
+    assert_eq!(output.status.code(), Some(exit::INVALID_ARGS));
+}
+
+/// The state of a chess game that has just started.
+#[cfg(feature = "chess")]
+fn chess_state() -> Value {
+    let player = |id: &str, opponent: &str, color: &str| {
+        json!({
+            "id": id,
+            "gameObjectName": "Player",
+            "logs": {"&LEN": 0},
+            "name": color,
+            "clientType": "Rust",
+            "won": false,
+            "lost": false,
+            "reasonWon": "",
+            "reasonLost": "",
+            "timeRemaining": 9e10,
+            "opponent": {"id": opponent},
+            "color": color,
+        })
+    };
+    json!({
+        "gameObjects": {"0": player("0", "1", "white"), "1": player("1", "0", "black")},
+        "players": {"&LEN": 2, "0": {"id": "0"}, "1": {"id": "1"}},
+        "session": "mock",
+        "history": {"&LEN": 0},
+        "fen": "start",
+    })
+}
+
+/// A move that ends the chess game, as a delta.
+#[cfg(feature = "chess")]
+fn chess_move() -> Value {
+    json!({
+        "gameObjects": {"1": {"won": true}},
+        "fen": "moved",
+    })
+}
+
+#[cfg(feature = "chess")]
+const CHESS_CHANGES: [&str; 5] = [
+    "Game changed: fen, history, players, session",
+    "Player #0 created",
+    "Player #1 created",
+    "Game changed: fen",
+    "Player #1 changed: won",
+];
+
+#[test]
+#[cfg(feature = "chess")]
+fn spectators_print_what_changes() {
+    let server = MockServer::bind();
+    let port = server.port().to_string();
+    let server = server.spawn(|client| {
+        assert_eq!(client.lobby("Chess"), "Spectator");
+        client.start(chess_state(), "");
+        client.delta(chess_move());
+        client.over();
+    });
+
+    let output = joueur(&[
+        "chess",
+        "--spectate",
+        "--server",
+        "127.0.0.1",
+        "--port",
+        &port,
+    ]);
+    server.join().expect("mock server failed");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let printed: Vec<_> = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Game"))
+        .collect();
+    assert_eq!(printed, CHESS_CHANGES);
+}
+
+#[test]
+#[cfg(feature = "chess")]
+fn gamelogs_without_an_ai_are_printed() {
+    let gamelog = json!({
+        "gameName": "Chess",
+        "gameSession": "saved",
+        "constants": mock::constants(),
+        "deltas": [
+            {"type": "start", "game": chess_state()},
+            {"type": "finished", "game": chess_move()},
+            {"type": "over"},
+        ],
+    });
+    let path = std::env::temp_dir().join(format!("joueur-gamelog-{}.json", std::process::id()));
+    std::fs::write(&path, gamelog.to_string()).unwrap();
+
+    let output = joueur(&["replay", path.to_str().unwrap()]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let printed: Vec<_> = stdout.lines().collect();
+    assert_eq!(printed[0], "Replaying game 'Chess' from session 'saved'");
+    assert_eq!(printed[1..], CHESS_CHANGES);
+}
+
+#[test]
+fn outcomes_must_go_in_a_directory() {
+    let output = joueur(&["chess", "--outcome", "no/such/dir/outcome.json"]);
+    assert_eq!(output.status.code(), Some(exit::INVALID_ARGS));
+}
+
+#[test]
+fn spectators_have_no_outcome() {
+    let output = joueur(&["chess", "--spectate", "--outcome", "outcome.json"]);
+    assert_eq!(output.status.code(), Some(exit::INVALID_ARGS));
+}
@@ -0,0 +1,96 @@
+//! Checks the codegen binary's name mangling, and that the generated game modules are up to date.
+
+#[path = "../src/bin/codegen/names.rs"]
+mod names;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use names::{sanitize, underscore, variant};
+
+#[test]
+fn names_are_underscored() {
+    let cases = [
+        ("game", "game"),
+        ("gameObject", "game_object"),
+        ("GameObject", "game_object"),
+        ("BroodMother", "brood_mother"),
+        ("tileX", "tile_x"),
+        ("maxTurns", "max_turns"),
+        ("unitHP", "unit_hp"),
+        ("HPUnit", "hp_unit"),
+        ("jobID", "job_id"),
+        ("tile2X", "tile2_x"),
+        ("turnsToDie", "turns_to_die"),
+    ];
+    for (name, expected) in cases {
+        assert_eq!(underscore(name), expected, "{:?}", name);
+    }
+}
+
+#[test]
+fn keywords_are_sanitized() {
+    assert_eq!(sanitize("move"), "move_");
+    assert_eq!(sanitize("type"), "type_");
+    assert_eq!(sanitize("tile"), "tile");
+}
+
+#[test]
+fn literals_become_variants() {
+    assert_eq!(variant("white"), "White");
+    assert_eq!(variant("redium ore"), "RediumOre");
+    assert_eq!(variant("north-west"), "NorthWest");
+    assert_eq!(variant("blueprint2"), "Blueprint2");
+    assert_eq!(variant(""), "Empty");
+}
+
+#[test]
+fn generated_games_are_up_to_date() {
+    let out = std::env::temp_dir().join(format!("joueur-codegen-{}", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_codegen"))
+        .arg("--out")
+        .arg(&out)
+        .status()
+        .expect("failed to run codegen");
+    assert!(status.success());
+
+    let games = Path::new("src/games");
+    let mut stale = Vec::new();
+    compare(&out.join("mod.rs"), &games.join("mod.rs"), &mut stale);
+    for entry in fs::read_dir(&out).unwrap() {
+        let generated = entry.unwrap().path();
+        if generated.is_dir() {
+            let committed = games.join(generated.file_name().unwrap());
+            compare_dirs(&generated, &committed, &mut stale);
+        }
+    }
+    fs::remove_dir_all(&out).unwrap();
+    assert!(
+        stale.is_empty(),
+        "run `cargo run --bin codegen` to regenerate {:?}",
+        stale
+    );
+}
+
+/// Compares the generated files of a game with the committed ones, both ways.
+fn compare_dirs(generated: &Path, committed: &Path, stale: &mut Vec<String>) {
+    for entry in fs::read_dir(generated).unwrap() {
+        let path = entry.unwrap().path();
+        compare(&path, &committed.join(path.file_name().unwrap()), stale);
+    }
+    for entry in fs::read_dir(committed).into_iter().flatten() {
+        let path = entry.unwrap().path();
+        let generated = generated.join(path.file_name().unwrap());
+        if path.extension().is_some_and(|ext| ext == "rs") && !generated.exists() {
+            stale.push(path.display().to_string());
+        }
+    }
+}
+
+fn compare(generated: &Path, committed: &Path, stale: &mut Vec<String>) {
+    let generated = fs::read_to_string(generated).unwrap();
+    if fs::read_to_string(committed).ok().as_ref() != Some(&generated) {
+        stale.push(committed.display().to_string());
+    }
+}
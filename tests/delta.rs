@@ -0,0 +1,79 @@
+//! Merges deltas into the plain data types used by game objects.
+
+use joueur::client::delta::{Context, DeltaMergeable, FromDelta};
+use joueur::client::exit::Exit;
+use joueur::types::{List, Map, Str};
+use serde_json::{json, Value};
+
+fn merge<T: DeltaMergeable>(value: &mut T, delta: Value) {
+    value.merge_delta(delta, &Context::default()).unwrap();
+}
+
+#[test]
+fn floats_and_strings_are_replaced() {
+    let mut time: f32 = 0.0;
+    merge(&mut time, json!(1.5));
+    assert_eq!(time, 1.5);
+
+    let mut range: f64 = 0.0;
+    merge(&mut range, json!(3));
+    assert_eq!(range, 3.0);
+
+    let mut name = Str::from("old");
+    merge(&mut name, json!("new"));
+    assert_eq!(name.as_slice(), "new");
+}
+
+#[test]
+fn options_are_cleared_by_null() {
+    let mut value = Some(Str::from("set"));
+    merge(&mut value, Value::Null);
+    assert_eq!(value, None);
+
+    merge(&mut value, json!("again"));
+    assert_eq!(value, Some(Str::from("again")));
+}
+
+#[test]
+fn lists_grow_shrink_and_copy_on_write() {
+    let mut list = List::<i32>::from(vec![1, 2, 3]);
+    let shared = list.clone();
+
+    merge(&mut list, json!({"&LEN": 4, "1": 20, "3": 40}));
+    assert_eq!(list, vec![1, 20, 3, 40]);
+    assert_eq!(shared, vec![1, 2, 3]);
+
+    merge(&mut list, json!({"&LEN": 2}));
+    assert_eq!(list, vec![1, 20]);
+}
+
+#[test]
+fn lists_with_missing_elements_are_rejected() {
+    let delta = json!({"&LEN": 3, "2": 30});
+    let err = List::<i32>::from_delta(delta, &Context::default()).unwrap_err();
+    assert_eq!(err.exit(), Some(Exit::DeltaMergeFailure));
+}
+
+#[test]
+fn maps_insert_merge_and_remove() {
+    let mut map: Map<Str, List<i32>> = vec![
+        (Str::from("kept"), List::from(vec![1])),
+        (Str::from("gone"), List::from(vec![2])),
+    ]
+    .into_iter()
+    .collect();
+    let shared = map.clone();
+
+    merge(
+        &mut map,
+        json!({
+            "kept": {"&LEN": 2, "1": 10},
+            "gone": "&RM",
+            "new": {"&LEN": 1, "0": 3},
+        }),
+    );
+    assert_eq!(map.get("kept"), Some(&List::from(vec![1, 10])));
+    assert_eq!(map.get("gone"), None);
+    assert_eq!(map.get("new"), Some(&List::from(vec![3])));
+    assert_eq!(shared.get("gone"), Some(&List::from(vec![2])));
+}
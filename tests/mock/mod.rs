@@ -0,0 +1,233 @@
+//! A scriptable stand-in for a Cerveau game server, so that clients can be tested without one.
+//!
+//! The server listens on a loopback port and runs a script against the first client to connect,
+//! on a thread of its own. Scripts drive the game one event at a time and panic when the client
+//! does something unexpected, which fails the test when the server thread is joined.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use joueur::client::proto::{ClientEvent, EventSink, EventStream, ServerEvent};
+use joueur::client::{Client, ClientConfig, GameOutcome};
+use joueur::error::Error;
+use joueur::games::Registry;
+use serde_json::Value;
+
+/// How long the server waits for the client before failing the test, so that a client stuck on
+/// a missing event can't hang the test suite.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The constants sent in the "lobbied" event.
+pub fn constants() -> HashMap<String, String> {
+    let mut constants = HashMap::new();
+    constants.insert("DELTA_LIST_LENGTH".to_string(), "&LEN".to_string());
+    constants.insert("DELTA_REMOVED".to_string(), "&RM".to_string());
+    constants
+}
+
+pub struct MockServer {
+    listener: TcpListener,
+}
+
+impl MockServer {
+    /// Listens on a free loopback port.
+    pub fn bind() -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind mock server");
+        MockServer { listener }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.listener.local_addr().unwrap().port()
+    }
+
+    /// Accepts one client and runs `script` against it on another thread.
+    pub fn spawn<F>(self, script: F) -> JoinHandle<()>
+    where
+        F: FnOnce(&mut MockClient) + Send + 'static,
+    {
+        thread::spawn(move || {
+            let (socket, _) = self.listener.accept().unwrap();
+            socket.set_read_timeout(Some(TIMEOUT)).unwrap();
+            let mut client = MockClient {
+                stream: EventStream::new(BufReader::new(socket.try_clone().unwrap())),
+                sink: EventSink::new(socket),
+            };
+            script(&mut client);
+        })
+    }
+}
+
+/// The server's end of a connection to the client under test.
+pub struct MockClient {
+    stream: EventStream<BufReader<TcpStream>, ClientEvent>,
+    sink: EventSink<TcpStream, ServerEvent>,
+}
+
+impl MockClient {
+    pub fn send(&mut self, event: ServerEvent) {
+        self.sink.send(event).expect("failed to send to client");
+    }
+
+    /// Waits for the next event from the client.
+    pub fn recv(&mut self) -> ClientEvent {
+        self.stream
+            .recv()
+            .expect("client disconnected")
+            .expect("failed to read from client")
+    }
+
+    /// Waits for the client to close the connection, without sending anything else.
+    pub fn expect_disconnect(&mut self) {
+        if let Some(Ok(event)) = self.stream.recv() {
+            panic!("expected a disconnect, got {:?}", event);
+        }
+    }
+
+    /// Answers "alias" and "play" as a server would, returning the "play" event's player name.
+    pub fn lobby(&mut self, game_name: &str) -> String {
+        match self.recv() {
+            ClientEvent::Alias(_) => self.send(ServerEvent::Named(game_name.to_string())),
+            event => panic!("expected alias, got {:?}", event),
+        }
+        let player_name = match self.recv() {
+            ClientEvent::Play {
+                game_name: played,
+                player_name,
+                ..
+            } => {
+                assert_eq!(played, game_name);
+                player_name
+            }
+            event => panic!("expected play, got {:?}", event),
+        };
+        self.send(ServerEvent::Lobbied {
+            game_name: game_name.to_string(),
+            game_session: "mock".to_string(),
+            constants: constants(),
+        });
+        player_name
+    }
+
+    /// Sends the initial state of the game, followed by the "start" event.
+    pub fn start(&mut self, delta: Value, player_id: &str) {
+        self.delta(delta);
+        self.send(ServerEvent::Start {
+            player_id: player_id.to_string(),
+        });
+    }
+
+    pub fn delta(&mut self, delta: Value) {
+        let delta = serde_json::from_value(delta).expect("delta must be an object");
+        self.send(ServerEvent::Delta(delta));
+    }
+
+    /// Gives the client an order, and waits for it to be finished. Runs that the client asks for
+    /// in the meantime are passed to `on_run`, which answers them.
+    pub fn order_with<F>(
+        &mut self,
+        index: usize,
+        name: &str,
+        args: Vec<Value>,
+        mut on_run: F,
+    ) -> Value
+    where
+        F: FnMut(&mut MockClient, Run),
+    {
+        self.send(ServerEvent::Order {
+            name: name.to_string(),
+            index,
+            args,
+        });
+        loop {
+            match self.recv() {
+                ClientEvent::Finished {
+                    order_index,
+                    returned,
+                } => {
+                    assert_eq!(order_index, index as isize, "finished the wrong order");
+                    return returned;
+                }
+                ClientEvent::Run {
+                    caller,
+                    function_name,
+                    args,
+                } => {
+                    let run = Run {
+                        caller: caller.id().to_string(),
+                        function_name,
+                        args,
+                    };
+                    on_run(self, run);
+                }
+                event => panic!("expected finished or run, got {:?}", event),
+            }
+        }
+    }
+
+    /// Gives the client an order that must be finished without running anything.
+    pub fn order(&mut self, index: usize, name: &str, args: Vec<Value>) -> Value {
+        self.order_with(index, name, args, |_, run| panic!("unexpected {:?}", run))
+    }
+
+    pub fn ran(&mut self, returned: Value) {
+        self.send(ServerEvent::Ran(returned));
+    }
+
+    pub fn invalid(&mut self, message: &str) {
+        self.send(ServerEvent::Invalid {
+            message: message.to_string(),
+        });
+    }
+
+    pub fn over(&mut self) {
+        self.send(ServerEvent::Over {
+            gamelog_url: None,
+            visualizer_url: None,
+            message: None,
+        });
+    }
+}
+
+/// A "run" event sent by the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub caller: String,
+    pub function_name: String,
+    pub args: HashMap<String, Value>,
+}
+
+/// Plays a game with the AI registered for `game_name`, against a server that lobbies the client,
+/// starts the game from `initial_state` with the client as player "0", and then runs `script`.
+pub fn play<F>(
+    registry: &Registry,
+    game_name: &str,
+    initial_state: Value,
+    script: F,
+) -> Result<GameOutcome, Error>
+where
+    F: FnOnce(&mut MockClient) + Send + 'static,
+{
+    let player_name = registry.session(game_name).unwrap().player_name();
+
+    let server = MockServer::bind();
+    let client = Client::new(
+        ClientConfig::new(game_name)
+            .server("127.0.0.1")
+            .port(server.port()),
+    );
+    let server_game_name = game_name.to_string();
+    let server = server.spawn(move |client| {
+        assert_eq!(client.lobby(&server_game_name), player_name);
+        client.start(initial_state, "0");
+        script(client);
+    });
+
+    let result = client.play(registry);
+    server.join().expect("mock server failed");
+    result
+}
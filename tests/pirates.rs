@@ -0,0 +1,285 @@
+//! Checks how pirates' functions are sent to the mock server, since they take game objects.
+#![cfg(feature = "pirates")]
+
+mod mock;
+
+use std::collections::HashSet;
+
+use joueur::client::exit::Exit;
+use joueur::client::proto::{ClientEvent, ServerEvent};
+use joueur::client::{Client, ClientConfig, GameSettings};
+use joueur::error::Error;
+use joueur::games::pirates::{self, Changes, Game, Player, Tile, TileType, Unit, UnitType, AI};
+use joueur::games::{AnyPlayer, GridGame, GridTile, Registry};
+use serde_json::{json, Value};
+
+use mock::MockServer;
+
+/// Moves its unit east and attacks the tile it came from, in its only turn.
+struct Raider;
+
+impl AI for Raider {
+    fn run_turn(&mut self, game: &Game, player: &Player) -> Result<bool, Error> {
+        let unit = &player.units()[0];
+        let start = unit.tile().unwrap();
+        // Handles hash by identity, so their interior mutability can't change their hash.
+        #[allow(clippy::mutable_key_type)]
+        let tiles: HashSet<_> = game.tiles().iter().cloned().collect();
+        assert!(tiles.contains(&start));
+
+        let moved = unit.move_(&start.tile_east().unwrap())?;
+        assert!(moved);
+        assert_eq!(unit.tile(), start.tile_east());
+        assert!(start < unit.tile().unwrap());
+
+        assert_eq!(first_neighbor(game, 0, 0), start.tile_east());
+        assert_eq!(game.get_tile_at(1, 0), start.tile_east());
+        assert_eq!(game.get_tile_at(2, 0), None);
+        assert_eq!(opponent_of_opponent(player), *player);
+        assert_eq!(start.type_(), TileType::Water);
+        unit.attack(&start, UnitType::Crew)
+    }
+}
+
+/// Helpers written against the shared traits work with any game.
+fn first_neighbor<G: GridGame>(game: &G, x: i64, y: i64) -> Option<G::Tile> {
+    game.get_tile_at(x, y)?.neighbors().into_iter().next()
+}
+
+fn opponent_of_opponent<P: AnyPlayer>(player: &P) -> P {
+    player.opponent().opponent()
+}
+
+/// Attacks with its unit, which the mock server removes from the game.
+struct Doomed;
+
+impl AI for Doomed {
+    fn run_turn(&mut self, game: &Game, player: &Player) -> Result<bool, Error> {
+        let unit = player.units()[0].clone();
+        assert!(unit.is_alive());
+        assert_eq!(game.get_object::<Unit>("5"), Some(unit.clone()));
+        assert_eq!(game.get_object::<Unit>("2"), None);
+
+        unit.attack(&unit.tile().unwrap(), UnitType::Crew)?;
+        assert!(!unit.is_alive());
+        assert!(player.units().is_empty());
+        assert_eq!(game.get_object::<Unit>("5"), None);
+        assert!(game.get_object::<Tile>("2").unwrap().is_alive());
+        Ok(true)
+    }
+
+    fn game_updated(
+        &mut self,
+        _game: &Game,
+        _player: &Player,
+        changes: &Changes,
+    ) -> Result<(), Error> {
+        assert!(changes.was_removed("5"));
+        assert!(changes.has_changed("0", "units"));
+        assert!(changes.has_changed("2", "unit"));
+        assert!(changes.game_fields().contains("current_turn"));
+        Ok(())
+    }
+}
+
+fn initial_state() -> Value {
+    let player = |id: &str, opponent: &str, port: &str, units: Value| {
+        json!({
+            "id": id,
+            "gameObjectName": "Player",
+            "name": format!("Player {}", id),
+            "opponent": {"id": opponent},
+            "port": {"id": port},
+            "units": units,
+        })
+    };
+    let tile = |id: &str, x: i64, east: Value, west: Value| {
+        json!({
+            "id": id,
+            "gameObjectName": "Tile",
+            "x": x,
+            "y": 0,
+            "tileEast": east,
+            "tileWest": west,
+            "type": "water",
+        })
+    };
+    json!({
+        "gameObjects": {
+            "0": player("0", "1", "4", json!({"&LEN": 1, "0": {"id": "5"}})),
+            "1": player("1", "0", "4", json!({"&LEN": 0})),
+            "2": tile("2", 0, json!({"id": "3"}), Value::Null),
+            "3": tile("3", 1, Value::Null, json!({"id": "2"})),
+            "4": {"id": "4", "gameObjectName": "Port", "tile": {"id": "2"}},
+            "5": {"id": "5", "gameObjectName": "Unit", "owner": {"id": "0"}, "tile": {"id": "2"}},
+        },
+        "players": {"&LEN": 2, "0": {"id": "0"}, "1": {"id": "1"}},
+        "currentPlayer": {"id": "0"},
+        "mapWidth": 2,
+        "mapHeight": 1,
+        "tiles": {"&LEN": 2, "0": {"id": "2"}, "1": {"id": "3"}},
+        "units": {"&LEN": 1, "0": {"id": "5"}},
+        "ports": {"&LEN": 1, "0": {"id": "4"}},
+    })
+}
+
+#[test]
+fn game_objects_are_sent_as_references() {
+    let mut registry = Registry::new();
+    pirates::register(&mut registry, || Raider);
+
+    let mut runs = Vec::new();
+    let result = mock::play(&registry, "Pirates", initial_state(), move |client| {
+        let returned = client.order_with(0, "runTurn", vec![], |client, run| {
+            runs.push((
+                run.caller.clone(),
+                run.function_name.clone(),
+                json!(run.args),
+            ));
+            if run.function_name == "move" {
+                client.delta(json!({
+                    "gameObjects": {
+                        "5": {"tile": {"id": "3"}},
+                        "2": {"unit": null},
+                        "3": {"unit": {"id": "5"}},
+                    },
+                }));
+            }
+            client.ran(json!(true));
+        });
+        assert_eq!(returned, json!(true));
+        assert_eq!(
+            runs,
+            [
+                ("5".into(), "move".into(), json!({"tile": {"id": "3"}})),
+                (
+                    "5".into(),
+                    "attack".into(),
+                    json!({"tile": {"id": "2"}, "target": "crew"})
+                ),
+            ]
+        );
+        client.over();
+    });
+
+    result.unwrap();
+}
+
+#[test]
+fn removed_objects_are_no_longer_alive() {
+    let mut registry = Registry::new();
+    pirates::register(&mut registry, || Doomed);
+
+    let result = mock::play(&registry, "Pirates", initial_state(), |client| {
+        client.order_with(0, "runTurn", vec![], |client, _| {
+            client.delta(json!({
+                "gameObjects": {
+                    "5": "&RM",
+                    "0": {"units": {"&LEN": 0}},
+                    "2": {"unit": null},
+                },
+                "units": {"&LEN": 0},
+            }));
+            client.ran(json!(true));
+        });
+        client.delta(json!({"currentTurn": 1}));
+        client.over();
+    });
+
+    result.unwrap();
+}
+
+#[test]
+fn game_settings_are_checked_before_playing() {
+    let names: Vec<_> = pirates::SETTINGS
+        .iter()
+        .map(|setting| setting.name)
+        .collect();
+    assert!(names.contains(&"maxTurns"));
+    assert!(names.contains(&"crewCost"));
+
+    // Settings that aren't known to the client are still sent, for the server to check.
+    let settings = GameSettings::new()
+        .set("maxTurns", 50)
+        .set("randomSeed", "calm seas")
+        .add("krakens", 1)
+        .add("krakens", 2);
+    let server = MockServer::bind();
+    let config = ClientConfig::new("pirates")
+        .server("127.0.0.1")
+        .port(server.port())
+        .game_settings(settings);
+    let server = server.spawn(|client| {
+        client.recv();
+        client.send(ServerEvent::Named("Pirates".to_string()));
+        match client.recv() {
+            ClientEvent::Play { game_settings, .. } => assert_eq!(
+                game_settings.as_deref(),
+                Some("maxTurns=50&randomSeed=calm%20seas&krakens=1&krakens=2")
+            ),
+            event => panic!("expected play, got {:?}", event),
+        }
+    });
+    let result = pirates::play(&Client::new(config), Raider);
+    server.join().expect("mock server failed");
+    assert!(result.is_err());
+
+    let server = MockServer::bind();
+    let config = ClientConfig::new("pirates")
+        .server("127.0.0.1")
+        .port(server.port())
+        .game_settings(GameSettings::new().set("maxTurns", "forever"));
+    let server = server.spawn(|client| {
+        client.recv();
+        client.send(ServerEvent::Named("Pirates".to_string()));
+        client.expect_disconnect();
+    });
+    let result = pirates::play(&Client::new(config), Raider);
+    server.join().expect("mock server failed");
+    assert_eq!(result.unwrap_err().exit(), Some(Exit::InvalidArgs));
+}
+
+#[test]
+fn outcomes_point_to_the_connected_host() {
+    let mut registry = Registry::new();
+    pirates::register(&mut registry, || Raider);
+
+    let result = mock::play(&registry, "Pirates", initial_state(), |client| {
+        client.delta(json!({
+            "currentTurn": 7,
+            "gameObjects": {"0": {"won": true, "reasonWon": "plunder"}},
+        }));
+        client.send(ServerEvent::Over {
+            gamelog_url: Some("http://__HOSTNAME__:3080/gamelog/1".to_string()),
+            visualizer_url: Some("http://vis/?log=http%3A%2F%2F__HOSTNAME__%3A3080".to_string()),
+            message: Some("watch at __HOSTNAME__".to_string()),
+        });
+    });
+
+    let outcome = result.unwrap();
+    assert!(outcome.won);
+    assert_eq!(outcome.reason_won, "plunder");
+    assert_eq!(outcome.final_turn, Some(7));
+    assert_eq!(
+        outcome.gamelog_url.as_deref(),
+        Some("http://127.0.0.1:3080/gamelog/1")
+    );
+    assert_eq!(
+        outcome.visualizer_url.as_deref(),
+        Some("http://vis/?log=http%3A%2F%2F127.0.0.1%3A3080")
+    );
+
+    let path = std::env::temp_dir().join(format!("joueur-outcome-{}.json", std::process::id()));
+    outcome.save(&path).unwrap();
+    let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(saved["game_name"], json!("Pirates"));
+    assert_eq!(saved["final_turn"], json!(7));
+    assert_eq!(
+        saved["gamelog_url"],
+        json!("http://127.0.0.1:3080/gamelog/1")
+    );
+
+    let err = outcome.save("no/such/dir/outcome.json").unwrap_err();
+    assert_eq!(err.exit(), None);
+}
@@ -0,0 +1,133 @@
+//! Parses AI and game settings the way `--aiSettings` and `--gameSettings` are given on the
+//! command line.
+
+use joueur::client::exit::Exit;
+use joueur::client::settings::{GameSetting, SettingKind};
+use joueur::client::{AiSettings, GameSettings};
+
+#[test]
+fn settings_are_parsed_as_pairs() {
+    let settings = AiSettings::parse("depth=4&name=deep blue&&empty=&depth=6").unwrap();
+
+    assert_eq!(settings.get_str("depth"), Some("6"));
+    assert_eq!(settings.get_str("name"), Some("deep blue"));
+    assert_eq!(settings.get_str("empty"), Some(""));
+    assert!(!settings.contains_key("missing"));
+    assert_eq!(settings.iter().count(), 3);
+    assert_eq!(AiSettings::parse("").unwrap(), AiSettings::new());
+}
+
+#[test]
+fn settings_are_parsed_into_types() {
+    let mut settings = AiSettings::parse("depth=4&aggressive=true&ratio=0.5").unwrap();
+    settings.insert("name", "bot");
+
+    assert_eq!(settings.get::<u32>("depth").unwrap(), Some(4));
+    assert_eq!(settings.get::<bool>("aggressive").unwrap(), Some(true));
+    assert_eq!(settings.get::<f64>("ratio").unwrap(), Some(0.5));
+    assert_eq!(
+        settings.get::<String>("name").unwrap(),
+        Some("bot".to_string())
+    );
+    assert_eq!(settings.get::<u32>("missing").unwrap(), None);
+    assert_eq!(settings.get_or("missing", 7u32).unwrap(), 7);
+    assert_eq!(settings.get_or("depth", 7u32).unwrap(), 4);
+}
+
+#[test]
+fn malformed_settings_are_invalid_args() {
+    for malformed in ["depth", "=4", "depth=4&fast"] {
+        let err = AiSettings::parse(malformed).unwrap_err();
+        assert_eq!(err.exit(), Some(Exit::InvalidArgs), "{:?}", malformed);
+    }
+
+    let settings = AiSettings::parse("depth=deep").unwrap();
+    let err = settings.get::<u32>("depth").unwrap_err();
+    assert_eq!(err.exit(), Some(Exit::InvalidArgs));
+    assert!(err.to_string().contains("depth=\"deep\""));
+}
+
+#[test]
+fn game_settings_are_percent_encoded() {
+    let settings = GameSettings::new()
+        .set("randomSeed", "50% & more=")
+        .add("tag", "a")
+        .add("tag", "b")
+        .set("maxTurns", 10)
+        .set("maxTurns", 20);
+
+    let query = settings.to_string();
+    assert_eq!(
+        query,
+        "randomSeed=50%25%20%26%20more%3D&tag=a&tag=b&maxTurns=20"
+    );
+    assert_eq!(GameSettings::parse(&query).unwrap(), settings);
+    assert_eq!(settings.get("randomSeed"), Some("50% & more="));
+    assert_eq!(settings.get_all("tag").collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(settings.get("missing"), None);
+
+    let parsed = GameSettings::parse("name=deep+blue&flag&&caf%C3%A9=%e2%82%ac").unwrap();
+    let pairs: Vec<_> = parsed.iter().collect();
+    assert_eq!(pairs, [("name", "deep blue"), ("flag", ""), ("café", "€")]);
+    assert!(GameSettings::parse("").unwrap().is_empty());
+}
+
+#[test]
+fn malformed_game_settings_are_invalid_args() {
+    for malformed in [
+        "=4",
+        "maxTurns=%4",
+        "maxTurns=%zz",
+        "maxTurns=%+1",
+        "seed=%FF",
+    ] {
+        let err = GameSettings::parse(malformed).unwrap_err();
+        assert_eq!(err.exit(), Some(Exit::InvalidArgs), "{:?}", malformed);
+    }
+}
+
+#[test]
+fn game_settings_are_checked_against_the_game() {
+    const SETTINGS: &[GameSetting] = &[
+        GameSetting {
+            name: "maxTurns",
+            kind: SettingKind::Int,
+            description: "",
+        },
+        GameSetting {
+            name: "fog",
+            kind: SettingKind::Boolean,
+            description: "",
+        },
+    ];
+
+    let valid = GameSettings::new()
+        .set("maxTurns", 100)
+        .set("fog", true)
+        .set("randomSeed", "seed");
+    assert_eq!(valid.check(SETTINGS).unwrap(), Vec::<String>::new());
+
+    // The server has the final say on settings that aren't known, so they are only warned about.
+    let unexpected = GameSettings::new()
+        .set("lodgesToWin", 3)
+        .add("maxTurns", 1)
+        .add("maxTurns", 2);
+    let warnings = unexpected.check(SETTINGS).unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("unknown"), "{}", warnings[0]);
+    assert!(warnings[1].contains("more than once"), "{}", warnings[1]);
+
+    let invalid = [
+        (GameSettings::new().set("maxTurns", 1.5), "not a valid int"),
+        (GameSettings::new().set("fog", "yes"), "not a valid boolean"),
+        (
+            GameSettings::new().set("playerStartTime", "soon"),
+            "not a valid int",
+        ),
+    ];
+    for (settings, reason) in invalid {
+        let err = settings.check(SETTINGS).unwrap_err();
+        assert_eq!(err.exit(), Some(Exit::InvalidArgs));
+        assert!(err.to_string().contains(reason), "{}", err);
+    }
+}
@@ -0,0 +1,56 @@
+//! Uses the reference-counted collections the way an AI would.
+
+use std::collections::BTreeMap;
+
+use joueur::types::{List, Map, Str};
+use serde_json::json;
+
+#[test]
+fn maps_can_be_read_like_hash_maps() {
+    let map: Map<Str, i32> = vec![(Str::from("a"), 1), (Str::from("b"), 2)]
+        .into_iter()
+        .collect();
+
+    assert_eq!(map.len(), 2);
+    assert!(!map.is_empty());
+    assert!(map.contains_key("a"));
+    assert!(!map.contains_key("c"));
+    assert_eq!(map.values().sum::<i32>(), 3);
+
+    let mut keys: Vec<_> = map.keys().map(Str::as_slice).collect();
+    keys.sort();
+    assert_eq!(keys, ["a", "b"]);
+
+    let sorted: BTreeMap<_, _> = (&map).into_iter().collect();
+    assert_eq!(sorted.len(), 2);
+}
+
+#[test]
+fn lists_can_be_extended_without_affecting_clones() {
+    let mut list: List<i32> = (1..=2).collect();
+    let shared = list.clone();
+
+    list.push(3);
+    list.extend(vec![4, 5]);
+    assert_eq!(list.pop(), Some(5));
+
+    let mut total = 0;
+    for item in &list {
+        total += item;
+    }
+    assert_eq!(total, 10);
+    assert_eq!(shared, vec![1, 2]);
+}
+
+#[test]
+fn collections_round_trip_through_json() {
+    let list: List<Str> = vec![Str::from("x"), Str::from("y")].into();
+    let map: Map<Str, List<Str>> = vec![(Str::from("k"), list.clone())].into_iter().collect();
+
+    let value = serde_json::to_value(&map).unwrap();
+    assert_eq!(value, json!({"k": ["x", "y"]}));
+
+    let parsed: Map<Str, List<Str>> = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, map);
+    assert_eq!(Str::from("x").to_string(), "x");
+}